@@ -2,7 +2,7 @@
 
 use backend::Environment;
 use backend::errors::*;
-use core::{Loc, RpEnumBody, RpVariant};
+use core::{Loc, RpEnumBody, RpVariant, RpVariantValue};
 use doc_builder::DocBuilder;
 use escape::Escape;
 use macros::FormatAttribute;
@@ -46,8 +46,12 @@ impl<'p> EnumProcessor<'p> {
                 html!(self, span {class => "kind"} ~ "variant");
                 self.full_name_without_package(&variant.name)?;
                 html!(self, span {class => "keyword"} ~ "as");
-                html!(self, span {class => "variant-ordinal"} ~
-                      Escape(format!("\"{}\"", variant.ordinal()).as_str()));
+                let ordinal = match variant.ordinal() {
+                    RpVariantValue::String(string) => format!("\"{}\"", string),
+                    RpVariantValue::Number(number) => number.to_string(),
+                };
+
+                html!(self, span {class => "variant-ordinal"} ~ Escape(ordinal.as_str()));
             });
 
             self.doc(&variant.comment)?;
@@ -1,7 +1,7 @@
 //! Processor trait.
 
 use super::{DOC_CSS_NAME, NORMALIZE_CSS_NAME};
-use backend::Environment;
+use backend::{Environment, ValueBuilder};
 use backend::errors::*;
 use core::{ForEachLoc, Loc, RpDecl, RpField, RpName, RpType, RpVersionedPackage};
 use doc_builder::DocBuilder;
@@ -115,6 +115,10 @@ pub trait Processor<'env> {
             Boolean => self.primitive("boolean")?,
             String => self.primitive("string")?,
             DateTime => self.primitive("datetime")?,
+            Date => self.primitive("date")?,
+            Duration => self.primitive("duration")?,
+            Uuid => self.primitive("uuid")?,
+            Decimal => self.primitive("decimal")?,
             Bytes => self.primitive("bytes")?,
             Any => self.primitive("any")?,
             Signed { ref size } => self.primitive(format!("i{}", size).as_str())?,
@@ -170,6 +174,17 @@ pub trait Processor<'env> {
 
             self.write_type(&field.ty)?;
 
+            if !field.constraints.is_empty() {
+                let constraints = field.constraints.describe().join(", ");
+                html!(self, span {class => "field-constraints"} ~ Escape(constraints.as_str()));
+            }
+
+            if let Some(ref default) = field.default {
+                let default = DeclaredValue.value(&field.ty, default.value());
+                html!(self, span {class => "keyword"} ~ "=");
+                html!(self, span {class => "field-default"} ~ Escape(default.as_str()));
+            }
+
             if field.ident() != field.name() {
                 html!(self, span {class => "keyword"} ~ "as");
                 html!(self, span {class => "field-name"} ~ Escape(field.name()));
@@ -377,3 +392,22 @@ pub trait Processor<'env> {
         Ok(())
     }
 }
+
+/// Formats values in the same form that they are declared.
+struct DeclaredValue;
+
+impl<'el> ValueBuilder<'el> for DeclaredValue {
+    type Output = String;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        literal
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        format!("{:?}", string)
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        format!("[{}]", items.join(", "))
+    }
+}
@@ -2,7 +2,7 @@
 
 use backend::Environment;
 use backend::errors::*;
-use core::{RpEndpoint, RpServiceBody, WithPos};
+use core::{Loc, RpEndpoint, RpOptionDecl, RpServiceBody, RpValue, WithPos};
 use doc_builder::DocBuilder;
 use escape::Escape;
 use macros::FormatAttribute;
@@ -78,7 +78,61 @@ impl<'p> ServiceProcessor<'p> {
             }
         });
 
+        if let Some(ref http) = endpoint.http {
+            html!(self, div {class => "endpoint-http"} => {
+                html!(self, span {class => "endpoint-http-method"} ~ http.method.value());
+                html!(self, span {class => "endpoint-http-path"} ~ Escape(http.path.url().as_str()));
+            });
+        }
+
         self.doc(&endpoint.comment)?;
+        self.endpoint_options(&endpoint.options)?;
+        Ok(())
+    }
+
+    fn endpoint_options(&self, options: &[Loc<RpOptionDecl>]) -> Result<()> {
+        if options.is_empty() {
+            return Ok(());
+        }
+
+        html!(self, dl {class => "endpoint-options"} => {
+            for option in options {
+                html!(self, dt {class => "endpoint-option-name"} ~ Escape(option.name.as_str()));
+
+                html!(self, dd {class => "endpoint-option-value"} => {
+                    self.option_value(option.value.value())?;
+                });
+            }
+        });
+
+        Ok(())
+    }
+
+    fn option_value(&self, value: &RpValue) -> Result<()> {
+        use self::RpValue::*;
+
+        match *value {
+            String(ref string) => {
+                html!(self, span {class => "value-string"} ~ Escape(format!("{:?}", string).as_str()));
+            }
+            Number(ref number) => {
+                html!(self, span {class => "value-number"} ~ number);
+            }
+            Boolean(ref boolean) => {
+                html!(self, span {class => "value-boolean"} ~ boolean);
+            }
+            Identifier(ref identifier) => {
+                html!(self, span {class => "value-identifier"} ~ Escape(identifier.as_str()));
+            }
+            Array(ref values) => {
+                html!(self, span {class => "value-array"} => {
+                    for value in values {
+                        self.option_value(value.value())?;
+                    }
+                });
+            }
+        }
+
         Ok(())
     }
 }
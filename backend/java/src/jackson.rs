@@ -1,9 +1,10 @@
 //! Module that adds fasterxml annotations to generated classes.
 
 use backend::errors::*;
+use core::RpTypeInfo;
 use genco::{Cons, Element, IntoTokens, Java, Quoted, Tokens};
-use genco::java::{Argument, Class, DOUBLE, FLOAT, Field, INTEGER, LONG, Modifier, SHORT, imported,
-                  local};
+use genco::java::{Argument, BYTE, Class, DOUBLE, FLOAT, Field, INTEGER, LONG, Modifier, SHORT,
+                  imported, local};
 use listeners::{ClassAdded, EnumAdded, InterfaceAdded, Listeners, TupleAdded};
 use std::rc::Rc;
 
@@ -62,6 +63,8 @@ pub struct Module {
     type_reference: Java<'static>,
     token: Java<'static>,
     string: Java<'static>,
+    big_decimal: Java<'static>,
+    format: Java<'static>,
     io_exception: Java<'static>,
 }
 
@@ -92,6 +95,8 @@ impl Module {
             type_reference: imported("com.fasterxml.jackson.core.type", "TypeReference"),
             token: imported("com.fasterxml.jackson.core", "JsonToken"),
             string: imported("java.lang", "String"),
+            big_decimal: imported("java.math", "BigDecimal"),
+            format: imported("com.fasterxml.jackson.annotation", "JsonFormat"),
             io_exception: imported("java.io", "IOException"),
         }
     }
@@ -129,7 +134,7 @@ impl Module {
                 let access = toks!["value.", field.var()];
 
                 let write = match field.ty() {
-                    SHORT | LONG | INTEGER | FLOAT | DOUBLE => {
+                    BYTE | SHORT | LONG | INTEGER | FLOAT | DOUBLE => {
                         toks!["writeNumber(", access.clone(), ")"]
                     }
                     Java::Primitive { .. } => {
@@ -138,6 +143,8 @@ impl Module {
                     class @ Java::Class { .. } => {
                         if class == self.string {
                             toks!["writeString(", access.clone(), ")"]
+                        } else if class == self.big_decimal {
+                            toks!["writeString(", access.clone(), ".toString())"]
                         } else {
                             toks!["writeObject(", access.clone(), ")"]
                         }
@@ -177,6 +184,10 @@ impl Module {
                 let test = toks!["!", p.clone(), ".nextToken().isNumeric()"];
 
                 match java {
+                    BYTE => (
+                        Some((test, "VALUE_NUMBER_INT")),
+                        toks![p, ".getByteValue()"],
+                    ),
                     SHORT => (
                         Some((test, "VALUE_NUMBER_INT")),
                         toks![p, ".getShortValue()"],
@@ -359,6 +370,17 @@ impl Module {
         for (field, name) in spec.fields.iter_mut().zip(names.iter()) {
             let ann = toks!["@", self.property.clone(), "(", name.clone().quoted(), ")"];
             field.annotation(ann.clone());
+
+            // decimals are encoded as strings, to preserve their precision.
+            if field.ty() == self.big_decimal {
+                field.annotation(toks![
+                    "@",
+                    self.format.clone(),
+                    "(shape = ",
+                    self.format.clone(),
+                    ".Shape.STRING)",
+                ]);
+            }
         }
 
         for c in &mut spec.constructors {
@@ -436,8 +458,19 @@ impl Listeners for Module {
             let mut args = Tokens::new();
 
             args.append(toks!["use=", self.type_info.clone(), ".Id.NAME"]);
-            args.append(toks!["include=", self.type_info.clone(), ".As.PROPERTY"]);
-            args.append(toks!["property=", "type".quoted()]);
+
+            match e.body.type_info {
+                RpTypeInfo::TypeField { ref name } => {
+                    args.append(toks!["include=", self.type_info.clone(), ".As.PROPERTY"]);
+                    args.append(toks!["property=", name.as_str().quoted()]);
+                }
+                RpTypeInfo::Array => {
+                    args.append(toks!["include=", self.type_info.clone(), ".As.WRAPPER_ARRAY"]);
+                }
+                RpTypeInfo::ObjectKeys => {
+                    args.append(toks!["include=", self.type_info.clone(), ".As.WRAPPER_OBJECT"]);
+                }
+            }
 
             e.spec.annotation(TypeInfo(self, args));
         }
@@ -1,13 +1,14 @@
 //! Java backend for reproto
 
 use super::JAVA_CONTEXT;
-use backend::{Code, Converter, Environment, FromNaming, Naming, SnakeCase};
+use backend::{Code, Converter, Environment, FromNaming, Naming, SnakeCase, ValueBuilder};
 use backend::errors::*;
 use core::{ForEachLoc, Loc, RpDecl, RpEnumBody, RpEnumType, RpField, RpInterfaceBody, RpName,
-           RpPackage, RpServiceBody, RpTupleBody, RpType, RpTypeBody, RpVersionedPackage};
+           RpNumber, RpPackage, RpServiceBody, RpTupleBody, RpType, RpTypeBody,
+           RpVariantValue, RpVersionedPackage};
 use genco::{Cons, Element, IoFmt, Java, Quoted, Tokens, WriteTokens};
-use genco::java::{Argument, BOOLEAN, Class, Constructor, DOUBLE, Enum, Extra, FLOAT, Field,
-                  INTEGER, Interface, LONG, Method, Modifier, imported, local, optional};
+use genco::java::{Argument, BOOLEAN, BYTE, Class, Constructor, DOUBLE, Enum, Extra, FLOAT, Field,
+                  INTEGER, Interface, LONG, Method, Modifier, SHORT, imported, local, optional};
 use java_field::JavaField;
 use java_options::JavaOptions;
 use listeners::{ClassAdded, EnumAdded, InterfaceAdded, Listeners, ServiceAdded, TupleAdded};
@@ -33,8 +34,14 @@ pub struct JavaBackend {
     map: Java<'static>,
     string: Java<'static>,
     instant: Java<'static>,
+    local_date: Java<'static>,
+    duration: Java<'static>,
+    uuid: Java<'static>,
+    big_decimal: Java<'static>,
     optional: Java<'static>,
     illegal_argument: Java<'static>,
+    pattern: Java<'static>,
+    arrays: Java<'static>,
     async_container: Java<'static>,
     byte_buffer: Java<'static>,
 }
@@ -65,8 +72,14 @@ impl JavaBackend {
             map: imported("java.util", "Map"),
             string: imported("java.lang", "String"),
             instant: imported("java.time", "Instant"),
+            local_date: imported("java.time", "LocalDate"),
+            duration: imported("java.time", "Duration"),
+            uuid: imported("java.util", "UUID"),
+            big_decimal: imported("java.math", "BigDecimal"),
             optional: imported("java.util", "Optional"),
             illegal_argument: imported("java.lang", "IllegalArgumentException"),
+            pattern: imported("java.util.regex", "Pattern"),
+            arrays: imported("java.util", "Arrays"),
             async_container: async_container,
             byte_buffer: imported("java.nio", "ByteBuffer"),
         }
@@ -156,8 +169,17 @@ impl JavaBackend {
         let out = match *ty {
             String => self.string.clone().into(),
             DateTime => self.instant.clone().into(),
+            Date => self.local_date.clone().into(),
+            Duration => self.duration.clone().into(),
+            Uuid => self.uuid.clone().into(),
+            Decimal => self.big_decimal.clone().into(),
+            Signed { size: 8 } => BYTE.into(),
+            Signed { size: 16 } => SHORT.into(),
             Signed { size: 32 } => INTEGER.into(),
             Signed { size: 64 } => LONG.into(),
+            // java has no unsigned types, small unsigned types are widened to fit their values.
+            Unsigned { size: 8 } => SHORT.into(),
+            Unsigned { size: 16 } => INTEGER.into(),
             Unsigned { size: 32 } => INTEGER.into(),
             Unsigned { size: 64 } => LONG.into(),
             Float => FLOAT.into(),
@@ -184,8 +206,8 @@ impl JavaBackend {
     fn build_constructor<'el>(&self, fields: &[JavaField<'el>]) -> Constructor<'el> {
         let mut c = Constructor::new();
 
-        for field in fields {
-            let field = &field.spec;
+        for java_field in fields {
+            let field = &java_field.spec;
 
             let argument = Argument::new(field.ty(), field.var());
 
@@ -197,18 +219,149 @@ impl JavaBackend {
 
             c.arguments.push(argument.clone());
 
-            c.body.push(toks![
-                "this.",
-                field.var(),
-                " = ",
-                argument.var(),
-                ";",
-            ]);
+            if !java_field.checks.is_empty() {
+                c.body.push(java_field.checks.clone());
+            }
+
+            // absent values are replaced by the default value of the field.
+            let value = match java_field.default {
+                Some(ref default) => {
+                    let present = if self.options.nullable {
+                        toks![argument.var(), " != null && ", argument.var(), ".isPresent()"]
+                    } else {
+                        toks![argument.var(), ".isPresent()"]
+                    };
+
+                    toks![
+                        present,
+                        " ? ",
+                        argument.var(),
+                        " : ",
+                        self.optional.clone(),
+                        ".of(",
+                        default.clone(),
+                        ")",
+                    ]
+                }
+                None => toks![argument.var()],
+            };
+
+            c.body.push(toks!["this.", field.var(), " = ", value, ";"]);
         }
 
         c
     }
 
+    /// Build checks that the argument `var` satisfies the constraints of the given field.
+    fn constraint_checks<'el>(&self, field: &RpField, var: Rc<String>) -> Tokens<'el, Java<'el>> {
+        let constraints = &field.constraints;
+
+        if constraints.is_empty() {
+            return Tokens::new();
+        }
+
+        let value = if field.is_optional() {
+            toks![var.clone(), ".get()"]
+        } else {
+            toks![var.clone()]
+        };
+
+        let length = match field.ty {
+            RpType::String => toks![value.clone(), ".length()"],
+            _ => toks![value.clone(), ".size()"],
+        };
+
+        let mut checks: Vec<(Tokens<'el, Java<'el>>, String)> = Vec::new();
+
+        if let Some(ref min) = constraints.min {
+            checks.push((
+                toks![value.clone(), " < ", Rc::new(min.to_string())],
+                format!("{}: must be at least {}", field.name(), min),
+            ));
+        }
+
+        if let Some(ref max) = constraints.max {
+            checks.push((
+                toks![value.clone(), " > ", Rc::new(max.to_string())],
+                format!("{}: must be at most {}", field.name(), max),
+            ));
+        }
+
+        if let Some(min_length) = constraints.min_length {
+            checks.push((
+                toks![length.clone(), " < ", Rc::new(min_length.to_string())],
+                format!("{}: length must be at least {}", field.name(), min_length),
+            ));
+        }
+
+        if let Some(max_length) = constraints.max_length {
+            checks.push((
+                toks![length.clone(), " > ", Rc::new(max_length.to_string())],
+                format!("{}: length must be at most {}", field.name(), max_length),
+            ));
+        }
+
+        if let Some(ref pattern) = constraints.pattern {
+            checks.push((
+                toks![
+                    "!",
+                    self.pattern.clone(),
+                    ".compile(",
+                    pattern.clone().quoted(),
+                    ").matcher(",
+                    value.clone(),
+                    ").find()",
+                ],
+                format!("{}: must match the pattern {}", field.name(), pattern),
+            ));
+        }
+
+        if let Some(min_items) = constraints.min_items {
+            checks.push((
+                toks![length.clone(), " < ", Rc::new(min_items.to_string())],
+                format!("{}: must have at least {} item(s)", field.name(), min_items),
+            ));
+        }
+
+        if let Some(max_items) = constraints.max_items {
+            checks.push((
+                toks![length.clone(), " > ", Rc::new(max_items.to_string())],
+                format!("{}: must have at most {} item(s)", field.name(), max_items),
+            ));
+        }
+
+        if constraints.non_empty {
+            checks.push((
+                toks![value.clone(), ".isEmpty()"],
+                format!("{}: must not be empty", field.name()),
+            ));
+        }
+
+        let mut body = Tokens::new();
+
+        for (condition, message) in checks {
+            body.push(toks!["if (", condition, ") {"]);
+            body.nested(toks![
+                "throw new ",
+                self.illegal_argument.clone(),
+                "(",
+                message.quoted(),
+                ");",
+            ]);
+            body.push("}");
+        }
+
+        if field.is_optional() {
+            let mut out = Tokens::new();
+            out.push(toks!["if (", var, ".isPresent()) {"]);
+            out.nested(body);
+            out.push("}");
+            return out;
+        }
+
+        body
+    }
+
     /// Build a require-non-null check.
     fn require_non_null<'el>(
         &self,
@@ -551,12 +704,8 @@ impl JavaBackend {
     }
 
     fn enum_type_to_java<'a, 'el>(&self, ty: &'a RpEnumType) -> Result<Java<'el>> {
-        use self::RpEnumType::*;
-
-        match *ty {
-            String => Ok(self.string.clone().into()),
-            Generated => Ok(self.string.clone().into()),
-        }
+        // variants are stored as values of the type that the enum is declared with.
+        self.into_java_type(&ty.as_type())
     }
 
     fn process_enum<'el>(&self, body: &'el RpEnumBody) -> Result<Enum<'el>> {
@@ -565,11 +714,17 @@ impl JavaBackend {
         let enum_type = self.enum_type_to_java(&body.variant_type)?;
         spec.fields.push(self.new_field_spec(&enum_type, "value"));
 
+        let variant_type = body.variant_type.as_type();
+
         for variant in &body.variants {
             let mut enum_value = Tokens::new();
             let mut enum_toks = toks![variant.local_name.as_str()];
 
-            let value = self.ordinal(variant)?;
+            // numbers are cast like other literals, since small types can't be assigned an int.
+            let value = match variant.ordinal() {
+                RpVariantValue::Number(number) => self.number_literal(&variant_type, number),
+                RpVariantValue::String(_) => self.ordinal(variant)?,
+            };
             enum_toks.append(toks!["(", value, ")"]);
 
             enum_value.push(enum_toks);
@@ -812,12 +967,16 @@ impl JavaBackend {
         let camel_name = Rc::new(self.snake_to_upper_camel.convert(field.ident()));
         let ident = Rc::new(self.snake_to_lower_camel.convert(field.ident()));
 
+        let checks = self.constraint_checks(field, ident.clone());
+        let default = field.default.as_ref().map(|d| self.value(&field.ty, d.value()));
         let spec = Field::new(java_type, ident);
 
         Ok(JavaField {
             name: Rc::new(field.name().to_string()).into(),
             camel_name: camel_name,
             spec: spec,
+            checks: checks,
+            default: default,
         })
     }
 
@@ -902,3 +1061,40 @@ impl<'el> Converter<'el> for JavaBackend {
         Ok(toks![self.convert_type_id(name)?])
     }
 }
+
+impl<'el> ValueBuilder<'el> for JavaBackend {
+    type Output = Tokens<'el, Java<'el>>;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        toks![Rc::new(literal)]
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        toks![string.to_string().quoted()]
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        let mut args = Tokens::new();
+
+        for item in items {
+            args.append(item);
+        }
+
+        toks![self.arrays.clone(), ".asList(", args.join(", "), ")"]
+    }
+
+    fn number_literal(&self, ty: &RpType, number: &RpNumber) -> Self::Output {
+        let literal = match *ty {
+            RpType::Signed { size: 64 } |
+            RpType::Unsigned { size: 64 } => format!("{}L", number),
+            RpType::Signed { size: 8 } => format!("(byte) {}", number),
+            RpType::Signed { size: 16 } |
+            RpType::Unsigned { size: 8 } => format!("(short) {}", number),
+            RpType::Float => format!("{}f", number),
+            RpType::Double => format!("{}d", number),
+            _ => number.to_string(),
+        };
+
+        self.literal(literal)
+    }
+}
@@ -9,6 +9,10 @@ pub struct JavaField<'a> {
     pub name: Cons<'a>,
     pub camel_name: Rc<String>,
     pub spec: Field<'a>,
+    /// Checks of the constraints of the field, performed by the constructor.
+    pub checks: Tokens<'a, Java<'a>>,
+    /// Literal of the default value of the field, if it has one.
+    pub default: Option<Tokens<'a, Java<'a>>>,
 }
 
 impl<'el> JavaField<'el> {
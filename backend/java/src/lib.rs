@@ -23,6 +23,7 @@ mod java_field;
 mod mutable;
 mod nullable;
 mod grpc;
+mod okhttp;
 
 use self::backend::{ArgMatches, CompilerOptions, Environment, Options};
 use self::backend::errors::*;
@@ -34,6 +35,9 @@ use std::path::Path;
 
 pub const JAVA_CONTEXT: &str = "java";
 
+/// Endpoint options understood by the backend.
+const ENDPOINT_OPTIONS: &[&str] = &["http_status"];
+
 #[derive(Default)]
 pub struct JavaLang;
 
@@ -50,6 +54,7 @@ pub enum JavaModule {
     ConstructorProperties,
     Mutable,
     Nullable,
+    OkHttp,
 }
 
 impl TryFromToml for JavaModule {
@@ -64,6 +69,7 @@ impl TryFromToml for JavaModule {
             "constructor_properties" => ConstructorProperties,
             "mutable" => Mutable,
             "nullable" => Nullable,
+            "okhttp" => OkHttp,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -81,6 +87,7 @@ impl TryFromToml for JavaModule {
             "constructor_properties" => ConstructorProperties,
             "mutable" => Mutable,
             "nullable" => Nullable,
+            "okhttp" => OkHttp,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -104,6 +111,7 @@ fn setup_listeners(modules: &[JavaModule]) -> Result<(JavaOptions, Box<Listeners
             }
             Mutable => Box::new(mutable::Module::new()) as Box<Listeners>,
             Nullable => Box::new(nullable::Module::new()) as Box<Listeners>,
+            OkHttp => Box::new(okhttp::Module::new()) as Box<Listeners>,
         };
 
         listeners.push(listener);
@@ -125,6 +133,7 @@ pub fn compile(
     _matches: &ArgMatches,
     manifest: Manifest<JavaLang>,
 ) -> Result<()> {
+    env.verify_endpoint_options(ENDPOINT_OPTIONS)?;
     let (options, listeners) = setup_listeners(&manifest.modules)?;
     let backend = JavaBackend::new(env, options, listeners);
     backend.compile(&compiler_options.out_path)
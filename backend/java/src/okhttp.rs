@@ -0,0 +1,307 @@
+//! Module that builds an OkHttp client for services with HTTP bindings.
+
+use backend::{FromNaming, Naming, SnakeCase};
+use backend::errors::*;
+use core::{RpEndpoint, RpEndpointHttp, RpPathSegment};
+use genco::{Cons, Java, Quoted, Tokens};
+use genco::java::{Argument, Class, Constructor, Field, Method, Modifier, imported, local};
+use java_backend::JavaBackend;
+use listeners::{Listeners, ServiceAdded};
+use std::rc::Rc;
+
+const CLIENT_NAME: &'static str = "OkHttp";
+
+pub struct Module {
+    snake_to_upper_camel: Box<Naming>,
+    string: Java<'static>,
+    io_exception: Java<'static>,
+    unsupported_operation: Java<'static>,
+    completable_future: Java<'static>,
+    object_mapper: Java<'static>,
+    type_reference: Java<'static>,
+    client: Java<'static>,
+    http_url: Java<'static>,
+    request: Java<'static>,
+    request_body: Java<'static>,
+    media_type: Java<'static>,
+    call: Java<'static>,
+    callback: Java<'static>,
+    response: Java<'static>,
+    response_body: Java<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {
+            snake_to_upper_camel: SnakeCase::new().to_upper_camel(),
+            string: imported("java.lang", "String"),
+            io_exception: imported("java.io", "IOException"),
+            unsupported_operation: imported("java.lang", "UnsupportedOperationException"),
+            completable_future: imported("java.util.concurrent", "CompletableFuture"),
+            object_mapper: imported("com.fasterxml.jackson.databind", "ObjectMapper"),
+            type_reference: imported("com.fasterxml.jackson.core.type", "TypeReference"),
+            client: imported("okhttp3", "OkHttpClient"),
+            http_url: imported("okhttp3", "HttpUrl"),
+            request: imported("okhttp3", "Request"),
+            request_body: imported("okhttp3", "RequestBody"),
+            media_type: imported("okhttp3", "MediaType"),
+            call: imported("okhttp3", "Call"),
+            callback: imported("okhttp3", "Callback"),
+            response: imported("okhttp3", "Response"),
+            response_body: imported("okhttp3", "ResponseBody"),
+        }
+    }
+
+    /// Build the statement declaring the `url` variable for the given binding.
+    fn url<'el>(&self, http: &'el RpEndpointHttp) -> Tokens<'el, Java<'el>> {
+        let mut t = Tokens::new();
+        t.push(toks!["final ", self.http_url.clone(), " url = this.baseUrl.newBuilder()"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            for segment in &http.path.segments {
+                match *segment {
+                    RpPathSegment::Literal { ref value } => {
+                        t.push(toks![".addPathSegment(", value.as_str().quoted(), ")"]);
+                    }
+                    RpPathSegment::Variable { ref name } => {
+                        let getter = Rc::new(format!(
+                            "get{}",
+                            self.snake_to_upper_camel.convert(name.as_str())
+                        ));
+
+                        t.push(toks![
+                            ".addPathSegment(",
+                            self.string.clone(),
+                            ".valueOf(request.",
+                            getter,
+                            "()))",
+                        ]);
+                    }
+                }
+            }
+
+            t.push(".build();");
+            t
+        });
+
+        t
+    }
+
+    /// Build the client method for a single endpoint.
+    fn endpoint_method<'el>(
+        &self,
+        backend: &JavaBackend,
+        name: Cons<'el>,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Method<'el>> {
+        let response_ty = if let Some(res) = endpoint.response.as_ref() {
+            backend.into_java_type(res.ty())?
+        } else {
+            backend.void.clone()
+        };
+
+        let future_ty = self.completable_future.with_arguments(vec![response_ty.clone()]);
+
+        let mut method = Method::new(name);
+        method.modifiers = vec![Modifier::Public];
+        method.returns = future_ty.clone();
+
+        if let Some(req) = endpoint.request.as_ref() {
+            let ty = backend.into_java_type(req.ty())?;
+            method.arguments.push(Argument::new(ty, "request"));
+        }
+
+        let http = match endpoint.http {
+            Some(ref http) => http,
+            None => {
+                method.body.push(toks![
+                    "final ", future_ty.clone(), " future = new ", future_ty.clone(), "();",
+                ]);
+
+                method.body.push(toks![
+                    "future.completeExceptionally(new ",
+                    self.unsupported_operation.clone(),
+                    "(",
+                    "endpoint does not have an http binding".quoted(),
+                    "));",
+                ]);
+
+                method.body.push("return future;");
+                return Ok(method);
+            }
+        };
+
+        method.body.push(self.url(http));
+
+        let body = if http.method.has_body() && endpoint.request.is_some() {
+            method.body.push(toks!["final ", self.request_body.clone(), " requestBody;"]);
+            method.body.push({
+                let mut t = Tokens::new();
+                t.push("try {");
+                t.nested(toks![
+                    "requestBody = ", self.request_body.clone(),
+                    ".create(JSON, this.mapper.writeValueAsBytes(request));",
+                ]);
+                t.push("} catch (final Exception e) {");
+                t.nested(toks![
+                    "final ", future_ty.clone(), " future = new ", future_ty.clone(), "();",
+                ]);
+                t.nested("future.completeExceptionally(e);");
+                t.nested("return future;");
+                t.push("}");
+                t
+            });
+
+            "requestBody"
+        } else if http.method.has_body() {
+            method.body.push(toks![
+                "final ", self.request_body.clone(), " requestBody = ", self.request_body.clone(),
+                ".create(null, new byte[0]);",
+            ]);
+
+            "requestBody"
+        } else {
+            "null"
+        };
+
+        method.body.push({
+            let mut t = Tokens::new();
+            t.push(toks![
+                "final ", self.request.clone(), " req = new ", self.request.clone(), ".Builder()",
+            ]);
+            t.nested(".url(url)");
+            t.nested(toks![".method(", http.method.as_str().quoted(), ", ", body, ")"]);
+            t.nested(".build();");
+            t
+        });
+
+        method.body.push(toks![
+            "final ", future_ty.clone(), " future = new ", future_ty.clone(), "();",
+        ]);
+
+        let status_check = match endpoint.http_status()? {
+            Some(status) => toks!["if (response.code() != ", Rc::new(status.to_string()), ") {"],
+            None => toks!["if (!response.isSuccessful()) {"],
+        };
+
+        let decode = if response_ty != backend.void {
+            toks![
+                "future.complete(", CLIENT_NAME, ".this.mapper.readValue(body.bytes(), new ",
+                self.type_reference.with_arguments(vec![response_ty.clone()]), "(){}));",
+            ]
+        } else {
+            toks!["future.complete(null);"]
+        };
+
+        method.body.push({
+            let mut t = Tokens::new();
+
+            t.push(toks!["this.client.newCall(req).enqueue(new ", self.callback.clone(), "() {"]);
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push({
+                    let mut t = Tokens::new();
+                    t.push(toks![
+                        "public void onFailure(final ", self.call.clone(), " call, final ",
+                        self.io_exception.clone(), " e) {",
+                    ]);
+                    t.nested("future.completeExceptionally(e);");
+                    t.push("}");
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+                    t.push(toks![
+                        "public void onResponse(final ", self.call.clone(), " call, final ",
+                        self.response.clone(), " response) {",
+                    ]);
+                    t.nested({
+                        let mut t = Tokens::new();
+                        t.push(toks![
+                            "try (final ", self.response_body.clone(), " body = response.body()) {",
+                        ]);
+                        t.nested({
+                            let mut t = Tokens::new();
+                            t.push(status_check);
+                            t.nested(toks![
+                                "throw new ", self.io_exception.clone(), "(",
+                                "bad response: ".quoted(), " + response);",
+                            ]);
+                            t.push("}");
+                            t.push(decode);
+                            t
+                        });
+                        t.push("} catch (final Exception e) {");
+                        t.nested("future.completeExceptionally(e);");
+                        t.push("}");
+                        t
+                    });
+                    t.push("}");
+                    t
+                });
+
+                t.join_line_spacing()
+            });
+
+            t.push("});");
+            t
+        });
+
+        method.body.push("return future;");
+        Ok(method)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        use self::Modifier::*;
+
+        let mut class = Class::new(CLIENT_NAME);
+        class.modifiers = vec![Public, Static];
+        class.implements = vec![local(e.body.local_name.as_str())];
+
+        let mut json = Field::new(self.media_type.clone(), "JSON");
+        json.modifiers = vec![Private, Static, Final];
+        json.initializer(toks![
+            self.media_type.clone(),
+            ".parse(",
+            "application/json; charset=utf-8".quoted(),
+            ")",
+        ]);
+
+        let client = Field::new(self.client.clone(), "client");
+        let base_url = Field::new(self.http_url.clone(), "baseUrl");
+        let mapper = Field::new(self.object_mapper.clone(), "mapper");
+
+        class.constructors.push({
+            let mut c = Constructor::new();
+
+            for field in &[&client, &base_url, &mapper] {
+                c.arguments.push(Argument::new(field.ty(), field.var()));
+                c.body.push(toks!["this.", field.var(), " = ", field.var(), ";"]);
+            }
+
+            c
+        });
+
+        class.fields.push(json);
+        class.fields.push(client);
+        class.fields.push(base_url);
+        class.fields.push(mapper);
+
+        for (endpoint, name) in e.body.endpoints.values().zip(
+            e.endpoint_names.iter().cloned(),
+        )
+        {
+            class.methods.push(self.endpoint_method(e.backend, name, endpoint)?);
+        }
+
+        e.spec.body.push(class);
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+//! Module that builds a client using `fetch` for services with HTTP bindings.
+
+use backend::{DynamicDecode, DynamicEncode};
+use backend::errors::*;
+use core::{RpEndpoint, RpEndpointHttp, RpPathSegment};
+use genco::{JavaScript, Quoted, Tokens};
+use js_backend::JsBackend;
+use listeners::{Listeners, ServiceAdded};
+use std::rc::Rc;
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+
+    /// Build the expression for the URL of the given binding.
+    fn url<'el>(
+        &self,
+        backend: &JsBackend,
+        http: &'el RpEndpointHttp,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut url = Tokens::new();
+        url.append("this.url");
+
+        let mut literal = String::new();
+
+        for segment in &http.path.segments {
+            literal.push('/');
+
+            match *segment {
+                RpPathSegment::Literal { ref value } => {
+                    literal.push_str(value.as_str());
+                }
+                RpPathSegment::Variable { ref name } => {
+                    url.append(Rc::new(literal.clone()).quoted());
+                    literal.clear();
+
+                    let ident = Rc::new(backend.ident(name.as_str()));
+                    url.append(toks!["encodeURIComponent(String(request.", ident, "))"]);
+                }
+            }
+        }
+
+        if !literal.is_empty() {
+            url.append(Rc::new(literal).quoted());
+        }
+
+        url.join(" + ")
+    }
+
+    fn endpoint<'el>(
+        &self,
+        backend: &JsBackend,
+        endpoint: &'el RpEndpoint,
+        http: &'el RpEndpointHttp,
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let mut t = Tokens::new();
+
+        let args = if endpoint.request.is_some() {
+            "request"
+        } else {
+            ""
+        };
+
+        t.push(toks![endpoint.id.as_str(), "(", args, ") {"]);
+
+        t.nested({
+            let mut body = Tokens::new();
+
+            body.push(toks!["const url = ", self.url(backend, http), ";"]);
+
+            let mut init = Vec::new();
+            init.push(toks!["method: ", http.method.as_str().quoted()]);
+
+            match endpoint.request {
+                Some(ref req) if http.method.has_body() => {
+                    let encode = backend.dynamic_encode(req.ty(), toks!["request"])?;
+
+                    init.push(toks![
+                        "headers: {",
+                        "Content-Type".quoted(),
+                        ": ",
+                        "application/json".quoted(),
+                        "}",
+                    ]);
+
+                    init.push(toks!["body: JSON.stringify(", encode, ")"]);
+                }
+                _ => {}
+            }
+
+            let mut call = Tokens::new();
+            call.push("return this.fetch(url, {");
+            call.nested({
+                let mut t = Tokens::new();
+                let last = init.len() - 1;
+
+                for (i, entry) in init.into_iter().enumerate() {
+                    if i < last {
+                        t.push(toks![entry, ","]);
+                    } else {
+                        t.push(entry);
+                    }
+                }
+
+                t
+            });
+            call.push("}).then(response => {");
+
+            call.nested({
+                let mut t = Tokens::new();
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    match endpoint.http_status()? {
+                        Some(status) => {
+                            let status = Rc::new(status.to_string());
+                            t.push(toks!["if (response.status !== ", status, ") {"]);
+                        }
+                        None => {
+                            t.push("if (!response.ok) {");
+                        }
+                    }
+
+                    t.nested(toks![
+                        "throw new Error(",
+                        "bad response: ".quoted(),
+                        " + response.status);",
+                    ]);
+                    t.push("}");
+                    t
+                });
+
+                if let Some(ref res) = endpoint.response {
+                    let decode = backend.dynamic_decode(res.ty(), toks!["data"])?;
+                    t.push(toks!["return response.json().then(data => ", decode, ");"]);
+                }
+
+                t.join_line_spacing()
+            });
+
+            call.push("});");
+            body.push(call);
+
+            body.join_line_spacing()
+        });
+
+        t.push("}");
+        Ok(t)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        let name = Rc::new(format!("{}_Fetch", e.type_name));
+
+        let mut class_body = Tokens::new();
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push("constructor(url, fetchImpl) {");
+            t.nested(toks!["this.url = url.replace(/\\/+$/, ", "".quoted(), ");"]);
+            t.nested(
+                "this.fetch = fetchImpl !== undefined ? fetchImpl : (u, init) => fetch(u, init);",
+            );
+            t.push("}");
+            t
+        });
+
+        for endpoint in e.body.endpoints.values() {
+            if let Some(ref http) = endpoint.http {
+                class_body.push(self.endpoint(e.backend, endpoint, http)?);
+            }
+        }
+
+        let mut class = Tokens::new();
+        class.push(toks!["export class ", name, " {"]);
+        class.nested(class_body.join_line_spacing());
+        class.push("}");
+
+        e.out.push(class);
+        Ok(())
+    }
+}
@@ -1,16 +1,16 @@
-use super::{JS_CONTEXT, TYPE, TYPE_SEP};
+use super::{JS_CONTEXT, TYPE_SEP};
 use backend::{Code, CompilerOptions, Converter, DynamicConverter, DynamicDecode, DynamicEncode,
-              Environment, FromNaming, Naming, PackageUtils, SnakeCase};
+              Environment, FromNaming, Naming, PackageUtils, SnakeCase, ValueBuilder};
 use backend::errors::*;
-use core::{ForEachLoc, Loc, RpEnumBody, RpField, RpInterfaceBody, RpModifier, RpName, RpTupleBody,
-           RpType, RpTypeBody};
+use core::{ForEachLoc, Loc, RpEnumBody, RpField, RpInterfaceBody, RpModifier, RpName,
+           RpServiceBody, RpTupleBody, RpType, RpTypeBody, RpTypeInfo};
 use genco::{Element, JavaScript, Quoted, Tokens};
 use genco::js::imported_alias;
 use js_compiler::JsCompiler;
 use js_field::JsField;
 use js_file_spec::JsFileSpec;
 use js_options::JsOptions;
-use listeners::Listeners;
+use listeners::{Listeners, ServiceAdded};
 use std::borrow::Cow;
 use std::rc::Rc;
 use utils::{is_defined, is_not_defined};
@@ -20,7 +20,6 @@ pub struct JsBackend {
     listeners: Box<Listeners>,
     id_converter: Option<Box<Naming>>,
     to_lower_snake: Box<Naming>,
-    type_var: Tokens<'static, JavaScript<'static>>,
     values: Tokens<'static, JavaScript<'static>>,
     enum_name: Tokens<'static, JavaScript<'static>>,
 }
@@ -37,7 +36,6 @@ impl JsBackend {
             listeners: listeners,
             id_converter: id_converter,
             to_lower_snake: SnakeCase::new().to_lower_snake(),
-            type_var: TYPE.quoted().into(),
             values: "values".into(),
             enum_name: "name".into(),
         }
@@ -64,6 +62,7 @@ impl JsBackend {
         fields: &[Loc<JsField<'el>>],
         builder: B,
         extra: Option<Tokens<'el, JavaScript<'el>>>,
+        result: Tokens<'el, JavaScript<'el>>,
     ) -> Result<Tokens<'el, JavaScript<'el>>>
     where
         B: Into<Tokens<'el, JavaScript<'el>>>,
@@ -103,7 +102,7 @@ impl JsBackend {
             body.push(assign.join_line_spacing());
         }
 
-        body.push(js![return "data"]);
+        body.push(js![return result]);
 
         Ok({
             let mut t = Tokens::new();
@@ -191,9 +190,31 @@ impl JsBackend {
                     let mut check = Tokens::new();
 
                     check.push(toks!["let ", var_name.clone(), " = data[", var, "];"]);
+                    let absent = match field.default {
+                        Some(default) => self.value(field.ty, default.value()),
+                        None => toks!["null"],
+                    };
+
                     check.push(js![if is_defined(var_name.clone()),
                                       toks![var_name.clone(), " = ", var_toks, ";"],
-                                      toks![var_name, " = null", ";"]]);
+                                      toks![var_name.clone(), " = ", absent, ";"]]);
+
+                    let checks = self.constraints_check(
+                        field.name,
+                        field.ty,
+                        field.constraints,
+                        var_name.clone(),
+                    );
+
+                    if !checks.is_empty() {
+                        let mut check_body = Tokens::new();
+
+                        for c in checks {
+                            check_body.push(c);
+                        }
+
+                        check.push(js![if toks![var_name, " !== null"], check_body]);
+                    }
 
                     Ok(check.join_line_spacing().into()) as Result<Tokens<'el, JavaScript<'el>>>
                 }
@@ -206,9 +227,19 @@ impl JsBackend {
                     let var_name = toks![var_name.clone()];
 
                     check.push(toks!["const ", var_name.clone(), " = ", var_toks, ";"]);
-                    check.push(js![if is_not_defined(var_name),
+                    check.push(js![if is_not_defined(var_name.clone()),
                                    js![throw var, " + ", ": required field".quoted()]]);
 
+                    for c in self.constraints_check(
+                        field.name,
+                        field.ty,
+                        field.constraints,
+                        var_name,
+                    )
+                    {
+                        check.push(c);
+                    }
+
                     Ok(check.join_line_spacing().into()) as Result<Tokens<'el, JavaScript<'el>>>
                 }
             })?;
@@ -240,14 +271,18 @@ impl JsBackend {
         i.to_string().into()
     }
 
-    fn field_ident(&self, field: &RpField) -> String {
+    pub fn ident(&self, name: &str) -> String {
         if let Some(ref id_converter) = self.id_converter {
-            id_converter.convert(field.ident())
+            id_converter.convert(name)
         } else {
-            field.ident().to_owned()
+            name.to_owned()
         }
     }
 
+    fn field_ident(&self, field: &RpField) -> String {
+        self.ident(field.ident())
+    }
+
     fn build_constructor<'el>(&self, fields: &[Loc<JsField<'el>>]) -> Tokens<'el, JavaScript<'el>> {
         let mut arguments = Tokens::new();
         let mut assignments = Tokens::new();
@@ -360,6 +395,8 @@ impl JsBackend {
         js_field_f(JsField {
             modifier: &field.modifier,
             ty: &field.ty,
+            constraints: &field.constraints,
+            default: field.default.as_ref(),
             name: field.name(),
             ident: Rc::new(ident),
         })
@@ -503,7 +540,7 @@ impl JsBackend {
             Self::field_by_name,
         )?);
 
-        class_body.push(self.encode_method(&fields, "{}", None)?);
+        class_body.push(self.encode_method(&fields, "{}", None, toks!["data"])?);
         class_body.push_unless_empty(Code(&body.codes, JS_CONTEXT));
 
         let mut class = Tokens::new();
@@ -574,16 +611,18 @@ impl JsBackend {
                 Self::field_by_name,
             )?);
 
-            let type_toks =
-                toks![
-                "data[",
-                self.type_var.clone(),
-                "] = ",
-                interface_type_name.clone(),
-                ".TYPE;",
-            ];
+            let type_toks = toks![type_name.clone(), ".TYPE"];
 
-            class_body.push(self.encode_method(&fields, "{}", Some(type_toks))?);
+            let (extra, result) = match body.type_info {
+                RpTypeInfo::TypeField { ref name } => {
+                    let extra = toks!["data[", name.as_str().quoted(), "] = ", type_toks, ";"];
+                    (Some(extra), toks!["data"])
+                }
+                RpTypeInfo::Array => (None, toks!["[", type_toks, ", data]"]),
+                RpTypeInfo::ObjectKeys => (None, toks!["{[", type_toks, "]: data}"]),
+            };
+
+            class_body.push(self.encode_method(&fields, "{}", extra, result)?);
             class_body.push_unless_empty(Code(&sub_type.codes, JS_CONTEXT));
 
             classes.push({
@@ -597,10 +636,10 @@ impl JsBackend {
             });
 
             classes.push(toks![
-                interface_type_name.clone(),
+                type_name.clone(),
                 ".TYPE",
                 " = ",
-                type_name.quoted(),
+                sub_type.name().quoted(),
                 ";",
             ]);
 
@@ -610,6 +649,23 @@ impl JsBackend {
         out.0.push(classes.join_line_spacing());
         Ok(())
     }
+
+    pub fn process_service<'el>(
+        &self,
+        out: &mut JsFileSpec<'el>,
+        body: &'el RpServiceBody,
+    ) -> Result<()> {
+        let type_name = Rc::new(body.name.join(TYPE_SEP));
+
+        self.listeners.service_added(&mut ServiceAdded {
+            backend: self,
+            body: body,
+            type_name: type_name,
+            out: &mut out.0,
+        })?;
+
+        Ok(())
+    }
 }
 
 impl PackageUtils for JsBackend {}
@@ -707,21 +763,26 @@ impl<'el> DynamicDecode<'el> for JsBackend {
         t
     }
 
-    fn assign_type_var(&self, data: &'el str, type_var: &'el str) -> Tokens<'el, JavaScript<'el>> {
-        toks![
-            "const ",
-            type_var,
-            " = ",
-            data,
-            "[",
-            self.type_var.clone(),
-            "]",
-        ]
+    fn assign_type_var(
+        &self,
+        data: &'el str,
+        type_var: &'el str,
+        type_info: &'el RpTypeInfo,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        use self::RpTypeInfo::*;
+
+        let value = match *type_info {
+            TypeField { ref name } => toks![data, "[", name.as_str().quoted(), "]"],
+            Array => toks![data, "[0]"],
+            ObjectKeys => toks!["Object.keys(", data, ")[0]"],
+        };
+
+        toks!["const ", type_var, " = ", value, ";"]
     }
 
     fn check_type_var(
         &self,
-        data: &'el str,
+        data: Tokens<'el, JavaScript<'el>>,
         type_var: &'el str,
         name: &'el Loc<String>,
         type_name: Tokens<'el, Self::Custom>,
@@ -736,6 +797,33 @@ impl<'el> DynamicDecode<'el> for JsBackend {
         js![throw "bad type: ".quoted(), " + ", type_var]
     }
 
+    fn length_of(
+        &self,
+        ty: &'el RpType,
+        var: Tokens<'el, Self::Custom>,
+    ) -> Tokens<'el, Self::Custom> {
+        match *ty {
+            RpType::Map { .. } => toks!["Object.keys(", var, ").length"],
+            _ => toks![var, ".length"],
+        }
+    }
+
+    fn pattern_mismatch(
+        &self,
+        var: Tokens<'el, Self::Custom>,
+        pattern: &'el str,
+    ) -> Tokens<'el, Self::Custom> {
+        toks!["!new RegExp(", pattern.quoted(), ").test(", var, ")"]
+    }
+
+    fn raise_constraint(
+        &self,
+        condition: Tokens<'el, Self::Custom>,
+        message: String,
+    ) -> Tokens<'el, Self::Custom> {
+        js![if condition, js![throw message.quoted()]]
+    }
+
     fn new_decode_method(
         &self,
         data: &'el str,
@@ -785,3 +873,25 @@ impl<'el> DynamicEncode<'el> for JsBackend {
         t
     }
 }
+
+impl<'el> ValueBuilder<'el> for JsBackend {
+    type Output = Tokens<'el, JavaScript<'el>>;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        toks![Rc::new(literal)]
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        toks![string.to_string().quoted()]
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        let mut args = Tokens::new();
+
+        for item in items {
+            args.append(item);
+        }
+
+        toks!["[", args.join(", "), "]"]
+    }
+}
@@ -3,7 +3,7 @@
 use super::EXT;
 use backend::{Environment, PackageProcessor, PackageUtils};
 use backend::errors::*;
-use core::{Loc, RpEnumBody, RpInterfaceBody, RpPackage, RpTupleBody, RpTypeBody,
+use core::{Loc, RpEnumBody, RpInterfaceBody, RpPackage, RpServiceBody, RpTupleBody, RpTypeBody,
            RpVersionedPackage};
 use js_backend::JsBackend;
 use js_file_spec::JsFileSpec;
@@ -59,4 +59,8 @@ impl<'el> PackageProcessor<'el> for JsCompiler<'el> {
     ) -> Result<()> {
         self.backend.process_interface(out, body)
     }
+
+    fn process_service(&self, out: &mut Self::Out, body: &'el Loc<RpServiceBody>) -> Result<()> {
+        self.backend.process_service(out, body)
+    }
 }
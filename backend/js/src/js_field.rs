@@ -1,10 +1,12 @@
-use core::{RpModifier, RpType};
+use core::{Loc, RpConstraints, RpModifier, RpType, RpValue};
 use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct JsField<'a> {
     pub modifier: &'a RpModifier,
     pub ty: &'a RpType,
+    pub constraints: &'a RpConstraints,
+    pub default: Option<&'a Loc<RpValue>>,
     pub name: &'a str,
     pub ident: Rc<String>,
 }
@@ -14,6 +16,8 @@ impl<'a> JsField<'a> {
         JsField {
             modifier: self.modifier,
             ty: self.ty,
+            constraints: self.constraints,
+            default: self.default,
             name: self.name,
             ident: Rc::new(ident),
         }
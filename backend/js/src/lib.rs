@@ -17,6 +17,8 @@ mod js_backend;
 mod js_compiler;
 mod js_file_spec;
 mod js_options;
+mod fetch;
+mod rpc;
 
 use self::backend::{ArgMatches, CompilerOptions, Environment, Options};
 use self::backend::errors::*;
@@ -26,11 +28,13 @@ use self::listeners::Listeners;
 use manifest::{Lang, Manifest, NoModule, TryFromToml, self as m};
 use std::path::Path;
 
-const TYPE: &str = "type";
 const TYPE_SEP: &str = "_";
 const EXT: &str = "js";
 const JS_CONTEXT: &str = "js";
 
+/// Endpoint options understood by the backend.
+const ENDPOINT_OPTIONS: &[&str] = &["http_status"];
+
 #[derive(Default)]
 pub struct JsLang;
 
@@ -40,24 +44,48 @@ impl Lang for JsLang {
 
 #[derive(Debug)]
 pub enum JsModule {
+    Fetch,
+    Rpc,
 }
 
 impl TryFromToml for JsModule {
     fn try_from_string(path: &Path, id: &str, value: String) -> m::errors::Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::JsModule::*;
+
+        let result = match id {
+            "fetch" => Fetch,
+            "rpc" => Rpc,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 
     fn try_from_value(path: &Path, id: &str, value: toml::Value) -> m::errors::Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::JsModule::*;
+
+        let result = match id {
+            "fetch" => Fetch,
+            "rpc" => Rpc,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 }
 
 fn setup_listeners(modules: &[JsModule]) -> Result<(JsOptions, Box<Listeners>)> {
-    let listeners: Vec<Box<Listeners>> = Vec::new();
+    use self::JsModule::*;
+
+    let mut listeners: Vec<Box<Listeners>> = Vec::new();
 
     for module in modules {
-        match *module {
-        }
+        let listener = match *module {
+            Fetch => Box::new(fetch::Module::new()) as Box<Listeners>,
+            Rpc => Box::new(rpc::Module::new()) as Box<Listeners>,
+        };
+
+        listeners.push(listener);
     }
 
     let mut options = JsOptions::new();
@@ -76,6 +104,7 @@ pub fn compile(
     _matches: &ArgMatches,
     manifest: Manifest<JsLang>,
 ) -> Result<()> {
+    env.verify_endpoint_options(ENDPOINT_OPTIONS)?;
     let id_converter = opts.id_converter;
     let (options, listeners) = setup_listeners(&manifest.modules)?;
     let backend = JsBackend::new(env, options, listeners, id_converter);
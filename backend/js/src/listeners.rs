@@ -1,9 +1,23 @@
 use super::*;
+use core::RpServiceBody;
+use genco::{JavaScript, Tokens};
+use std::rc::Rc;
+
+pub struct ServiceAdded<'a, 'el: 'a> {
+    pub backend: &'a JsBackend,
+    pub body: &'el RpServiceBody,
+    pub type_name: Rc<String>,
+    pub out: &'a mut Tokens<'el, JavaScript<'el>>,
+}
 
 pub trait Listeners {
     fn configure(&self, _: &mut JsOptions) -> Result<()> {
         Ok(())
     }
+
+    fn service_added(&self, _: &mut ServiceAdded) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// A vector of listeners is a valid listener.
@@ -15,4 +29,12 @@ impl Listeners for Vec<Box<Listeners>> {
 
         Ok(())
     }
+
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        for listeners in self {
+            listeners.service_added(e)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,272 @@
+//! Module that builds a client for services.
+//!
+//! Requests and responses are exchanged as iterables of encoded values through a transport
+//! provided by the user.
+
+use backend::{DynamicDecode, DynamicEncode};
+use backend::errors::*;
+use core::{ForEachLoc, RpEndpoint};
+use genco::{JavaScript, Quoted, Tokens};
+use js_backend::JsBackend;
+use listeners::{Listeners, ServiceAdded};
+use std::rc::Rc;
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+
+    /// Static helpers of the service client, used to adapt iterables.
+    ///
+    /// Iterables can either be synchronous or asynchronous. `_drain` is only needed by streaming
+    /// endpoints without a response.
+    fn helpers<'el>(&self, name: Rc<String>, drain: bool) -> Vec<Tokens<'el, JavaScript<'el>>> {
+        let mut helpers = Vec::new();
+
+        helpers.push({
+            let mut t = Tokens::new();
+            t.push("static _iter(iterable) {");
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push({
+                    let mut t = Tokens::new();
+                    t.push("if (iterable[Symbol.asyncIterator] !== undefined) {");
+                    t.nested("return iterable[Symbol.asyncIterator]();");
+                    t.push("}");
+                    t
+                });
+
+                t.push("return iterable[Symbol.iterator]();");
+                t.join_line_spacing()
+            });
+            t.push("}");
+            t
+        });
+
+        helpers.push({
+            let mut t = Tokens::new();
+            t.push("static _map(iterable, f) {");
+            t.nested({
+                let mut t = Tokens::new();
+                t.push(toks!["const it = ", name.clone(), "._iter(iterable);"]);
+
+                t.push({
+                    let mut t = Tokens::new();
+                    t.push("return {");
+                    t.nested({
+                        let mut t = Tokens::new();
+                        t.push("[Symbol.asyncIterator]() {");
+                        t.nested("return this;");
+                        t.push("},");
+                        t.push("next() {");
+                        t.nested({
+                            let mut t = Tokens::new();
+                            t.push("return Promise.resolve(it.next()).then(r => {");
+                            t.nested("return r.done ? r : {done: false, value: f(r.value)};");
+                            t.push("});");
+                            t
+                        });
+                        t.push("}");
+                        t
+                    });
+                    t.push("};");
+                    t
+                });
+
+                t.join_line_spacing()
+            });
+            t.push("}");
+            t
+        });
+
+        helpers.push({
+            let mut t = Tokens::new();
+            t.push("static _first(iterable) {");
+            t.nested({
+                let mut t = Tokens::new();
+                t.push(toks![
+                    "return Promise.resolve(",
+                    name.clone(),
+                    "._iter(iterable).next()).then(r => {",
+                ]);
+                t.nested(js![if toks!["r.done"], js![throw "missing response".quoted()]]);
+                t.nested("return r.value;");
+                t.push("});");
+                t
+            });
+            t.push("}");
+            t
+        });
+
+        if !drain {
+            return helpers;
+        }
+
+        helpers.push({
+            let mut t = Tokens::new();
+            t.push("static _drain(iterable) {");
+            t.nested({
+                let mut t = Tokens::new();
+                t.push(toks!["const it = ", name.clone(), "._iter(iterable);"]);
+                t.push(
+                    "const next = () => Promise.resolve(it.next()).then(r => r.done ? undefined : next());",
+                );
+                t.push("return next();");
+                t.join_line_spacing()
+            });
+            t.push("}");
+            t
+        });
+
+        helpers
+    }
+
+    /// Build the client method for the given endpoint.
+    ///
+    /// Endpoints without streaming channels are sent through `transport.unary`, everything else
+    /// through `transport.stream`.
+    fn client_method<'el>(
+        &self,
+        backend: &JsBackend,
+        name: Rc<String>,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let id = endpoint.id.as_str();
+        let endpoint_name = endpoint.name().quoted();
+
+        let streaming = endpoint.request.as_ref().map(|r| r.is_streaming()).unwrap_or(false) ||
+            endpoint.response.as_ref().map(|r| r.is_streaming()).unwrap_or(false);
+
+        let mut body = Tokens::new();
+
+        if !streaming {
+            let request = match endpoint.request {
+                Some(ref req) => backend.dynamic_encode(req.ty(), toks!["request"])?,
+                None => toks!["undefined"],
+            };
+
+            let response = match endpoint.response {
+                Some(ref res) => backend.dynamic_decode(res.ty(), toks!["data"])?,
+                None => toks!["undefined"],
+            };
+
+            body.push(toks![
+                "return this.transport.unary(",
+                endpoint_name,
+                ", ",
+                request,
+                ").then(data => ",
+                response,
+                ");",
+            ]);
+        } else {
+            let requests = match endpoint.request {
+                Some(ref req) if req.is_streaming() => {
+                    let encode = backend.dynamic_encode(req.ty(), toks!["v"])?;
+                    toks![name.clone(), "._map(request, v => ", encode, ")"]
+                }
+                Some(ref req) => {
+                    let encode = backend.dynamic_encode(req.ty(), toks!["request"])?;
+                    toks!["[", encode, "]"]
+                }
+                None => toks!["[]"],
+            };
+
+            body.push(toks![
+                "const responses = this.transport.stream(",
+                endpoint_name,
+                ", ",
+                requests,
+                ");",
+            ]);
+
+            match endpoint.response {
+                Some(ref res) if res.is_streaming() => {
+                    let decode = backend.dynamic_decode(res.ty(), toks!["v"])?;
+                    body.push(toks![
+                        "return ",
+                        name.clone(),
+                        "._map(responses, v => ",
+                        decode,
+                        ");",
+                    ]);
+                }
+                Some(ref res) => {
+                    let decode = backend.dynamic_decode(res.ty(), toks!["data"])?;
+                    body.push(toks![
+                        "return ",
+                        name.clone(),
+                        "._first(responses).then(data => ",
+                        decode,
+                        ");",
+                    ]);
+                }
+                None => {
+                    body.push(toks!["return ", name.clone(), "._drain(responses);"]);
+                }
+            }
+        }
+
+        let args = if endpoint.request.is_some() {
+            "request"
+        } else {
+            ""
+        };
+
+        let mut t = Tokens::new();
+        t.push(toks![id, "(", args, ") {"]);
+        t.nested(body);
+        t.push("}");
+        Ok(t)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        let name = Rc::new(format!("{}_Client", e.type_name));
+
+        let mut class_body = Tokens::new();
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push("constructor(transport) {");
+            t.nested("this.transport = transport;");
+            t.push("}");
+            t
+        });
+
+        let mut has_streaming = false;
+        let mut has_drain = false;
+
+        let endpoints = e.body.endpoints.values().map(|l| l.loc_ref());
+
+        endpoints.for_each_loc(|endpoint| {
+            let streaming = endpoint.request.as_ref().map(|r| r.is_streaming()).unwrap_or(false) ||
+                endpoint.response.as_ref().map(|r| r.is_streaming()).unwrap_or(false);
+
+            has_streaming = has_streaming || streaming;
+            has_drain = has_drain || (streaming && endpoint.response.is_none());
+
+            class_body.push(self.client_method(e.backend, name.clone(), endpoint)?);
+            Ok(()) as Result<()>
+        })?;
+
+        if has_streaming {
+            for helper in self.helpers(name.clone(), has_drain) {
+                class_body.push(helper);
+            }
+        }
+
+        let mut class = Tokens::new();
+        class.push(toks!["export class ", name, " {"]);
+        class.nested(class_body.join_line_spacing());
+        class.push("}");
+
+        e.out.push(class);
+        Ok(())
+    }
+}
+
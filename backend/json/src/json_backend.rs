@@ -6,20 +6,31 @@ use core::RpPackage;
 use json_compiler::JsonCompiler;
 use json_options::JsonOptions;
 use listeners::Listeners;
+use schema_compiler::SchemaCompiler;
 
 pub struct JsonBackend {
     pub env: Environment,
+    options: JsonOptions,
     listeners: Box<Listeners>,
 }
 
 impl JsonBackend {
-    pub fn new(env: Environment, _options: JsonOptions, listeners: Box<Listeners>) -> JsonBackend {
+    pub fn new(env: Environment, options: JsonOptions, listeners: Box<Listeners>) -> JsonBackend {
         JsonBackend {
             env: env,
+            options: options,
             listeners: listeners,
         }
     }
 
+    pub fn compile(&self, options: CompilerOptions) -> Result<()> {
+        if self.options.json_schema {
+            return self.schema_compiler(options)?.compile();
+        }
+
+        self.compiler(options)?.compile()
+    }
+
     pub fn compiler(&self, options: CompilerOptions) -> Result<JsonCompiler> {
         Ok(JsonCompiler {
             out_path: options.out_path,
@@ -27,6 +38,13 @@ impl JsonBackend {
         })
     }
 
+    pub fn schema_compiler(&self, options: CompilerOptions) -> Result<SchemaCompiler> {
+        Ok(SchemaCompiler {
+            out_path: options.out_path,
+            processor: self,
+        })
+    }
+
     pub fn package_file(&self, package: &RpPackage) -> String {
         package.parts.join("_")
     }
@@ -1,9 +1,12 @@
 //! Options for JSON
 
-pub struct JsonOptions {}
+pub struct JsonOptions {
+    /// Emit draft-07 JSON Schema documents.
+    pub json_schema: bool,
+}
 
 impl JsonOptions {
     pub fn new() -> JsonOptions {
-        JsonOptions {}
+        JsonOptions { json_schema: false }
     }
 }
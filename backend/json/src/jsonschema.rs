@@ -0,0 +1,20 @@
+//! Module that emits JSON Schema documents instead of the plain declaration dump.
+
+use backend::errors::*;
+use json_options::JsonOptions;
+use listeners::Listeners;
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+}
+
+impl Listeners for Module {
+    fn configure(&self, options: &mut JsonOptions) -> Result<()> {
+        options.json_schema = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use backend::IntoBytes;
+use backend::errors::*;
+use schema_compiler::{SCHEMA, SchemaCompiler};
+use serde_json::{self, Map, Value};
+
+/// Collects all definitions of a single package into one schema document.
+pub struct SchemaCollector {
+    pub definitions: Map<String, Value>,
+}
+
+impl Default for SchemaCollector {
+    fn default() -> Self {
+        SchemaCollector { definitions: Map::new() }
+    }
+}
+
+impl<'a> IntoBytes<SchemaCompiler<'a>> for SchemaCollector {
+    fn into_bytes(self, _: &SchemaCompiler<'a>) -> Result<Vec<u8>> {
+        let mut document = Map::new();
+        document.insert("$schema".to_string(), Value::String(SCHEMA.to_string()));
+        document.insert("definitions".to_string(), Value::Object(self.definitions));
+
+        let mut out = serde_json::to_string_pretty(&Value::Object(document))?;
+        out.push('\n');
+        Ok(out.into_bytes())
+    }
+}
@@ -0,0 +1,332 @@
+//! Compiler for JSON Schema
+
+use backend::{Environment, PackageProcessor, PackageUtils, ValueBuilder};
+use backend::errors::*;
+use core::{Loc, RpConstraints, RpEnumBody, RpEnumType, RpField, RpInterfaceBody, RpName,
+           RpNumber, RpPackage, RpTupleBody, RpType, RpTypeBody, RpTypeInfo, RpVersionedPackage};
+use json_backend::JsonBackend;
+use schema_collector::SchemaCollector;
+use serde_json::{Map, Value};
+use std::iter;
+use std::path::{Path, PathBuf};
+
+/// Identifier of the draft which is emitted.
+pub const SCHEMA: &str = "http://json-schema.org/draft-07/schema#";
+const SCHEMA_EXT: &str = "schema.json";
+
+pub struct SchemaCompiler<'el> {
+    pub out_path: PathBuf,
+    pub processor: &'el JsonBackend,
+}
+
+impl<'el> SchemaCompiler<'el> {
+    pub fn compile(&self) -> Result<()> {
+        let files = self.populate_files()?;
+        self.write_files(files)?;
+        Ok(())
+    }
+
+    fn definition(&self, name: &RpName) -> String {
+        name.join(".")
+    }
+
+    /// Build a reference to the given name, which lives in another document if it is imported.
+    fn reference(&self, name: &RpName) -> Value {
+        let definition = self.definition(name);
+
+        if name.prefix.is_some() {
+            let package = self.processor.package(&name.package);
+
+            return json!({
+                "$ref": format!(
+                    "{}.{}#/definitions/{}",
+                    self.processor.package_file(&package),
+                    SCHEMA_EXT,
+                    definition
+                ),
+            });
+        }
+
+        json!({"$ref": format!("#/definitions/{}", definition)})
+    }
+
+    fn type_schema(&self, ty: &RpType) -> Value {
+        use self::RpType::*;
+
+        match *ty {
+            Signed { size: 8 } => integer(i8::min_value(), i8::max_value()),
+            Signed { size: 16 } => integer(i16::min_value(), i16::max_value()),
+            Signed { size: 32 } => integer(i32::min_value(), i32::max_value()),
+            Signed { size: 64 } => integer(i64::min_value(), i64::max_value()),
+            Unsigned { size: 8 } => integer(0, u8::max_value()),
+            Unsigned { size: 16 } => integer(0, u16::max_value()),
+            Unsigned { size: 32 } => integer(0, u32::max_value()),
+            Unsigned { size: 64 } => integer(0, u64::max_value()),
+            Signed { .. } => json!({"type": "integer"}),
+            Unsigned { .. } => json!({"type": "integer", "minimum": 0}),
+            Float | Double => json!({"type": "number"}),
+            String => json!({"type": "string"}),
+            DateTime => json!({"type": "string", "format": "date-time"}),
+            Date => json!({"type": "string", "format": "date"}),
+            Duration => json!({"type": "string", "format": "duration"}),
+            Uuid => json!({"type": "string", "format": "uuid"}),
+            Decimal => json!({"type": "string", "pattern": "^-?[0-9]+(\\.[0-9]+)?$"}),
+            Bytes => json!({"type": "string", "contentEncoding": "base64"}),
+            Boolean => json!({"type": "boolean"}),
+            Any => json!({}),
+            Name { ref name } => self.reference(name),
+            Array { ref inner } => json!({"type": "array", "items": self.type_schema(inner)}),
+            Map { ref value, .. } => {
+                json!({"type": "object", "additionalProperties": self.type_schema(value)})
+            }
+        }
+    }
+
+    fn field_schema(&self, field: &RpField) -> Value {
+        let mut schema = self.type_schema(&field.ty);
+
+        if let Value::Object(ref mut map) = schema {
+            describe(map, &field.comment);
+            constrain(map, &field.constraints);
+
+            if let Some(ref default) = field.default {
+                map.insert(
+                    "default".to_string(),
+                    self.value(&field.ty, default.value()),
+                );
+            }
+        }
+
+        schema
+    }
+
+    /// Build an object schema out of the given fields.
+    ///
+    /// `tag` is an additional required property, used to discriminate sub-types.
+    fn object<'a, I>(&self, fields: I, tag: Option<(&str, Value)>) -> Map<String, Value>
+    where
+        I: IntoIterator<Item = &'a Loc<RpField>>,
+    {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        if let Some((name, value)) = tag {
+            properties.insert(name.to_string(), value);
+            required.push(Value::String(name.to_string()));
+        }
+
+        for field in fields {
+            properties.insert(field.name().to_string(), self.field_schema(field));
+
+            if field.is_required() {
+                required.push(Value::String(field.name().to_string()));
+            }
+        }
+
+        let mut object = Map::new();
+        object.insert("type".to_string(), json!("object"));
+        object.insert("properties".to_string(), Value::Object(properties));
+
+        if !required.is_empty() {
+            object.insert("required".to_string(), Value::Array(required));
+        }
+
+        object
+    }
+}
+
+/// Build an integer schema with the given bounds.
+fn integer<L: Into<Value>, H: Into<Value>>(minimum: L, maximum: H) -> Value {
+    json!({"type": "integer", "minimum": minimum.into(), "maximum": maximum.into()})
+}
+
+/// Attach the constraints of a field as validation keywords.
+fn constrain(schema: &mut Map<String, Value>, constraints: &RpConstraints) {
+    if let Some(ref min) = constraints.min {
+        schema.insert("minimum".to_string(), json!(min));
+    }
+
+    if let Some(ref max) = constraints.max {
+        schema.insert("maximum".to_string(), json!(max));
+    }
+
+    if let Some(min_length) = constraints.min_length {
+        schema.insert("minLength".to_string(), json!(min_length));
+    }
+
+    if let Some(max_length) = constraints.max_length {
+        schema.insert("maxLength".to_string(), json!(max_length));
+    }
+
+    if let Some(ref pattern) = constraints.pattern {
+        schema.insert("pattern".to_string(), json!(pattern));
+    }
+
+    if let Some(min_items) = constraints.min_items {
+        schema.insert("minItems".to_string(), json!(min_items));
+    }
+
+    if let Some(max_items) = constraints.max_items {
+        schema.insert("maxItems".to_string(), json!(max_items));
+    }
+
+    if constraints.non_empty {
+        schema.insert("minProperties".to_string(), json!(1));
+    }
+}
+
+/// Attach the comment of a declaration or field as its description.
+fn describe(schema: &mut Map<String, Value>, comment: &[String]) {
+    if !comment.is_empty() {
+        let lines: Vec<&str> = comment.iter().map(|c| c.trim()).collect();
+        schema.insert("description".to_string(), json!(lines.join("\n")));
+    }
+}
+
+impl<'el> PackageProcessor<'el> for SchemaCompiler<'el> {
+    type Out = SchemaCollector;
+
+    fn ext(&self) -> &str {
+        SCHEMA_EXT
+    }
+
+    fn env(&self) -> &'el Environment {
+        &self.processor.env
+    }
+
+    fn out_path(&self) -> &Path {
+        &self.out_path
+    }
+
+    fn processed_package(&self, package: &RpVersionedPackage) -> RpPackage {
+        self.processor.package(package)
+    }
+
+    fn default_process(&self, _: &mut Self::Out, _: &RpName) -> Result<()> {
+        Ok(())
+    }
+
+    fn resolve_full_path(&self, package: &RpPackage) -> Result<PathBuf> {
+        let mut full_path = self.out_path().join(self.processor.package_file(package));
+        full_path.set_extension(self.ext());
+        Ok(full_path)
+    }
+
+    fn process_enum(&self, out: &mut Self::Out, body: &'el Loc<RpEnumBody>) -> Result<()> {
+        let values: Vec<Value> = body.variants
+            .iter()
+            .map(|v| json!(v.ordinal()))
+            .collect();
+
+        let ty = match body.variant_type {
+            RpEnumType::Signed { .. } |
+            RpEnumType::Unsigned { .. } => "integer",
+            _ => "string",
+        };
+
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!(ty));
+        schema.insert("enum".to_string(), Value::Array(values));
+        describe(&mut schema, &body.comment);
+
+        out.definitions.insert(self.definition(&body.name), Value::Object(schema));
+        Ok(())
+    }
+
+    fn process_interface(
+        &self,
+        out: &mut Self::Out,
+        body: &'el Loc<RpInterfaceBody>,
+    ) -> Result<()> {
+        let mut one_of = Vec::new();
+
+        for sub_type in body.sub_types.values() {
+            let name = sub_type.name();
+            let fields = body.fields.iter().chain(sub_type.fields.iter());
+
+            let mut schema = match body.type_info {
+                RpTypeInfo::TypeField { name: ref field } => {
+                    self.object(fields, Some((field.as_str(), json!({"const": name}))))
+                }
+                RpTypeInfo::Array => {
+                    let object = self.object(fields, None);
+
+                    let mut schema = Map::new();
+                    schema.insert("type".to_string(), json!("array"));
+                    schema.insert(
+                        "items".to_string(),
+                        json!([{"const": name}, Value::Object(object)]),
+                    );
+                    schema.insert("additionalItems".to_string(), json!(false));
+                    schema.insert("minItems".to_string(), json!(2));
+                    schema
+                }
+                RpTypeInfo::ObjectKeys => {
+                    let object = Value::Object(self.object(fields, None));
+                    let mut schema = self.object(iter::empty(), Some((name, object)));
+                    schema.insert("additionalProperties".to_string(), json!(false));
+                    schema
+                }
+            };
+
+            describe(&mut schema, &sub_type.comment);
+
+            one_of.push(self.reference(&sub_type.name));
+            out.definitions.insert(self.definition(&sub_type.name), Value::Object(schema));
+        }
+
+        let mut schema = Map::new();
+        schema.insert("oneOf".to_string(), Value::Array(one_of));
+        describe(&mut schema, &body.comment);
+
+        out.definitions.insert(self.definition(&body.name), Value::Object(schema));
+        Ok(())
+    }
+
+    fn process_type(&self, out: &mut Self::Out, body: &'el Loc<RpTypeBody>) -> Result<()> {
+        let mut schema = self.object(&body.fields, None);
+        describe(&mut schema, &body.comment);
+
+        out.definitions.insert(self.definition(&body.name), Value::Object(schema));
+        Ok(())
+    }
+
+    fn process_tuple(&self, out: &mut Self::Out, body: &'el Loc<RpTupleBody>) -> Result<()> {
+        let items: Vec<Value> = body.fields.iter().map(|f| self.field_schema(f)).collect();
+        let required = body.fields.iter().filter(|f| f.is_required()).count();
+
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("array"));
+        schema.insert("items".to_string(), Value::Array(items));
+        schema.insert("additionalItems".to_string(), json!(false));
+        schema.insert("minItems".to_string(), json!(required));
+        describe(&mut schema, &body.comment);
+
+        out.definitions.insert(self.definition(&body.name), Value::Object(schema));
+        Ok(())
+    }
+}
+
+impl<'el> ValueBuilder<'el> for SchemaCompiler<'el> {
+    type Output = Value;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        Value::String(literal)
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        json!(string)
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        Value::Array(items)
+    }
+
+    fn number_literal(&self, _: &RpType, number: &RpNumber) -> Self::Output {
+        json!(number)
+    }
+
+    fn boolean_literal(&self, boolean: bool) -> Self::Output {
+        json!(boolean)
+    }
+}
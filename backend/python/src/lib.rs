@@ -14,6 +14,8 @@ mod python_compiler;
 mod python_field;
 mod python_file_spec;
 mod python_options;
+mod requests;
+mod rpc;
 
 use self::backend::{ArgMatches, CompilerOptions, Environment, Options};
 use self::backend::errors::*;
@@ -23,12 +25,14 @@ use self::python_options::PythonOptions;
 use manifest::{Lang, Manifest, NoModule, TryFromToml, self as m};
 use std::path::Path;
 
-const TYPE: &str = "type";
 const TYPE_SEP: &str = "_";
 const INIT_PY: &str = "__init__.py";
 const EXT: &str = "py";
 const PYTHON_CONTEXT: &str = "python";
 
+/// Endpoint options understood by the backend.
+const ENDPOINT_OPTIONS: &[&str] = &["http_status"];
+
 #[derive(Default)]
 pub struct PythonLang;
 
@@ -38,24 +42,48 @@ impl Lang for PythonLang {
 
 #[derive(Debug)]
 pub enum PythonModule {
+    Requests,
+    Rpc,
 }
 
 impl TryFromToml for PythonModule {
     fn try_from_string(path: &Path, id: &str, value: String) -> m::errors::Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::PythonModule::*;
+
+        let result = match id {
+            "requests" => Requests,
+            "rpc" => Rpc,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 
     fn try_from_value(path: &Path, id: &str, value: toml::Value) -> m::errors::Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::PythonModule::*;
+
+        let result = match id {
+            "requests" => Requests,
+            "rpc" => Rpc,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 }
 
 pub fn setup_listeners(modules: &[PythonModule]) -> Result<(PythonOptions, Box<Listeners>)> {
-    let listeners: Vec<Box<Listeners>> = Vec::new();
+    use self::PythonModule::*;
+
+    let mut listeners: Vec<Box<Listeners>> = Vec::new();
 
     for module in modules {
-        match *module {
-        }
+        let listener = match *module {
+            Requests => Box::new(requests::Module::new()) as Box<Listeners>,
+            Rpc => Box::new(rpc::Module::new()) as Box<Listeners>,
+        };
+
+        listeners.push(listener);
     }
 
     let mut options = PythonOptions::new();
@@ -74,6 +102,7 @@ pub fn compile(
     _matches: &ArgMatches,
     manifest: Manifest<PythonLang>,
 ) -> Result<()> {
+    env.verify_endpoint_options(ENDPOINT_OPTIONS)?;
     let id_converter = opts.id_converter;
     let (options, listeners) = setup_listeners(&manifest.modules)?;
     let backend = PythonBackend::new(env, options, listeners, id_converter);
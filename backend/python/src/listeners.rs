@@ -1,9 +1,24 @@
 use super::*;
+use core::RpServiceBody;
+use genco::{Python, Tokens};
+use python_backend::PythonBackend;
+use std::rc::Rc;
+
+pub struct ServiceAdded<'a, 'el: 'a> {
+    pub backend: &'a PythonBackend,
+    pub body: &'el RpServiceBody,
+    pub type_name: Rc<String>,
+    pub out: &'a mut Tokens<'el, Python<'el>>,
+}
 
 pub trait Listeners {
     fn configure(&self, _: &mut PythonOptions) -> Result<()> {
         Ok(())
     }
+
+    fn service_added(&self, _: &mut ServiceAdded) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// A vector of listeners is a valid listener.
@@ -15,4 +30,12 @@ impl Listeners for Vec<Box<Listeners>> {
 
         Ok(())
     }
+
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        for listeners in self {
+            listeners.service_added(e)?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,14 +1,14 @@
 //! Python Backend
 
-use super::{PYTHON_CONTEXT, TYPE, TYPE_SEP};
+use super::{PYTHON_CONTEXT, TYPE_SEP};
 use backend::{Code, CompilerOptions, Converter, DynamicConverter, DynamicDecode, DynamicEncode,
-              Environment, FromNaming, Naming, PackageUtils, SnakeCase};
+              Environment, FromNaming, Naming, PackageUtils, SnakeCase, ValueBuilder};
 use backend::errors::*;
 use core::{ForEachLoc, Loc, RpEnumBody, RpField, RpInterfaceBody, RpModifier, RpName,
-           RpServiceBody, RpTupleBody, RpType, RpTypeBody, WithPos};
+           RpServiceBody, RpTupleBody, RpType, RpTypeBody, RpTypeInfo, WithPos};
 use genco::{Element, Quoted, Tokens};
 use genco::python::{Python, imported_alias, imported_ref};
-use listeners::Listeners;
+use listeners::{Listeners, ServiceAdded};
 use python_compiler::PythonCompiler;
 use python_field::PythonField;
 use python_file_spec::PythonFileSpec;
@@ -24,7 +24,7 @@ pub struct PythonBackend {
     to_lower_snake: Box<Naming>,
     dict: Element<'static, Python<'static>>,
     enum_enum: Python<'static>,
-    type_var: Tokens<'static, Python<'static>>,
+    re_search: Python<'static>,
 }
 
 impl PythonBackend {
@@ -41,7 +41,7 @@ impl PythonBackend {
             to_lower_snake: SnakeCase::new().to_lower_snake(),
             dict: "dict".into(),
             enum_enum: imported_ref("enum", "Enum"),
-            type_var: TYPE.quoted().into(),
+            re_search: imported_ref("re", "search"),
         }
     }
 
@@ -72,6 +72,7 @@ impl PythonBackend {
         fields: &[Loc<PythonField<'el>>],
         builder: Tokens<'el, Python<'el>>,
         extra: Option<Tokens<'el, Python<'el>>>,
+        result: Tokens<'el, Python<'el>>,
     ) -> Result<Tokens<'el, Python<'el>>> {
         let mut encode_body = Tokens::new();
 
@@ -111,7 +112,7 @@ impl PythonBackend {
             Ok(()) as Result<()>
         })?;
 
-        encode_body.push(toks!["return data"]);
+        encode_body.push(toks!["return ", result]);
 
         let mut encode = Tokens::new();
         encode.push("def encode(self):");
@@ -258,6 +259,42 @@ impl PythonBackend {
             };
 
             body.push(toks);
+
+            // absent values are replaced by the default value of the field.
+            if let Some(default) = field.default {
+                let value = self.value(field.ty, default.value());
+
+                let mut absent = Tokens::new();
+                absent.push(toks!["if ", var_name.clone(), " is None:"]);
+                absent.nested(toks![var_name.clone(), " = ", value]);
+                body.push(absent);
+            }
+
+            let checks = self.constraints_check(
+                field.name,
+                field.ty,
+                field.constraints,
+                toks!(var_name.clone()),
+            );
+
+            if !checks.is_empty() {
+                let mut check_body = Tokens::new();
+
+                for check in checks {
+                    check_body.push(check);
+                }
+
+                match *field.modifier {
+                    RpModifier::Optional => {
+                        let mut check = Tokens::new();
+                        check.push(toks!["if ", var_name.clone(), " is not None:"]);
+                        check.nested(check_body);
+                        body.push(check);
+                    }
+                    _ => body.push(check_body),
+                }
+            }
+
             args.append(toks!(var_name));
         }
 
@@ -273,7 +310,7 @@ impl PythonBackend {
         Ok(decode)
     }
 
-    fn ident(&self, name: &str) -> String {
+    pub fn ident(&self, name: &str) -> String {
         if let Some(ref id_converter) = self.id_converter {
             id_converter.convert(name)
         } else {
@@ -416,6 +453,8 @@ impl PythonBackend {
         python_field_f(PythonField {
             modifier: &field.modifier,
             ty: &field.ty,
+            constraints: &field.constraints,
+            default: field.default.as_ref(),
             name: field.name(),
             ident: Rc::new(ident),
         })
@@ -425,13 +464,34 @@ impl PythonBackend {
         self.into_python_field_with(field, |ident| ident)
     }
 
-    fn as_class<'el>(
+    pub fn as_class<'el>(
+        &self,
+        name: Rc<String>,
+        body: Tokens<'el, Python<'el>>,
+    ) -> Tokens<'el, Python<'el>> {
+        self.as_class_with_bases(name, vec![], body)
+    }
+
+    /// Build a class which inherits from the given base classes.
+    pub fn as_class_with_bases<'el>(
         &self,
         name: Rc<String>,
+        bases: Vec<Tokens<'el, Python<'el>>>,
         body: Tokens<'el, Python<'el>>,
     ) -> Tokens<'el, Python<'el>> {
         let mut class = Tokens::new();
-        class.push(toks!("class ", name, ":"));
+
+        if bases.is_empty() {
+            class.push(toks!("class ", name, ":"));
+        } else {
+            let mut args = Tokens::new();
+
+            for base in bases {
+                args.append(base);
+            }
+
+            class.push(toks!("class ", name, "(", args.join(", "), "):"));
+        }
 
         if body.is_empty() {
             class.nested("pass");
@@ -553,7 +613,8 @@ impl PythonBackend {
 
         class_body.push(decode);
 
-        let encode = self.encode_method(&fields, self.dict.clone().into(), None)?;
+        let encode =
+            self.encode_method(&fields, self.dict.clone().into(), None, toks!["data"])?;
 
         class_body.push(encode);
 
@@ -609,18 +670,18 @@ impl PythonBackend {
 
             sub_type_body.push(decode);
 
-            let type_var = self.type_var.clone();
+            let type_name = sub_type.name().quoted();
+
+            let (extra, result) = match body.type_info {
+                RpTypeInfo::TypeField { ref name } => {
+                    let extra = toks!["data[", name.as_str().quoted(), "] = ", type_name];
+                    (Some(extra), toks!["data"])
+                }
+                RpTypeInfo::Array => (None, toks!["[", type_name, ", data]"]),
+                RpTypeInfo::ObjectKeys => (None, toks!["{", type_name, ": data}"]),
+            };
 
-            let encode = self.encode_method(
-                &fields,
-                self.dict.clone().into(),
-                Some(toks![
-                    "data[",
-                    type_var,
-                    "] = ",
-                    sub_type.name().quoted(),
-                ]),
-            )?;
+            let encode = self.encode_method(&fields, self.dict.clone().into(), extra, result)?;
 
             sub_type_body.push(encode);
 
@@ -641,9 +702,35 @@ impl PythonBackend {
         body: &'el RpServiceBody,
     ) -> Result<()> {
         let type_name = Rc::new(body.name.join(TYPE_SEP));
-        let type_body = Tokens::new();
+        let mut type_body = Tokens::new();
+
+        let endpoints = body.endpoints.values().map(|l| l.loc_ref());
+
+        endpoints.for_each_loc(|endpoint| {
+            let mut args = Tokens::new();
+            args.append("self");
+
+            if endpoint.request.is_some() {
+                args.append("request");
+            }
+
+            let mut method = Tokens::new();
+            method.push(toks!["def ", endpoint.id.as_str(), "(", args.join(", "), "):"]);
+            method.nested("raise NotImplementedError()");
+
+            type_body.push(method);
+            Ok(()) as Result<()>
+        })?;
+
+        out.0.push(self.as_class(type_name.clone(), type_body));
+
+        self.listeners.service_added(&mut ServiceAdded {
+            backend: self,
+            body: body,
+            type_name: type_name,
+            out: &mut out.0,
+        })?;
 
-        out.0.push(self.as_class(type_name, type_body));
         Ok(())
     }
 }
@@ -747,13 +834,26 @@ impl<'el> DynamicDecode<'el> for PythonBackend {
         ]
     }
 
-    fn assign_type_var(&self, data: &'el str, type_var: &'el str) -> Tokens<'el, Self::Custom> {
-        toks![type_var, " = ", data, "[", self.type_var.clone(), "]"]
+    fn assign_type_var(
+        &self,
+        data: &'el str,
+        type_var: &'el str,
+        type_info: &'el RpTypeInfo,
+    ) -> Tokens<'el, Self::Custom> {
+        use self::RpTypeInfo::*;
+
+        match *type_info {
+            TypeField { ref name } => {
+                toks![type_var, " = ", data, "[", name.as_str().quoted(), "]"]
+            }
+            Array => toks![type_var, " = ", data, "[0]"],
+            ObjectKeys => toks![type_var, " = next(iter(", data, "))"],
+        }
     }
 
     fn check_type_var(
         &self,
-        _data: &'el str,
+        data: Tokens<'el, Self::Custom>,
         type_var: &'el str,
         name: &'el Loc<String>,
         type_name: Tokens<'el, Self::Custom>,
@@ -768,7 +868,34 @@ impl<'el> DynamicDecode<'el> for PythonBackend {
             ":",
         ]);
 
-        check.nested(toks!["return ", type_name, ".decode(data)"]);
+        check.nested(toks!["return ", type_name, ".decode(", data, ")"]);
+        check
+    }
+
+    fn length_of(
+        &self,
+        _: &'el RpType,
+        var: Tokens<'el, Self::Custom>,
+    ) -> Tokens<'el, Self::Custom> {
+        toks!["len(", var, ")"]
+    }
+
+    fn pattern_mismatch(
+        &self,
+        var: Tokens<'el, Self::Custom>,
+        pattern: &'el str,
+    ) -> Tokens<'el, Self::Custom> {
+        toks![self.re_search.clone(), "(", pattern.quoted(), ", ", var, ") is None"]
+    }
+
+    fn raise_constraint(
+        &self,
+        condition: Tokens<'el, Self::Custom>,
+        message: String,
+    ) -> Tokens<'el, Self::Custom> {
+        let mut check = Tokens::new();
+        check.push(toks!["if ", condition, ":"]);
+        check.nested(toks!["raise Exception(", message.quoted(), ")"]);
         check
     }
 
@@ -842,3 +969,33 @@ impl<'el> DynamicEncode<'el> for PythonBackend {
         ]
     }
 }
+
+impl<'el> ValueBuilder<'el> for PythonBackend {
+    type Output = Tokens<'el, Python<'el>>;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        toks![Rc::new(literal)]
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        toks![string.to_string().quoted()]
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        let mut args = Tokens::new();
+
+        for item in items {
+            args.append(item);
+        }
+
+        toks!["[", args.join(", "), "]"]
+    }
+
+    fn boolean_literal(&self, boolean: bool) -> Self::Output {
+        if boolean {
+            toks!["True"]
+        } else {
+            toks!["False"]
+        }
+    }
+}
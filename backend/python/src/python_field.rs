@@ -1,10 +1,12 @@
-use core::{RpModifier, RpType};
+use core::{Loc, RpConstraints, RpModifier, RpType, RpValue};
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
 pub struct PythonField<'a> {
     pub modifier: &'a RpModifier,
     pub ty: &'a RpType,
+    pub constraints: &'a RpConstraints,
+    pub default: Option<&'a Loc<RpValue>>,
     pub name: &'a str,
     pub ident: Rc<String>,
 }
@@ -14,6 +16,8 @@ impl<'a> PythonField<'a> {
         PythonField {
             modifier: self.modifier,
             ty: self.ty,
+            constraints: self.constraints,
+            default: self.default,
             name: self.name,
             ident: Rc::new(ident),
         }
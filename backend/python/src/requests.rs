@@ -0,0 +1,167 @@
+//! Module that builds a client using `requests` for services with HTTP bindings.
+
+use backend::{DynamicDecode, DynamicEncode};
+use backend::errors::*;
+use core::{RpEndpoint, RpEndpointHttp, RpPathSegment};
+use genco::{Python, Quoted, Tokens};
+use genco::python::imported_ref;
+use listeners::{Listeners, ServiceAdded};
+use python_backend::PythonBackend;
+use std::rc::Rc;
+
+pub struct Module {
+    session: Python<'static>,
+    quote: Python<'static>,
+    http_error: Python<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {
+            session: imported_ref("requests", "Session"),
+            quote: imported_ref("requests.utils", "quote"),
+            http_error: imported_ref("requests", "HTTPError"),
+        }
+    }
+
+    /// Build the expression for the URL of the given binding.
+    fn url<'el>(
+        &self,
+        backend: &PythonBackend,
+        http: &'el RpEndpointHttp,
+    ) -> Tokens<'el, Python<'el>> {
+        let mut url = Tokens::new();
+        url.append("self.url");
+
+        let mut literal = String::new();
+
+        for segment in &http.path.segments {
+            literal.push('/');
+
+            match *segment {
+                RpPathSegment::Literal { ref value } => {
+                    literal.push_str(value.as_str());
+                }
+                RpPathSegment::Variable { ref name } => {
+                    url.append(Rc::new(literal.clone()).quoted());
+                    literal.clear();
+
+                    let ident = Rc::new(backend.ident(name.as_str()));
+
+                    url.append(toks![
+                        self.quote.clone(),
+                        "(str(request.",
+                        ident,
+                        "), safe=",
+                        "".quoted(),
+                        ")",
+                    ]);
+                }
+            }
+        }
+
+        if !literal.is_empty() {
+            url.append(Rc::new(literal).quoted());
+        }
+
+        url.join(" + ")
+    }
+
+    fn endpoint<'el>(
+        &self,
+        backend: &PythonBackend,
+        endpoint: &'el RpEndpoint,
+        http: &'el RpEndpointHttp,
+    ) -> Result<Tokens<'el, Python<'el>>> {
+        let mut args = Tokens::new();
+        args.append("self");
+
+        if endpoint.request.is_some() {
+            args.append("request");
+        }
+
+        let mut t = Tokens::new();
+        t.push(toks!["def ", endpoint.id.as_str(), "(", args.join(", "), "):"]);
+
+        t.nested({
+            let mut body = Tokens::new();
+
+            body.push(toks!["url = ", self.url(backend, http)]);
+
+            let mut request_args = Tokens::new();
+            request_args.append(http.method.as_str().quoted());
+            request_args.append("url");
+
+            match endpoint.request {
+                Some(ref req) if http.method.has_body() => {
+                    let encode = backend.dynamic_encode(req.ty(), toks!["request"])?;
+                    request_args.append(toks!["json=", encode]);
+                }
+                _ => {}
+            }
+
+            body.push(toks!["r = self.session.request(", request_args.join(", "), ")"]);
+
+            match endpoint.http_status()? {
+                Some(status) => {
+                    let mut check = Tokens::new();
+                    check.push(toks!["if r.status_code != ", Rc::new(status.to_string()), ":"]);
+                    check.nested(toks![
+                        "raise ",
+                        self.http_error.clone(),
+                        "(",
+                        "bad response: ".quoted(),
+                        " + str(r.status_code), response=r)",
+                    ]);
+                    body.push(check);
+                }
+                None => {
+                    body.push("r.raise_for_status()");
+                }
+            }
+
+            if let Some(ref res) = endpoint.response {
+                let decode = backend.dynamic_decode(res.ty(), toks!["r.json()"])?;
+                body.push(toks!["return ", decode]);
+            }
+
+            body
+        });
+
+        Ok(t)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        let name = Rc::new(format!("{}_Requests", e.type_name));
+
+        let mut class_body = Tokens::new();
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push("def __init__(self, url, session=None):");
+            t.nested({
+                let mut t = Tokens::new();
+                t.push("if session is None:");
+                t.nested(toks!["session = ", self.session.clone(), "()"]);
+                t
+            });
+            t.nested(toks!["self.url = url.rstrip(", "/".quoted(), ")"]);
+            t.nested("self.session = session");
+            t
+        });
+
+        for endpoint in e.body.endpoints.values() {
+            if let Some(ref http) = endpoint.http {
+                class_body.push(self.endpoint(e.backend, endpoint, http)?);
+            }
+        }
+
+        // endpoints without HTTP bindings are left unimplemented by the service.
+        let bases = vec![toks![e.type_name.clone()]];
+
+        e.out.push(e.backend.as_class_with_bases(name, bases, class_body));
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+//! Module that builds a client and a server dispatcher for services.
+//!
+//! Requests and responses are exchanged as iterators of encoded values through a transport
+//! provided by the user.
+
+use backend::{DynamicDecode, DynamicEncode};
+use backend::errors::*;
+use core::{RpChannel, RpEndpoint};
+use genco::{Python, Quoted, Tokens};
+use listeners::{Listeners, ServiceAdded};
+use python_backend::PythonBackend;
+use std::rc::Rc;
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+
+    fn client_method<'el>(
+        &self,
+        backend: &PythonBackend,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Python<'el>>> {
+        let mut args = Tokens::new();
+        args.append("self");
+
+        let requests = match endpoint.request {
+            Some(ref req) => {
+                args.append("request");
+
+                match *req.value() {
+                    RpChannel::Streaming { ref ty } => {
+                        let encode = backend.dynamic_encode(ty, toks!["r"])?;
+                        toks!["(", encode, " for r in request)"]
+                    }
+                    RpChannel::Unary { ref ty } => {
+                        let encode = backend.dynamic_encode(ty, toks!["request"])?;
+                        toks!["iter([", encode, "])"]
+                    }
+                }
+            }
+            None => toks!["iter([])"],
+        };
+
+        let mut body = Tokens::new();
+
+        body.push(toks![
+            "responses = self.transport.call(",
+            endpoint.name().quoted(),
+            ", ",
+            requests,
+            ")",
+        ]);
+
+        match endpoint.response {
+            Some(ref res) => {
+                match *res.value() {
+                    RpChannel::Streaming { ref ty } => {
+                        let decode = backend.dynamic_decode(ty, toks!["r"])?;
+                        body.push(toks!["return (", decode, " for r in responses)"]);
+                    }
+                    RpChannel::Unary { ref ty } => {
+                        let decode = backend.dynamic_decode(ty, toks!["response"])?;
+
+                        let mut t = Tokens::new();
+                        t.push("for response in responses:");
+                        t.nested(toks!["return ", decode]);
+                        t.push(toks![
+                            "raise Exception(",
+                            "missing response".quoted(),
+                            ")",
+                        ]);
+
+                        body.push(t);
+                    }
+                }
+            }
+            None => {
+                let mut t = Tokens::new();
+                t.push("for _ in responses:");
+                t.nested("pass");
+                body.push(t);
+            }
+        }
+
+        let mut t = Tokens::new();
+        t.push(toks!["def ", endpoint.id.as_str(), "(", args.join(", "), "):"]);
+        t.nested(body.join_line_spacing());
+        Ok(t)
+    }
+
+    /// Build the branch of `dispatch` which handles the given endpoint.
+    fn server_branch<'el>(
+        &self,
+        backend: &PythonBackend,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Python<'el>>> {
+        let id = endpoint.id.as_str();
+
+        let call = match endpoint.request {
+            Some(ref req) => {
+                match *req.value() {
+                    RpChannel::Streaming { ref ty } => {
+                        let decode = backend.dynamic_decode(ty, toks!["r"])?;
+                        toks!["self.service.", id, "(", decode, " for r in requests)"]
+                    }
+                    RpChannel::Unary { ref ty } => {
+                        let decode = backend.dynamic_decode(ty, toks!["request"])?;
+                        toks!["self.service.", id, "(", decode, ")"]
+                    }
+                }
+            }
+            None => toks!["self.service.", id, "()"],
+        };
+
+        let mut respond = Tokens::new();
+
+        match endpoint.response {
+            Some(ref res) => {
+                match *res.value() {
+                    RpChannel::Streaming { ref ty } => {
+                        let encode = backend.dynamic_encode(ty, toks!["r"])?;
+                        respond.push(toks!["return (", encode, " for r in ", call, ")"]);
+                    }
+                    RpChannel::Unary { ref ty } => {
+                        let encode = backend.dynamic_encode(ty, toks!["response"])?;
+                        respond.push(toks!["response = ", call]);
+                        respond.push(toks!["return iter([", encode, "])"]);
+                    }
+                }
+            }
+            None => {
+                respond.push(call);
+                respond.push("return iter([])");
+            }
+        }
+
+        let mut t = Tokens::new();
+        t.push(toks!["if endpoint == ", endpoint.name().quoted(), ":"]);
+
+        match endpoint.request {
+            Some(ref req) if !req.is_streaming() => {
+                t.nested({
+                    let mut t = Tokens::new();
+                    t.push("for request in requests:");
+                    t.nested(respond);
+                    t.push(toks![
+                        "raise Exception(",
+                        "missing request".quoted(),
+                        ")",
+                    ]);
+                    t
+                });
+            }
+            _ => {
+                t.nested(respond);
+            }
+        }
+
+        Ok(t)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        let client_name = Rc::new(format!("{}_Client", e.type_name));
+        let server_name = Rc::new(format!("{}_Server", e.type_name));
+
+        let mut client_body = Tokens::new();
+
+        client_body.push({
+            let mut t = Tokens::new();
+            t.push("def __init__(self, transport):");
+            t.nested("self.transport = transport");
+            t
+        });
+
+        let mut server_body = Tokens::new();
+
+        server_body.push({
+            let mut t = Tokens::new();
+            t.push("def __init__(self, service):");
+            t.nested("self.service = service");
+            t
+        });
+
+        let mut dispatch = Tokens::new();
+
+        for endpoint in e.body.endpoints.values() {
+            client_body.push(self.client_method(e.backend, endpoint)?);
+            dispatch.push(self.server_branch(e.backend, endpoint)?);
+        }
+
+        dispatch.push(toks![
+            "raise Exception(",
+            "no such endpoint: ".quoted(),
+            " + endpoint)",
+        ]);
+
+        server_body.push({
+            let mut t = Tokens::new();
+            t.push("def dispatch(self, endpoint, requests):");
+            t.nested(dispatch.join_line_spacing());
+            t
+        });
+
+        let client_bases = vec![toks![e.type_name.clone()]];
+
+        e.out.push(e.backend.as_class_with_bases(
+            client_name,
+            client_bases,
+            client_body,
+        ));
+        e.out.push(e.backend.as_class(server_name, server_body));
+        Ok(())
+    }
+}
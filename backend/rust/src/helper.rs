@@ -0,0 +1,549 @@
+//! Helper modules, which encode types that serde doesn't encode as specified.
+//!
+//! Every helper module is generated into the files that use it, and is used through
+//! `#[serde(with = "...")]` on fields which contain the encoded type.
+//! The module implements an `Encode` trait for the encoded type, and for options, vectors, and
+//! maps of it, so that the type can be used anywhere in the type of a field.
+
+use backend::errors::*;
+use core::{RpField, RpType};
+use genco::{Rust, Tokens};
+
+/// A type which is encoded through a generated helper module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Helper {
+    /// `Vec<u8>`, encoded as a base64 string.
+    Bytes,
+    /// `chrono::Duration`, encoded as an ISO-8601 duration like `PT1H30M`.
+    Duration,
+}
+
+impl Helper {
+    /// Find the helper which encodes the given field, if any.
+    pub fn of_field(field: &RpField) -> Result<Option<Helper>> {
+        return find(field, &field.ty);
+
+        fn find(field: &RpField, ty: &RpType) -> Result<Option<Helper>> {
+            match *ty {
+                RpType::Bytes => Ok(Some(Helper::Bytes)),
+                RpType::Duration => Ok(Some(Helper::Duration)),
+                RpType::Array { ref inner } => find(field, inner),
+                RpType::Map { ref key, ref value } => {
+                    // keys are serialized as they are, which serde can't do for encoded types.
+                    if find(field, key)?.is_some() {
+                        return Err(
+                            format!(
+                                "{}: `{}` is not supported as the key of a map",
+                                field.name(),
+                                key
+                            ).into(),
+                        );
+                    }
+
+                    find(field, value)
+                }
+                _ => Ok(None),
+            }
+        }
+    }
+
+    /// Path to the helper module.
+    pub fn path(&self) -> &'static str {
+        match *self {
+            Helper::Bytes => "reproto_bytes",
+            Helper::Duration => "reproto_duration",
+        }
+    }
+
+    /// Build the helper module.
+    pub fn module<'el>(&self) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["mod ", self.path(), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+            t.push(encode_trait());
+
+            match *self {
+                Helper::Bytes => t.push(bytes_impl()),
+                Helper::Duration => t.push(duration_impl()),
+            }
+
+            t.push(option_impl());
+            t.push(vec_impl());
+            t.push(map_impl());
+            t.push(serialize());
+            t.push(deserialize());
+            t.join_line_spacing()
+        });
+
+        t.push("}");
+        t
+    }
+}
+
+/// The trait implemented by every type which can be encoded through the helper.
+fn encode_trait<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("pub trait Encode: Sized {");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr: ::serde::Serialize + ::serde::de::DeserializeOwned;");
+        body.push("fn encode(&self) -> Self::Repr;");
+        body.push("fn decode(repr: Self::Repr) -> Result<Self, String>;");
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
+
+/// Encode `Vec<u8>` as a base64 string.
+fn bytes_impl<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("impl Encode for Vec<u8> {");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr = String;");
+
+        body.push({
+            let mut e = Tokens::new();
+            e.push("fn encode(&self) -> String {");
+            e.nested("::base64::encode(self)");
+            e.push("}");
+            e
+        });
+
+        body.push({
+            let mut d = Tokens::new();
+            d.push("fn decode(repr: String) -> Result<Self, String> {");
+            d.nested("::base64::decode(&repr).map_err(|e| e.to_string())");
+            d.push("}");
+            d
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
+
+/// Encode `chrono::Duration` as an ISO-8601 duration.
+///
+/// Durations are encoded in hours, minutes, and seconds, like Java encodes them.
+/// Days are also accepted when decoding.
+fn duration_impl<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("impl Encode for ::chrono::Duration {");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr = String;");
+
+        body.push({
+            let mut e = Tokens::new();
+            e.push("fn encode(&self) -> String {");
+            e.nested(duration_encode());
+            e.push("}");
+            e
+        });
+
+        body.push({
+            let mut d = Tokens::new();
+            d.push("fn decode(repr: String) -> Result<Self, String> {");
+            d.nested(duration_decode());
+            d.push("}");
+            d
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    return t;
+
+    fn duration_encode<'el>() -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("let (sign, duration) = if *self < ::chrono::Duration::zero() {");
+            s.nested("(\"-\", -*self)");
+            s.push("} else {");
+            s.nested("(\"\", *self)");
+            s.push("};");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("let seconds = duration.num_seconds();");
+            s.push(
+                "let nanos = (duration - ::chrono::Duration::seconds(seconds))\
+                 .num_nanoseconds().unwrap_or(0);",
+            );
+            s.push("let mut out = format!(\"{}PT\", sign);");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if seconds >= 3600 {");
+            s.nested("out.push_str(&format!(\"{}H\", seconds / 3600));");
+            s.push("}");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if seconds % 3600 >= 60 {");
+            s.nested("out.push_str(&format!(\"{}M\", seconds % 3600 / 60));");
+            s.push("}");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if seconds % 60 != 0 || nanos != 0 || seconds == 0 {");
+
+            s.nested({
+                let mut body = Tokens::new();
+                body.push("out.push_str(&(seconds % 60).to_string());");
+
+                body.push({
+                    let mut f = Tokens::new();
+                    f.push("if nanos != 0 {");
+
+                    f.nested({
+                        let mut body = Tokens::new();
+                        body.push("let mut fraction = format!(\".{:09}\", nanos);");
+
+                        body.push({
+                            let mut w = Tokens::new();
+                            w.push("while fraction.ends_with('0') {");
+                            w.nested("fraction.pop();");
+                            w.push("}");
+                            w
+                        });
+
+                        body.push("out.push_str(&fraction);");
+                        body.join_line_spacing()
+                    });
+
+                    f.push("}");
+                    f
+                });
+
+                body.push("out.push('S');");
+                body.join_line_spacing()
+            });
+
+            s.push("}");
+            s
+        });
+
+        t.push("out");
+        t.join_line_spacing()
+    }
+
+    fn duration_decode<'el>() -> Tokens<'el, Rust<'el>> {
+        let error = "format!(\"bad duration: {}\", repr)";
+
+        let mut t = Tokens::new();
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("let (negative, rest) = if repr.starts_with('-') {");
+            s.nested("(true, &repr[1..])");
+            s.push("} else {");
+            s.nested("(false, &repr[..])");
+            s.push("};");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if !rest.starts_with('P') {");
+            s.nested(toks!["return Err(", error, ");"]);
+            s.push("}");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("let mut duration = ::chrono::Duration::zero();");
+            s.push("let mut time = false;");
+            s.push("let mut number = String::new();");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("for c in rest[1..].chars() {");
+
+            s.nested({
+                let mut body = Tokens::new();
+
+                body.push({
+                    let mut m = Tokens::new();
+                    m.push("let unit = match c {");
+                    m.nested({
+                        let mut arms = Tokens::new();
+                        arms.push("'T' if !time && number.is_empty() => {");
+                        arms.nested("time = true;");
+                        arms.nested("continue;");
+                        arms.push("}");
+                        arms.push("'D' if !time => 86_400,");
+                        arms.push("'H' if time => 3_600,");
+                        arms.push("'M' if time => 60,");
+                        arms.push("'S' if time => 1,");
+                        arms.push("c => {");
+                        arms.nested("number.push(c);");
+                        arms.nested("continue;");
+                        arms.push("}");
+                        arms
+                    });
+                    m.push("};");
+                    m
+                });
+
+                body.push({
+                    let mut s = Tokens::new();
+                    s.push("let mut parts = number.splitn(2, '.');");
+                    s.push("let whole = parts.next().unwrap_or(\"\");");
+                    s.push(toks![
+                        "let seconds: i64 = whole.parse().map_err(|_| ",
+                        error,
+                        ")?;",
+                    ]);
+                    s.push(
+                        "duration = duration + ::chrono::Duration::seconds(seconds * unit);",
+                    );
+                    s
+                });
+
+                body.push({
+                    let mut f = Tokens::new();
+                    f.push("if let Some(fraction) = parts.next() {");
+
+                    f.nested({
+                        let mut body = Tokens::new();
+
+                        body.push({
+                            let mut s = Tokens::new();
+                            s.push(
+                                "if unit != 1 || fraction.is_empty() || fraction.len() > 9 {",
+                            );
+                            s.nested(toks!["return Err(", error, ");"]);
+                            s.push("}");
+                            s
+                        });
+
+                        body.push({
+                            let mut s = Tokens::new();
+                            s.push("let nanos: i64 = format!(\"{:0<9}\", fraction)");
+                            s.nested(".parse()");
+                            s.nested(toks![".map_err(|_| ", error, ")?;"]);
+                            s
+                        });
+
+                        body.push({
+                            let mut s = Tokens::new();
+                            s.push("if whole.starts_with('-') {");
+                            s.nested(
+                                "duration = duration - ::chrono::Duration::nanoseconds(nanos);",
+                            );
+                            s.push("} else {");
+                            s.nested(
+                                "duration = duration + ::chrono::Duration::nanoseconds(nanos);",
+                            );
+                            s.push("}");
+                            s
+                        });
+
+                        body.join_line_spacing()
+                    });
+
+                    f.push("}");
+                    f
+                });
+
+                body.push("number.clear();");
+                body.join_line_spacing()
+            });
+
+            s.push("}");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if !number.is_empty() {");
+            s.nested(toks!["return Err(", error, ");"]);
+            s.push("}");
+            s
+        });
+
+        t.push({
+            let mut s = Tokens::new();
+            s.push("if negative {");
+            s.nested("return Ok(-duration);");
+            s.push("}");
+            s
+        });
+
+        t.push("Ok(duration)");
+        t.join_line_spacing()
+    }
+}
+
+fn option_impl<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("impl<T: Encode> Encode for Option<T> {");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr = Option<T::Repr>;");
+
+        body.push({
+            let mut e = Tokens::new();
+            e.push("fn encode(&self) -> Self::Repr {");
+            e.nested("self.as_ref().map(Encode::encode)");
+            e.push("}");
+            e
+        });
+
+        body.push({
+            let mut d = Tokens::new();
+            d.push("fn decode(repr: Self::Repr) -> Result<Self, String> {");
+            d.nested({
+                let mut m = Tokens::new();
+                m.push("match repr {");
+                m.nested("Some(repr) => T::decode(repr).map(Some),");
+                m.nested("None => Ok(None),");
+                m.push("}");
+                m
+            });
+            d.push("}");
+            d
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
+
+fn vec_impl<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("impl<T: Encode> Encode for Vec<T> {");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr = Vec<T::Repr>;");
+
+        body.push({
+            let mut e = Tokens::new();
+            e.push("fn encode(&self) -> Self::Repr {");
+            e.nested("self.iter().map(Encode::encode).collect()");
+            e.push("}");
+            e
+        });
+
+        body.push({
+            let mut d = Tokens::new();
+            d.push("fn decode(repr: Self::Repr) -> Result<Self, String> {");
+            d.nested("repr.into_iter().map(T::decode).collect()");
+            d.push("}");
+            d
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
+
+fn map_impl<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+
+    t.push("impl<K, T: Encode> Encode for ::std::collections::HashMap<K, T>");
+    t.push("where");
+    t.nested(
+        "K: ::std::hash::Hash + Eq + Clone + ::serde::Serialize + ::serde::de::DeserializeOwned,",
+    );
+    t.push("{");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("type Repr = ::std::collections::HashMap<K, T::Repr>;");
+
+        body.push({
+            let mut e = Tokens::new();
+            e.push("fn encode(&self) -> Self::Repr {");
+            e.nested("self.iter().map(|(k, v)| (k.clone(), v.encode())).collect()");
+            e.push("}");
+            e
+        });
+
+        body.push({
+            let mut d = Tokens::new();
+            d.push("fn decode(repr: Self::Repr) -> Result<Self, String> {");
+            d.nested("repr.into_iter().map(|(k, v)| T::decode(v).map(|v| (k, v))).collect()");
+            d.push("}");
+            d
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
+
+fn serialize<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+    t.push("pub fn serialize<T: Encode, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>");
+    t.push("where");
+    t.nested("S: ::serde::Serializer,");
+    t.push("{");
+    t.nested("::serde::Serialize::serialize(&value.encode(), serializer)");
+    t.push("}");
+    t
+}
+
+fn deserialize<'el>() -> Tokens<'el, Rust<'el>> {
+    let mut t = Tokens::new();
+    t.push("pub fn deserialize<'de, T: Encode, D>(deserializer: D) -> Result<T, D::Error>");
+    t.push("where");
+    t.nested("D: ::serde::Deserializer<'de>,");
+    t.push("{");
+
+    t.nested({
+        let mut body = Tokens::new();
+        body.push("use ::serde::de::Error;");
+
+        body.push({
+            let mut s = Tokens::new();
+            s.push("let repr = ::serde::Deserialize::deserialize(deserializer)?;");
+            s.push("T::decode(repr).map_err(D::Error::custom)");
+            s
+        });
+
+        body.join_line_spacing()
+    });
+
+    t.push("}");
+    t
+}
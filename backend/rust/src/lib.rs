@@ -9,9 +9,12 @@ extern crate genco;
 extern crate reproto_backend as backend;
 extern crate reproto_core as core;
 extern crate reproto_manifest as manifest;
+#[cfg(test)]
+extern crate reproto_repository as repository;
 extern crate serde;
 extern crate toml;
 
+mod helper;
 mod listeners;
 mod rust_backend;
 mod rust_compiler;
@@ -31,6 +34,9 @@ const MOD: &str = "mod";
 const EXT: &str = "rs";
 const RUST_CONTEXT: &str = "rust";
 
+/// Endpoint options understood by the backend.
+const ENDPOINT_OPTIONS: &[&str] = &["http_status"];
+
 #[derive(Default)]
 pub struct RustLang;
 
@@ -40,8 +46,12 @@ impl Lang for RustLang {
 
 #[derive(Debug)]
 pub enum RustModule {
+    BigDecimal,
     Chrono,
     Grpc,
+    Reqwest,
+    Rpc,
+    Uuid,
 }
 
 impl TryFromToml for RustModule {
@@ -49,8 +59,12 @@ impl TryFromToml for RustModule {
         use self::RustModule::*;
 
         let result = match id {
+            "bigdecimal" => BigDecimal,
             "chrono" => Chrono,
             "grpc" => Grpc,
+            "reqwest" => Reqwest,
+            "rpc" => Rpc,
+            "uuid" => Uuid,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -61,8 +75,12 @@ impl TryFromToml for RustModule {
         use self::RustModule::*;
 
         let result = match id {
+            "bigdecimal" => BigDecimal,
             "chrono" => Chrono,
             "grpc" => Grpc,
+            "reqwest" => Reqwest,
+            "rpc" => Rpc,
+            "uuid" => Uuid,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -79,8 +97,12 @@ pub fn setup_listeners(modules: &[RustModule]) -> Result<(RustOptions, Box<Liste
         debug!("+module: {:?}", module);
 
         let listener = match *module {
+            BigDecimal => Box::new(module::BigDecimal::new()) as Box<Listeners>,
             Chrono => Box::new(module::Chrono::new()) as Box<Listeners>,
             Grpc => Box::new(module::Grpc::new()) as Box<Listeners>,
+            Reqwest => Box::new(module::Reqwest::new()) as Box<Listeners>,
+            Rpc => Box::new(module::Rpc::new()) as Box<Listeners>,
+            Uuid => Box::new(module::Uuid::new()) as Box<Listeners>,
         };
 
         listeners.push(listener);
@@ -102,6 +124,7 @@ pub fn compile(
     _matches: &ArgMatches,
     manifest: Manifest<RustLang>,
 ) -> Result<()> {
+    env.verify_endpoint_options(ENDPOINT_OPTIONS)?;
     let id_converter = opts.id_converter;
     let (options, listeners) = setup_listeners(&manifest.modules)?;
     let backend = RustBackend::new(env, options, listeners, id_converter);
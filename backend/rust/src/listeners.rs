@@ -1,13 +1,24 @@
 /// Plugin infrastructure for Rust Backend.
 
+use super::rust_backend::RustBackend;
 use super::rust_options::RustOptions;
 use backend::errors::*;
+use core::RpServiceBody;
+use genco::{Rust, Tokens};
+
+pub struct ServiceAdded<'a, 'el: 'a> {
+    pub backend: &'a RustBackend,
+    pub body: &'el RpServiceBody,
+    pub out: &'a mut Tokens<'el, Rust<'el>>,
+}
 
 pub trait Listeners {
     listeners_vec_default!(configure, RustOptions);
+    listeners_vec_default!(service_added, ServiceAdded);
 }
 
 /// A vector of listeners is a valid listener.
 impl Listeners for Vec<Box<Listeners>> {
     listeners_vec!(configure, RustOptions);
+    listeners_vec!(service_added, ServiceAdded);
 }
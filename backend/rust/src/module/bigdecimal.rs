@@ -0,0 +1,24 @@
+//! BigDecimal module for Rust.
+
+use backend::errors::*;
+use genco::Rust;
+use genco::rust::imported_ref;
+use listeners::Listeners;
+use rust_options::RustOptions;
+
+pub struct Module {
+    big_decimal: Rust<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module { big_decimal: imported_ref("bigdecimal", "BigDecimal") }
+    }
+}
+
+impl Listeners for Module {
+    fn configure(&self, options: &mut RustOptions) -> Result<()> {
+        options.decimal = Some(self.big_decimal.clone().into());
+        Ok(())
+    }
+}
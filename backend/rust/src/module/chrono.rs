@@ -9,6 +9,8 @@ use rust_options::RustOptions;
 pub struct Module {
     datetime: Rust<'static>,
     offset_utc: Rust<'static>,
+    naive_date: Rust<'static>,
+    duration: Rust<'static>,
 }
 
 impl Module {
@@ -16,6 +18,8 @@ impl Module {
         Module {
             datetime: imported_ref("chrono", "DateTime"),
             offset_utc: imported_ref("chrono::offset", "Utc"),
+            naive_date: imported_ref("chrono", "NaiveDate"),
+            duration: imported_ref("chrono", "Duration"),
         }
     }
 }
@@ -29,6 +33,8 @@ impl Listeners for Module {
             ">",
         ]);
 
+        options.date = Some(self.naive_date.clone().into());
+        options.duration = Some(self.duration.clone().into());
         Ok(())
     }
 }
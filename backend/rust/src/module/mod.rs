@@ -1,5 +1,13 @@
+mod bigdecimal;
 mod chrono;
 mod grpc;
+mod reqwest;
+mod rpc;
+mod uuid;
 
+pub use self::bigdecimal::Module as BigDecimal;
 pub use self::chrono::Module as Chrono;
 pub use self::grpc::Module as Grpc;
+pub use self::reqwest::Module as Reqwest;
+pub use self::rpc::Module as Rpc;
+pub use self::uuid::Module as Uuid;
@@ -0,0 +1,300 @@
+//! reqwest module for Rust.
+//!
+//! Builds a client for every service, which calls endpoints with HTTP bindings.
+//!
+//! If every endpoint of a service has an HTTP binding, the client also implements the service
+//! trait. Requests are sent when a method is called, so the returned futures are already resolved.
+
+use backend::errors::*;
+use core::{RpEndpoint, RpEndpointHttp, RpHttpMethod, RpPathSegment};
+use genco::{Quoted, Rust, Tokens};
+use genco::rust::imported_ref;
+use listeners::{Listeners, ServiceAdded};
+use rust_backend::RustBackend;
+use std::rc::Rc;
+
+pub struct Module {
+    client: Rust<'static>,
+    url: Rust<'static>,
+    method: Rust<'static>,
+    future_result: Rust<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {
+            client: imported_ref("reqwest", "Client"),
+            url: imported_ref("reqwest", "Url"),
+            method: imported_ref("reqwest", "Method"),
+            future_result: imported_ref("futures::future", "result"),
+        }
+    }
+
+    fn method_variant(method: &RpHttpMethod) -> &'static str {
+        use self::RpHttpMethod::*;
+
+        match *method {
+            Get => "Get",
+            Post => "Post",
+            Put => "Put",
+            Patch => "Patch",
+            Delete => "Delete",
+            Head => "Head",
+        }
+    }
+
+    /// Build statements appending the path of the binding to `url`.
+    fn path<'el>(
+        &self,
+        backend: &RustBackend,
+        http: &'el RpEndpointHttp,
+    ) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push("{");
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push(
+                "let mut path = url.path_segments_mut().expect(\"url cannot be a base\");",
+            );
+            t.push("path.pop_if_empty();");
+
+            for segment in &http.path.segments {
+                match *segment {
+                    RpPathSegment::Literal { ref value } => {
+                        t.push(toks!["path.push(", value.as_str().quoted(), ");"]);
+                    }
+                    RpPathSegment::Variable { ref name } => {
+                        let ident = Rc::new(backend.ident(name.as_str()));
+                        t.push(toks!["path.push(&request.", ident, ".to_string());"]);
+                    }
+                }
+            }
+
+            t
+        });
+
+        t.push("}");
+        t
+    }
+
+    fn endpoint<'el>(
+        &self,
+        backend: &RustBackend,
+        endpoint: &'el RpEndpoint,
+        http: &'el RpEndpointHttp,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let mut args = Tokens::new();
+        args.append("&self");
+
+        if let Some(ref req) = endpoint.request {
+            args.append(toks!["request: &", backend.into_rust_type(req.ty())?]);
+        }
+
+        let response = match endpoint.response {
+            Some(ref res) => backend.into_rust_type(res.ty())?,
+            None => toks!["()"],
+        };
+
+        let mut t = Tokens::new();
+
+        t.push(toks![
+            "pub fn ",
+            endpoint.id.as_str(),
+            "(",
+            args.join(", "),
+            ") -> Result<",
+            response,
+            ", Box<::std::error::Error>> {",
+        ]);
+
+        t.nested({
+            let mut body = Tokens::new();
+
+            if http.path.segments.is_empty() {
+                body.push("let url = self.url.clone();");
+            } else {
+                body.push("let mut url = self.url.clone();");
+                body.push(self.path(backend, http));
+            }
+
+            let mut send = toks![
+                "self.client.request(",
+                self.method.clone(),
+                "::",
+                Self::method_variant(http.method.value()),
+                ", url)",
+            ];
+
+            if http.method.has_body() && endpoint.request.is_some() {
+                send.append(".json(request)");
+            }
+
+            send.append(".send()?");
+
+            // a declared status is the only one accepted, otherwise any successful status is.
+            let status = endpoint.http_status()?;
+
+            if status.is_none() {
+                send.append(".error_for_status()?");
+            }
+
+            if endpoint.response.is_some() {
+                body.push(toks!["let mut response = ", send, ";"]);
+            } else if status.is_some() {
+                body.push(toks!["let response = ", send, ";"]);
+            } else {
+                body.push(toks![send, ";"]);
+            }
+
+            if let Some(status) = status {
+                let mut check = Tokens::new();
+                check.push(toks![
+                    "if response.status().as_u16() != ",
+                    Rc::new(status.to_string()),
+                    " {",
+                ]);
+                check.nested(
+                    "return Err(format!(\"bad response: {}\", response.status()).into());",
+                );
+                check.push("}");
+                body.push(check);
+            }
+
+            if endpoint.response.is_some() {
+                body.push("Ok(response.json()?)");
+            } else {
+                body.push("Ok(())");
+            }
+
+            body.join_line_spacing()
+        });
+
+        t.push("}");
+        Ok(t)
+    }
+
+    /// Build the implementation of the service trait for the given endpoint, which calls the
+    /// method of the client.
+    fn trait_endpoint<'el>(
+        &self,
+        backend: &RustBackend,
+        name: Rc<String>,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let mut args = Tokens::new();
+        args.append("self");
+
+        if endpoint.request.is_some() {
+            args.append("&request");
+        }
+
+        let mut t = Tokens::new();
+
+        t.push(toks![backend.endpoint_signature(endpoint)?, " {"]);
+
+        t.nested(toks![
+            "Box::new(",
+            self.future_result.clone(),
+            "(",
+            name,
+            "::",
+            endpoint.id.as_str(),
+            "(",
+            args.join(", "),
+            ")))",
+        ]);
+
+        t.push("}");
+        Ok(t)
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        let name = Rc::new(format!("{}Reqwest", e.backend.convert_type_name(&e.body.name)));
+
+        let mut t = Tokens::new();
+
+        t.push(toks!["pub struct ", name.clone(), " {"]);
+        t.nested(toks!["client: ", self.client.clone(), ","]);
+        t.nested(toks!["url: ", self.url.clone(), ","]);
+        t.push("}");
+
+        e.out.push(t);
+
+        let mut t = Tokens::new();
+
+        t.push(toks!["impl ", name.clone(), " {"]);
+
+        t.nested({
+            let mut body = Tokens::new();
+
+            body.push({
+                let mut t = Tokens::new();
+
+                t.push(toks![
+                    "pub fn new(client: ",
+                    self.client.clone(),
+                    ", url: ",
+                    self.url.clone(),
+                    ") -> ",
+                    name.clone(),
+                    " {",
+                ]);
+
+                t.nested({
+                    let mut t = Tokens::new();
+                    t.push(toks![name.clone(), " {"]);
+                    t.nested("client: client,");
+                    t.nested("url: url,");
+                    t.push("}");
+                    t
+                });
+
+                t.push("}");
+                t
+            });
+
+            for endpoint in e.body.endpoints.values() {
+                if let Some(ref http) = endpoint.http {
+                    body.push(self.endpoint(e.backend, endpoint, http)?);
+                }
+            }
+
+            body.join_line_spacing()
+        });
+
+        t.push("}");
+
+        e.out.push(t);
+
+        // the service trait can only be implemented if every endpoint can be called.
+        if e.body.endpoints.values().all(|endpoint| endpoint.http.is_some()) {
+            let service = e.backend.convert_type_name(&e.body.name);
+
+            let mut t = Tokens::new();
+
+            t.push(toks!["impl ", service, " for ", name.clone(), " {"]);
+
+            t.nested({
+                let mut body = Tokens::new();
+                body.push("type Error = Box<::std::error::Error>;");
+
+                for endpoint in e.body.endpoints.values() {
+                    body.push(self.trait_endpoint(e.backend, name.clone(), endpoint)?);
+                }
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+
+            e.out.push(t);
+        }
+
+        Ok(())
+    }
+}
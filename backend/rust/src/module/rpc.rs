@@ -0,0 +1,532 @@
+//! RPC module for Rust.
+//!
+//! Builds a client and a server dispatcher for every service, which exchange JSON values over a
+//! transport implemented by the user.
+
+use backend::errors::*;
+use core::{Loc, RpEndpoint};
+use genco::{Quoted, Rust, Tokens};
+use genco::rust::{imported_alias_ref, imported_ref};
+use listeners::{Listeners, ServiceAdded};
+use rust_backend::RustBackend;
+use std::rc::Rc;
+
+pub struct Module {
+    rc: Rust<'static>,
+    stream_once: Rust<'static>,
+    stream_empty: Rust<'static>,
+    json_value: Rust<'static>,
+    json_error: Rust<'static>,
+    to_value: Rust<'static>,
+    from_value: Rust<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {
+            rc: imported_ref("std::rc", "Rc"),
+            stream_once: imported_ref("futures::stream", "once"),
+            stream_empty: imported_ref("futures::stream", "empty"),
+            json_value: imported_alias_ref("serde_json", "Value", "json"),
+            json_error: imported_alias_ref("serde_json", "Error", "json"),
+            to_value: imported_alias_ref("serde_json", "to_value", "json"),
+            from_value: imported_alias_ref("serde_json", "from_value", "json"),
+        }
+    }
+
+    /// Local import of the futures traits used in a function body.
+    fn use_futures(future: bool, stream: bool) -> Option<&'static str> {
+        match (future, stream) {
+            (true, true) => Some("use futures::{Future, Stream};"),
+            (true, false) => Some("use futures::Future;"),
+            (false, true) => Some("use futures::Stream;"),
+            (false, false) => None,
+        }
+    }
+
+    /// Encode the value of `var` as JSON.
+    fn encode<'el>(&self, var: &'static str, error: &'static str) -> Tokens<'el, Rust<'el>> {
+        toks![self.to_value.clone(), "(", var, ").map_err(", error, "::from)"]
+    }
+
+    /// Decode the JSON value of `var` into the given type.
+    fn decode<'el>(
+        &self,
+        ty: Tokens<'el, Rust<'el>>,
+        var: &'static str,
+        error: &'static str,
+    ) -> Tokens<'el, Rust<'el>> {
+        toks![
+            self.from_value.clone(),
+            "::<",
+            ty,
+            ">(",
+            var,
+            ").map_err(",
+            error,
+            "::from)",
+        ]
+    }
+
+    /// Build a future decoding the first value of `stream` into the given type.
+    ///
+    /// The future fails if the stream ends without a value.
+    fn decode_first<'el>(
+        &self,
+        prefix: &'static str,
+        stream: &'static str,
+        ty: Tokens<'el, Rust<'el>>,
+        what: &'static str,
+        error: &'static str,
+        suffix: &'static str,
+    ) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks![
+            prefix,
+            stream,
+            ".into_future().map_err(|(e, _)| e).and_then(|(value, _)| {",
+        ]);
+
+        t.nested({
+            let mut t = Tokens::new();
+            t.push("value");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push(toks![
+                    ".ok_or_else(|| <",
+                    self.json_error.clone(),
+                    " as ::serde::de::Error>::custom(",
+                    what.quoted(),
+                    "))",
+                ]);
+
+                t.push(toks![".and_then(", self.from_value.clone(), "::<", ty, ">)"]);
+                t.push(toks![".map_err(", error, "::from)"]);
+                t
+            });
+
+            t
+        });
+
+        t.push(toks!["})", suffix]);
+        t
+    }
+
+    fn transport<'el>(
+        &self,
+        backend: &RustBackend,
+        transport: Rc<String>,
+    ) -> Tokens<'el, Rust<'el>> {
+        let values = backend.boxed_stream(toks![self.json_value.clone()], "Self::Error");
+
+        let mut t = Tokens::new();
+
+        t.push(toks!["pub trait ", transport, " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push(toks![
+                "type Error: From<",
+                self.json_error.clone(),
+                "> + 'static;",
+            ]);
+
+            t.push(toks![
+                "fn call(&self, endpoint: &'static str, requests: ",
+                values.clone(),
+                ") -> ",
+                values,
+                ";",
+            ]);
+
+            t.join_line_spacing()
+        });
+
+        t.push("}");
+        t
+    }
+
+    fn client_method<'el>(
+        &self,
+        backend: &RustBackend,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let mut t = Tokens::new();
+
+        t.push(toks![backend.endpoint_signature(endpoint)?, " {"]);
+
+        t.nested({
+            let mut body = Tokens::new();
+
+            let unary_response = endpoint
+                .response
+                .as_ref()
+                .map(|res| !res.is_streaming())
+                .unwrap_or(false);
+
+            if let Some(use_futures) = Self::use_futures(unary_response, true) {
+                body.push(use_futures);
+            }
+
+            let requests = match endpoint.request {
+                Some(ref req) if req.is_streaming() => {
+                    toks![
+                        "Box::new(request.and_then(|r| ",
+                        self.encode("r", "T::Error"),
+                        "))",
+                    ]
+                }
+                Some(_) => {
+                    toks![
+                        "Box::new(",
+                        self.stream_once.clone(),
+                        "(",
+                        self.encode("request", "T::Error"),
+                        "))",
+                    ]
+                }
+                None => {
+                    toks![
+                        "Box::new(",
+                        self.stream_empty.clone(),
+                        "::<",
+                        self.json_value.clone(),
+                        ", T::Error>())",
+                    ]
+                }
+            };
+
+            body.push(toks![
+                "let responses = self.transport.call(",
+                endpoint.name().quoted(),
+                ", ",
+                requests,
+                ");",
+            ]);
+
+            match endpoint.response {
+                Some(ref res) if res.is_streaming() => {
+                    let ty = backend.into_rust_type(res.ty())?;
+
+                    body.push(toks![
+                        "Box::new(responses.and_then(|r| ",
+                        self.decode(ty, "r", "T::Error"),
+                        "))",
+                    ]);
+                }
+                Some(ref res) => {
+                    let ty = backend.into_rust_type(res.ty())?;
+
+                    body.push(self.decode_first(
+                        "Box::new(",
+                        "responses",
+                        ty,
+                        "missing response",
+                        "T::Error",
+                        ")",
+                    ));
+                }
+                None => {
+                    body.push("Box::new(responses.for_each(|_| Ok(())))");
+                }
+            }
+
+            body.join_line_spacing()
+        });
+
+        t.push("}");
+        Ok(t)
+    }
+
+    fn client<'a, 'el: 'a>(
+        &self,
+        e: &ServiceAdded<'a, 'el>,
+        name: Rc<String>,
+        transport: Rc<String>,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let client = Rc::new(format!("{}Client", name));
+
+        let mut out = Tokens::new();
+
+        out.push({
+            let mut t = Tokens::new();
+            t.push(toks!["pub struct ", client.clone(), "<T> {"]);
+            t.nested("transport: T,");
+            t.push("}");
+            t
+        });
+
+        out.push({
+            let mut t = Tokens::new();
+
+            t.push(toks!["impl<T: ", transport.clone(), "> ", client.clone(), "<T> {"]);
+
+            t.nested({
+                let mut t = Tokens::new();
+                t.push(toks!["pub fn new(transport: T) -> ", client.clone(), "<T> {"]);
+                t.nested(toks![client.clone(), " { transport: transport }"]);
+                t.push("}");
+                t
+            });
+
+            t.push("}");
+            t
+        });
+
+        out.push({
+            let mut t = Tokens::new();
+
+            t.push(toks![
+                "impl<T: ",
+                transport.clone(),
+                " + 'static> ",
+                name.clone(),
+                " for ",
+                client.clone(),
+                "<T> {",
+            ]);
+
+            t.nested({
+                let mut body = Tokens::new();
+                body.push("type Error = T::Error;");
+
+                for endpoint in e.body.endpoints.values().map(Loc::value) {
+                    body.push(self.client_method(e.backend, endpoint)?);
+                }
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        Ok(out.join_line_spacing())
+    }
+
+    /// Build the match arm dispatching to the given endpoint.
+    fn server_arm<'el>(
+        &self,
+        backend: &RustBackend,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let id = endpoint.id.as_str();
+        let mut body = Tokens::new();
+
+        match endpoint.request {
+            Some(ref req) if req.is_streaming() => {
+                let ty = backend.into_rust_type(req.ty())?;
+
+                body.push(toks![
+                    "let response = self.service.",
+                    id,
+                    "(Box::new(requests.and_then(|r| ",
+                    self.decode(ty, "r", "S::Error"),
+                    ")));",
+                ]);
+            }
+            Some(ref req) => {
+                let ty = backend.into_rust_type(req.ty())?;
+
+                body.push("let service = self.service.clone();");
+
+                body.push(self.decode_first(
+                    "let request = ",
+                    "requests",
+                    ty,
+                    "missing request",
+                    "S::Error",
+                    ";",
+                ));
+
+                let streaming_response = endpoint
+                    .response
+                    .as_ref()
+                    .map(|res| res.is_streaming())
+                    .unwrap_or(false);
+
+                if streaming_response {
+                    body.push(toks![
+                        "let response = request.map(move |request| service.",
+                        id,
+                        "(request)).flatten_stream();",
+                    ]);
+                } else {
+                    body.push(toks![
+                        "let response = request.and_then(move |request| service.",
+                        id,
+                        "(request));",
+                    ]);
+                }
+            }
+            None => {
+                body.push(toks!["let response = self.service.", id, "();"]);
+            }
+        }
+
+        match endpoint.response {
+            Some(ref res) if res.is_streaming() => {
+                body.push(toks![
+                    "Box::new(response.and_then(|r| ",
+                    self.encode("r", "S::Error"),
+                    "))",
+                ]);
+            }
+            Some(_) => {
+                body.push(toks![
+                    "Box::new(response.and_then(|r| ",
+                    self.encode("r", "S::Error"),
+                    ").into_stream())",
+                ]);
+            }
+            None => {
+                body.push(toks![
+                    "Box::new(response.into_stream().filter_map(|()| None::<",
+                    self.json_value.clone(),
+                    ">))",
+                ]);
+            }
+        }
+
+        let mut t = Tokens::new();
+        t.push(toks![endpoint.name().quoted(), " => {"]);
+        t.nested(body.join_line_spacing());
+        t.push("}");
+        Ok(t)
+    }
+
+    fn server<'a, 'el: 'a>(
+        &self,
+        e: &ServiceAdded<'a, 'el>,
+        name: Rc<String>,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let server = Rc::new(format!("{}Server", name));
+        let values = e.backend.boxed_stream(toks![self.json_value.clone()], "S::Error");
+
+        let mut out = Tokens::new();
+
+        out.push({
+            let mut t = Tokens::new();
+            t.push(toks!["pub struct ", server.clone(), "<S> {"]);
+            t.nested(toks!["service: ", self.rc.clone(), "<S>,"]);
+            t.push("}");
+            t
+        });
+
+        let mut uses_future = false;
+        let mut uses_stream = false;
+        let mut arms = Tokens::new();
+
+        for endpoint in e.body.endpoints.values().map(Loc::value) {
+            if let Some(ref req) = endpoint.request {
+                uses_stream = true;
+                uses_future = uses_future || !req.is_streaming();
+            }
+
+            match endpoint.response {
+                Some(ref res) if res.is_streaming() => uses_stream = true,
+                _ => uses_future = true,
+            }
+
+            arms.push(self.server_arm(e.backend, endpoint)?);
+        }
+
+        arms.push("_ => return None,");
+
+        out.push({
+            let mut t = Tokens::new();
+
+            t.push(toks!["impl<S: ", name.clone(), " + 'static> ", server.clone(), "<S>"]);
+            t.push("where");
+            t.nested(toks![
+                "S::Error: From<",
+                self.json_error.clone(),
+                "> + 'static,",
+            ]);
+            t.push("{");
+
+            t.nested({
+                let mut body = Tokens::new();
+
+                body.push({
+                    let mut t = Tokens::new();
+                    t.push(toks!["pub fn new(service: S) -> ", server.clone(), "<S> {"]);
+                    t.nested(toks![
+                        server.clone(),
+                        " { service: ",
+                        self.rc.clone(),
+                        "::new(service) }",
+                    ]);
+                    t.push("}");
+                    t
+                });
+
+                body.push({
+                    let mut t = Tokens::new();
+
+                    t.push(toks![
+                        "pub fn dispatch(&self, endpoint: &str, requests: ",
+                        values.clone(),
+                        ") -> Option<",
+                        values.clone(),
+                        "> {",
+                    ]);
+
+                    t.nested({
+                        let mut body = Tokens::new();
+
+                        if let Some(use_futures) = Self::use_futures(uses_future, uses_stream) {
+                            body.push(use_futures);
+                        }
+
+                        body.push({
+                            let mut t = Tokens::new();
+                            t.push(toks!["let responses: ", values.clone(), " = match endpoint {"]);
+                            t.nested(arms);
+                            t.push("};");
+                            t
+                        });
+
+                        body.push("Some(responses)");
+                        body.join_line_spacing()
+                    });
+
+                    t.push("}");
+                    t
+                });
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        Ok(out.join_line_spacing())
+    }
+}
+
+impl Listeners for Module {
+    fn service_added(&self, e: &mut ServiceAdded) -> Result<()> {
+        // nothing to call or dispatch to.
+        if e.body.endpoints.is_empty() {
+            return Ok(());
+        }
+
+        let name = e.backend.convert_type_name(&e.body.name);
+        let transport = Rc::new(format!("{}Transport", name));
+
+        let transport_trait = self.transport(e.backend, transport.clone());
+        let client = self.client(e, name.clone(), transport)?;
+        let server = self.server(e, name)?;
+
+        e.out.push(transport_trait);
+        e.out.push(client);
+        e.out.push(server);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//! Uuid module for Rust.
+
+use backend::errors::*;
+use genco::Rust;
+use genco::rust::imported_ref;
+use listeners::Listeners;
+use rust_options::RustOptions;
+
+pub struct Module {
+    uuid: Rust<'static>,
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module { uuid: imported_ref("uuid", "Uuid") }
+    }
+}
+
+impl Listeners for Module {
+    fn configure(&self, options: &mut RustOptions) -> Result<()> {
+        options.uuid = Some(self.uuid.clone().into());
+        Ok(())
+    }
+}
@@ -1,17 +1,21 @@
 //! Backend for Rust
 
 use super::RUST_CONTEXT;
-use backend::{Code, CompilerOptions, Environment, FromNaming, Naming, PackageUtils, SnakeCase};
+use backend::{Code, CompilerOptions, Environment, FromNaming, Naming, PackageUtils, SnakeCase,
+              ValueBuilder};
 use backend::errors::*;
-use core::{ForEachLoc, Loc, RpEnumBody, RpEnumOrdinal, RpField, RpInterfaceBody, RpName,
-           RpServiceBody, RpTupleBody, RpType, RpTypeBody};
+use core::{ForEachLoc, Loc, RpEndpoint, RpEnumBody, RpEnumType, RpField, RpInterfaceBody, RpName,
+           RpNumber, RpServiceBody, RpTupleBody, RpType, RpTypeBody, RpTypeInfo,
+           RpVariantValue};
 use genco::{Element, IntoTokens, Quoted, Rust, Tokens};
 use genco::rust::{imported_alias, imported_alias_ref, imported_ref};
-use listeners::Listeners;
+use helper::Helper;
+use listeners::{Listeners, ServiceAdded};
 use rust_compiler::RustCompiler;
 use rust_file_spec::RustFileSpec;
 use rust_options::RustOptions;
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::rc::Rc;
 
 /// Serializer derives.
@@ -41,6 +45,15 @@ impl<'a> IntoTokens<'a, Rust<'a>> for Tag<'a> {
     }
 }
 
+/// Remote attribute, used to derive serde implementations as inherent functions.
+pub struct Remote(Rc<String>);
+
+impl<'a> IntoTokens<'a, Rust<'a>> for Remote {
+    fn into_tokens(self) -> Tokens<'a, Rust<'a>> {
+        toks!["#[serde(remote = ", self.0.quoted(), ")]"]
+    }
+}
+
 const TYPE_SEP: &'static str = "_";
 const SCOPE_SEP: &'static str = "::";
 
@@ -51,7 +64,15 @@ pub struct RustBackend {
     to_lower_snake: Box<Naming>,
     hash_map: Rust<'static>,
     json_value: Rust<'static>,
+    json_map: Rust<'static>,
+    json_serializer: Rust<'static>,
+    future: Rust<'static>,
+    stream: Rust<'static>,
     datetime: Option<Tokens<'static, Rust<'static>>>,
+    date: Option<Tokens<'static, Rust<'static>>>,
+    duration: Option<Tokens<'static, Rust<'static>>>,
+    uuid: Option<Tokens<'static, Rust<'static>>>,
+    decimal: Option<Tokens<'static, Rust<'static>>>,
 }
 
 impl RustBackend {
@@ -68,7 +89,15 @@ impl RustBackend {
             to_lower_snake: SnakeCase::new().to_lower_snake(),
             hash_map: imported_ref("std::collections", "HashMap"),
             json_value: imported_alias_ref("serde_json", "Value", "json"),
+            json_map: imported_alias_ref("serde_json", "Map", "json"),
+            json_serializer: imported_alias_ref("serde_json", "value::Serializer", "json"),
+            future: imported_ref("futures", "Future"),
+            stream: imported_ref("futures", "Stream"),
             datetime: options.datetime.clone(),
+            date: options.date.clone(),
+            duration: options.duration.clone(),
+            uuid: options.uuid.clone(),
+            decimal: options.decimal.clone(),
         }
     }
 
@@ -94,7 +123,7 @@ impl RustBackend {
         out_impl
     }
 
-    fn ident(&self, name: &str) -> String {
+    pub fn ident(&self, name: &str) -> String {
         if let Some(ref id_converter) = self.id_converter {
             id_converter.convert(name)
         } else {
@@ -102,7 +131,7 @@ impl RustBackend {
         }
     }
 
-    fn convert_type_name(&self, name: &RpName) -> Rc<String> {
+    pub fn convert_type_name(&self, name: &RpName) -> Rc<String> {
         Rc::new(name.join(TYPE_SEP))
     }
 
@@ -138,6 +167,7 @@ impl RustBackend {
     fn enum_value_fn<'a>(
         &self,
         name: Rc<String>,
+        returns: Tokens<'a, Rust<'a>>,
         match_body: Tokens<'a, Rust<'a>>,
     ) -> Tokens<'a, Rust<'a>> {
         let mut value_fn = Tokens::new();
@@ -147,7 +177,7 @@ impl RustBackend {
         match_decl.nested(match_body);
         match_decl.push("}");
 
-        value_fn.push("pub fn value(&self) -> &'static str {");
+        value_fn.push(toks!["pub fn value(&self) -> ", returns, " {"]);
         value_fn.nested(toks!["use self::", name, "::*;"]);
         value_fn.nested(match_decl);
         value_fn.push("}");
@@ -155,14 +185,18 @@ impl RustBackend {
         value_fn
     }
 
-    fn datetime<'a>(&self, ty: &RpType) -> Result<Tokens<'a, Rust<'a>>> {
-        if let Some(ref datetime) = self.datetime {
-            return Ok(datetime.clone().into());
+    /// Use the type provided by a module, or suggest the module which provides it.
+    fn module_type<'a>(
+        &self,
+        provided: &Option<Tokens<'static, Rust<'static>>>,
+        ty: &RpType,
+        suggestion: &'static str,
+    ) -> Result<Tokens<'a, Rust<'a>>> {
+        if let Some(ref provided) = *provided {
+            return Ok(provided.clone().into());
         }
 
-        Err(
-            ErrorKind::MissingTypeImpl(ty.clone(), "try: -m chrono").into(),
-        )
+        Err(ErrorKind::MissingTypeImpl(ty.clone(), suggestion).into())
     }
 
     pub fn into_rust_type<'a>(&self, ty: &'a RpType) -> Result<Tokens<'a, Rust<'a>>> {
@@ -170,11 +204,19 @@ impl RustBackend {
 
         let ty = match *ty {
             String => toks!["String"],
-            DateTime => self.datetime(ty)?,
-            Bytes => toks!["String"],
+            DateTime => self.module_type(&self.datetime, ty, "try: -m chrono")?,
+            Date => self.module_type(&self.date, ty, "try: -m chrono")?,
+            Duration => self.module_type(&self.duration, ty, "try: -m chrono")?,
+            Uuid => self.module_type(&self.uuid, ty, "try: -m uuid")?,
+            Decimal => self.module_type(&self.decimal, ty, "try: -m bigdecimal")?,
+            Bytes => toks!["Vec<u8>"],
+            Signed { size: 8 } => toks!["i8"],
+            Signed { size: 16 } => toks!["i16"],
             Signed { size: 32 } => toks!["i32"],
             Signed { size: 64 } => toks!["i64"],
-            Unsigned { size: 32 } => toks!["i32"],
+            Unsigned { size: 8 } => toks!["u8"],
+            Unsigned { size: 16 } => toks!["u16"],
+            Unsigned { size: 32 } => toks!["u32"],
             Unsigned { size: 64 } => toks!["u64"],
             Float => toks!["f32"],
             Double => toks!["f64"],
@@ -197,7 +239,15 @@ impl RustBackend {
     }
 
     // Build the corresponding element out of a field declaration.
-    fn field_element<'a>(&self, field: &'a RpField) -> Result<Tokens<'a, Rust<'a>>> {
+    //
+    // Default values and constraints of the field are implemented by functions which are added
+    // to `functions`.
+    fn field_element<'a>(
+        &self,
+        functions: &mut Vec<Tokens<'a, Rust<'a>>>,
+        owner: &str,
+        field: &'a RpField,
+    ) -> Result<Tokens<'a, Rust<'a>>> {
         let mut elements = Tokens::new();
 
         let ident = self.ident(field.ident());
@@ -211,11 +261,190 @@ impl RustBackend {
             elements.push(Rename(field.name()));
         }
 
+        let helper = Helper::of_field(field)?;
+
+        // absent fields are not passed to helpers, or to the function which checks constraints.
+        if field.is_optional() && field.default.is_none() &&
+            (helper.is_some() || !field.constraints.is_empty())
+        {
+            elements.push("#[serde(default)]");
+        }
+
+        if let Some(helper) = helper {
+            if field.constraints.is_empty() {
+                elements.push(toks!["#[serde(with = ", helper.path().quoted(), ")]"]);
+            } else {
+                // the function which checks constraints decodes through the helper.
+                let serialize = format!("{}::serialize", helper.path());
+                elements.push(toks!["#[serde(serialize_with = ", serialize.quoted(), ")]"]);
+            }
+        }
+
+        if let Some(ref default) = field.default {
+            let name = Rc::new(format!("default_{}_{}", owner.to_lowercase(), ident));
+            elements.push(toks!["#[serde(default = ", name.clone().quoted(), ")]"]);
+
+            let mut t = Tokens::new();
+            t.push(toks!["fn ", name, "() -> ", type_spec.clone(), " {"]);
+            t.nested(toks!["Some(", self.value(&field.ty, default.value()), ")"]);
+            t.push("}");
+            functions.push(t);
+        }
+
+        if !field.constraints.is_empty() {
+            let name = Rc::new(format!("deserialize_{}_{}", owner.to_lowercase(), ident));
+            elements.push(toks!["#[serde(deserialize_with = ", name.clone().quoted(), ")]"]);
+
+            functions.push(self.constraints_deserialize(
+                name,
+                field,
+                helper,
+                type_spec.clone(),
+            )?);
+        }
+
         elements.push(toks![ident, ": ", type_spec, ","]);
 
         Ok(elements.into())
     }
 
+    /// Build a function that deserializes the given field, and checks its constraints.
+    ///
+    /// If the field is encoded through a helper, it is deserialized through the helper.
+    fn constraints_deserialize<'a>(
+        &self,
+        name: Rc<String>,
+        field: &'a RpField,
+        helper: Option<Helper>,
+        type_spec: Tokens<'a, Rust<'a>>,
+    ) -> Result<Tokens<'a, Rust<'a>>> {
+        let constraints = &field.constraints;
+
+        // literal of the same type as the field.
+        let literal = |number: &RpNumber| -> Rc<String> {
+            match field.ty {
+                RpType::Float => Rc::new(format!("{}f32", number)),
+                RpType::Double => Rc::new(format!("{}f64", number)),
+                _ => Rc::new(number.to_string()),
+            }
+        };
+
+        let mut checks: Vec<(Tokens<'a, Rust<'a>>, String)> = Vec::new();
+
+        if let Some(ref min) = constraints.min {
+            checks.push((
+                toks!["*value < ", literal(min)],
+                format!("{}: must be at least {}", field.name(), min),
+            ));
+        }
+
+        if let Some(ref max) = constraints.max {
+            checks.push((
+                toks!["*value > ", literal(max)],
+                format!("{}: must be at most {}", field.name(), max),
+            ));
+        }
+
+        if let Some(min_length) = constraints.min_length {
+            checks.push((
+                toks!["value.chars().count() < ", Rc::new(min_length.to_string())],
+                format!("{}: length must be at least {}", field.name(), min_length),
+            ));
+        }
+
+        if let Some(max_length) = constraints.max_length {
+            checks.push((
+                toks!["value.chars().count() > ", Rc::new(max_length.to_string())],
+                format!("{}: length must be at most {}", field.name(), max_length),
+            ));
+        }
+
+        if let Some(ref pattern) = constraints.pattern {
+            let regex = toks!["::regex::Regex::new(", pattern.as_str().quoted(), ")"];
+            let regex = toks![regex, ".map_err(D::Error::custom)?"];
+
+            checks.push((
+                toks!["!", regex, ".is_match(value)"],
+                format!("{}: must match the pattern {}", field.name(), pattern),
+            ));
+        }
+
+        if let Some(min_items) = constraints.min_items {
+            checks.push((
+                toks!["value.len() < ", Rc::new(min_items.to_string())],
+                format!("{}: must have at least {} item(s)", field.name(), min_items),
+            ));
+        }
+
+        if let Some(max_items) = constraints.max_items {
+            checks.push((
+                toks!["value.len() > ", Rc::new(max_items.to_string())],
+                format!("{}: must have at most {} item(s)", field.name(), max_items),
+            ));
+        }
+
+        if constraints.non_empty {
+            checks.push((
+                toks!["value.is_empty()"],
+                format!("{}: must not be empty", field.name()),
+            ));
+        }
+
+        let mut check_body = Tokens::new();
+
+        for (condition, message) in checks {
+            let mut check = Tokens::new();
+            check.push(toks!["if ", condition, " {"]);
+            check.nested(toks!["return Err(D::Error::custom(", message.quoted(), "));"]);
+            check.push("}");
+            check_body.push(check);
+        }
+
+        let mut t = Tokens::new();
+
+        t.push(toks![
+            "fn ",
+            name,
+            "<'de, D>(deserializer: D) -> Result<",
+            type_spec.clone(),
+            ", D::Error>",
+        ]);
+        t.push("where");
+        t.nested("D: ::serde::Deserializer<'de>,");
+        t.push("{");
+
+        t.nested({
+            let mut body = Tokens::new();
+            body.push("use ::serde::de::Error;");
+
+            let deserialize = match helper {
+                Some(helper) => toks![helper.path(), "::deserialize(deserializer)?;"],
+                None => toks!["::serde::Deserialize::deserialize(deserializer)?;"],
+            };
+
+            body.push(toks!["let out: ", type_spec, " = ", deserialize]);
+
+            let mut check = Tokens::new();
+
+            if field.is_optional() {
+                check.push("if let Some(value) = out.as_ref() {");
+            } else {
+                check.push("{");
+                check.nested("let value = &out;");
+            }
+
+            check.nested(check_body.join_line_spacing());
+            check.push("}");
+
+            body.push(check);
+            body.push("Ok(out)");
+            body.join_line_spacing()
+        });
+
+        t.push("}");
+        Ok(t)
+    }
+
     pub fn process_tuple<'a>(
         &self,
         out: &mut RustFileSpec<'a>,
@@ -224,9 +453,18 @@ impl RustBackend {
         let mut fields = Tokens::new();
 
         for field in &body.fields {
-            fields.push(self.into_type(field)?);
+            let ty = self.into_type(field)?;
+
+            if let Some(helper) = Helper::of_field(field)? {
+                fields.push(toks!["#[serde(with = ", helper.path().quoted(), ")] ", ty]);
+                continue;
+            }
+
+            fields.push(ty);
         }
 
+        add_helpers(&mut out.1, &body.fields)?;
+
         let name = self.convert_type_name(&body.name);
 
         let mut elements = Tokens::new();
@@ -246,36 +484,60 @@ impl RustBackend {
     pub fn process_enum<'a>(&self, out: &mut RustFileSpec<'a>, body: &'a RpEnumBody) -> Result<()> {
         let name = self.convert_type_name(&body.name);
 
+        // numeric enums are encoded through their value, like the repr of the enum.
+        let repr = match body.variant_type {
+            RpEnumType::Signed { .. } |
+            RpEnumType::Unsigned { .. } => Some(Rc::new(body.variant_type.to_string())),
+            _ => None,
+        };
+
         // variant declarations
         let mut variants = Tokens::new();
         // body of value function
         let mut match_body = Tokens::new();
+        // body of the match in the deserialize function, for numeric enums
+        let mut from_value_body = Tokens::new();
 
         body.variants.iter().for_each_loc(|variant| {
-            let value = if let RpEnumOrdinal::String(ref s) = variant.ordinal {
-                if s != variant.local_name.as_str() {
-                    variants.push(Rename(s.as_str()));
-                }
+            let local_name = variant.local_name.value().as_str();
 
-                s
-            } else {
-                variant.local_name.as_str()
-            };
+            let value: Element<'a, Rust<'a>> = match variant.ordinal() {
+                RpVariantValue::String(s) => {
+                    if s != local_name {
+                        variants.push(Rename(s));
+                    }
 
-            match_body.push(toks![
-                variant.local_name.value().as_str(),
-                " => ",
-                value.quoted(),
-                ",",
-            ]);
+                    s.quoted()
+                }
+                RpVariantValue::Number(number) => {
+                    let number = Rc::new(number.to_string());
+
+                    from_value_body.push(toks![
+                        number.clone(),
+                        " => Ok(",
+                        name.clone(),
+                        "::",
+                        local_name,
+                        "),",
+                    ]);
+
+                    number.into()
+                }
+            };
 
-            variants.push(toks![variant.local_name.value().as_str(), ","]);
+            match_body.push(toks![local_name, " => ", value, ","]);
+            variants.push(toks![local_name, ","]);
             Ok(()) as Result<()>
         })?;
 
         let mut out_enum = Tokens::new();
 
-        out_enum.push(Derives);
+        if repr.is_some() {
+            out_enum.push("#[derive(Clone, Copy, Debug, PartialEq, Eq)]");
+        } else {
+            out_enum.push(Derives);
+        }
+
         out_enum.push(toks!["pub enum ", name.clone(), " {"]);
         out_enum.nested(variants);
         out_enum.push("}");
@@ -285,8 +547,13 @@ impl RustBackend {
         out_impl.push(toks!["impl ", name.clone(), " {"]);
 
         out_impl.nested({
+            let returns = match repr {
+                Some(ref repr) => toks![repr.clone()],
+                None => toks!["&'static str"],
+            };
+
             let mut t = Tokens::new();
-            t.push(self.enum_value_fn(name.clone(), match_body));
+            t.push(self.enum_value_fn(name.clone(), returns, match_body));
             t.push_unless_empty(Code(&body.codes, RUST_CONTEXT));
             t
         });
@@ -295,17 +562,108 @@ impl RustBackend {
 
         out.0.push(out_enum);
         out.0.push(out_impl);
+
+        if let Some(repr) = repr {
+            out.0.push(self.repr_serialize(name.clone()));
+            out.0.push(self.repr_deserialize(name.clone(), repr, from_value_body));
+        }
+
         Ok(())
     }
 
+    /// Serialize a numeric enum as its value.
+    fn repr_serialize<'el>(&self, name: Rc<String>) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["impl ::serde::Serialize for ", name, " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push("fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>");
+            t.push("where");
+            t.nested("S: ::serde::Serializer,");
+            t.push("{");
+            t.nested("::serde::Serialize::serialize(&self.value(), serializer)");
+            t.push("}");
+
+            t
+        });
+
+        t.push("}");
+        t
+    }
+
+    /// Deserialize a numeric enum from its value.
+    fn repr_deserialize<'el>(
+        &self,
+        name: Rc<String>,
+        repr: Rc<String>,
+        from_value_body: Tokens<'el, Rust<'el>>,
+    ) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["impl<'de> ::serde::Deserialize<'de> for ", name.clone(), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push(toks![
+                "fn deserialize<D>(deserializer: D) -> Result<",
+                name,
+                ", D::Error>",
+            ]);
+            t.push("where");
+            t.nested("D: ::serde::Deserializer<'de>,");
+            t.push("{");
+
+            t.nested({
+                let mut body = Tokens::new();
+
+                body.push("use ::serde::de::Error;");
+
+                body.push(toks![
+                    "let value: ",
+                    repr,
+                    " = ::serde::Deserialize::deserialize(deserializer)?;",
+                ]);
+
+                body.push({
+                    let mut m = Tokens::new();
+                    m.push("match value {");
+                    m.nested(from_value_body);
+                    m.nested(
+                        "_ => Err(D::Error::custom(format!(\"unknown value: {}\", value))),",
+                    );
+                    m.push("}");
+                    m
+                });
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        t.push("}");
+        t
+    }
+
     pub fn process_type<'a>(&self, out: &mut RustFileSpec<'a>, body: &'a RpTypeBody) -> Result<()> {
         let mut fields = Tokens::new();
 
+        let name = self.convert_type_name(&body.name);
+        let mut functions = Vec::new();
+
         for field in &body.fields {
-            fields.push(field.as_ref().and_then(|f| self.field_element(f))?);
+            fields.push(field.as_ref().and_then(|f| {
+                self.field_element(&mut functions, name.as_str(), f)
+            })?);
         }
 
-        let name = self.convert_type_name(&body.name);
+        add_helpers(&mut out.1, &body.fields)?;
+
         let mut t = Tokens::new();
 
         t.push(Derives);
@@ -315,6 +673,10 @@ impl RustBackend {
 
         out.0.push(t);
 
+        for d in functions {
+            out.0.push(d);
+        }
+
         let impl_body = Code(&body.codes, RUST_CONTEXT).into_tokens();
 
         if !impl_body.is_empty() {
@@ -333,13 +695,22 @@ impl RustBackend {
         let mut t = Tokens::new();
 
         t.push(Derives);
-        t.push(Tag("type"));
+
+        match body.type_info {
+            RpTypeInfo::TypeField { ref name } => t.push(Tag(name.as_str())),
+            // serde represents enums externally tagged by default.
+            RpTypeInfo::ObjectKeys => {}
+            RpTypeInfo::Array => t.push(Remote(name.clone())),
+        }
+
         t.push(toks!["pub enum ", name.clone(), " {"]);
 
+        let mut functions = Vec::new();
         let sub_types = body.sub_types.values().map(AsRef::as_ref);
 
         sub_types.for_each_loc(|s| {
             let mut spec = Tokens::new();
+            let owner = format!("{}_{}", name, s.local_name.as_str());
 
             // TODO: clone should not be needed
             if let Some(ref sub_type_name) = s.names.first() {
@@ -353,9 +724,12 @@ impl RustBackend {
             spec.push(toks![s.local_name.as_str(), " {"]);
 
             for field in body.fields.iter().chain(s.fields.iter()) {
-                spec.nested(self.field_element(field)?);
+                spec.nested(self.field_element(&mut functions, &owner, field)?);
             }
 
+            add_helpers(&mut out.1, &body.fields)?;
+            add_helpers(&mut out.1, &s.fields)?;
+
             spec.push("},");
             t.nested(spec);
             Ok(()) as Result<()>
@@ -365,6 +739,15 @@ impl RustBackend {
 
         out.0.push(t);
 
+        for d in functions {
+            out.0.push(d);
+        }
+
+        if let RpTypeInfo::Array = body.type_info {
+            out.0.push(self.array_serialize(name.clone()));
+            out.0.push(self.array_deserialize(name.clone()));
+        }
+
         let impl_body = Code(&body.codes, RUST_CONTEXT).into_tokens();
 
         if !impl_body.is_empty() {
@@ -374,6 +757,186 @@ impl RustBackend {
         Ok(())
     }
 
+    /// Serialize an interface as a `[name, value]` array.
+    ///
+    /// Builds on top of the externally tagged representation derived through `Remote`.
+    fn array_serialize<'el>(&self, name: Rc<String>) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["impl ::serde::Serialize for ", name.clone(), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push("fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>");
+            t.push("where");
+            t.nested("S: ::serde::Serializer,");
+            t.push("{");
+
+            t.nested({
+                let mut body = Tokens::new();
+
+                body.push("use ::serde::ser::{Error, SerializeTuple};");
+
+                body.push(toks![
+                    "let value = ",
+                    name.clone(),
+                    "::serialize(self, ",
+                    self.json_serializer.clone(),
+                    ").map_err(S::Error::custom)?;",
+                ]);
+
+                body.push({
+                    let mut t = Tokens::new();
+                    t.push("let (name, value) = match value {");
+                    t.nested(toks![
+                        self.json_value.clone(),
+                        "::Object(map) => map.into_iter().next()",
+                        ".ok_or_else(|| S::Error::custom(\"missing sub-type\"))?,",
+                    ]);
+                    t.nested("_ => return Err(S::Error::custom(\"expected object\")),");
+                    t.push("};");
+                    t
+                });
+
+                body.push({
+                    let mut t = Tokens::new();
+                    t.push("let mut tuple = serializer.serialize_tuple(2)?;");
+                    t.push("tuple.serialize_element(&name)?;");
+                    t.push("tuple.serialize_element(&value)?;");
+                    t.push("tuple.end()");
+                    t
+                });
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        t.push("}");
+        t
+    }
+
+    /// Deserialize an interface from a `[name, value]` array.
+    fn array_deserialize<'el>(&self, name: Rc<String>) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["impl<'de> ::serde::Deserialize<'de> for ", name.clone(), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push(toks![
+                "fn deserialize<D>(deserializer: D) -> Result<",
+                name.clone(),
+                ", D::Error>",
+            ]);
+            t.push("where");
+            t.nested("D: ::serde::Deserializer<'de>,");
+            t.push("{");
+
+            t.nested({
+                let mut body = Tokens::new();
+
+                body.push("use ::serde::de::Error;");
+
+                body.push(toks![
+                    "let (name, value): (String, ",
+                    self.json_value.clone(),
+                    ") = ::serde::Deserialize::deserialize(deserializer)?;",
+                ]);
+
+                body.push({
+                    let mut t = Tokens::new();
+                    t.push(toks!["let mut map = ", self.json_map.clone(), "::new();"]);
+                    t.push("map.insert(name, value);");
+                    t
+                });
+
+                body.push(toks![
+                    name.clone(),
+                    "::deserialize(",
+                    self.json_value.clone(),
+                    "::Object(map)).map_err(D::Error::custom)",
+                ]);
+
+                body.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        t.push("}");
+        t
+    }
+
+    /// Build a boxed future resolving to `item`, or failing with `error`.
+    pub fn boxed_future<'el>(
+        &self,
+        item: Tokens<'el, Rust<'el>>,
+        error: &'static str,
+    ) -> Tokens<'el, Rust<'el>> {
+        toks!["Box<", self.future.clone(), "<Item = ", item, ", Error = ", error, ">>"]
+    }
+
+    /// Build a boxed stream of `item`, failing with `error`.
+    pub fn boxed_stream<'el>(
+        &self,
+        item: Tokens<'el, Rust<'el>>,
+        error: &'static str,
+    ) -> Tokens<'el, Rust<'el>> {
+        toks!["Box<", self.stream.clone(), "<Item = ", item, ", Error = ", error, ">>"]
+    }
+
+    /// Build the signature of the service method for the given endpoint.
+    ///
+    /// Streaming requests are passed in as boxed streams. Responses are boxed futures, or boxed
+    /// streams if they are streaming.
+    pub fn endpoint_signature<'el>(
+        &self,
+        endpoint: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Rust<'el>>> {
+        let mut args = Tokens::new();
+        args.append("&self");
+
+        if let Some(ref req) = endpoint.request {
+            let ty = self.into_rust_type(req.ty())?;
+
+            let ty = if req.is_streaming() {
+                self.boxed_stream(ty, "Self::Error")
+            } else {
+                ty
+            };
+
+            args.append(toks!["request: ", ty]);
+        }
+
+        let response = match endpoint.response {
+            Some(ref res) => {
+                let ty = self.into_rust_type(res.ty())?;
+
+                if res.is_streaming() {
+                    self.boxed_stream(ty, "Self::Error")
+                } else {
+                    self.boxed_future(ty, "Self::Error")
+                }
+            }
+            None => self.boxed_future(toks!["()"], "Self::Error"),
+        };
+
+        Ok(toks![
+            "fn ",
+            endpoint.id.as_str(),
+            "(",
+            args.join(", "),
+            ") -> ",
+            response,
+        ])
+    }
+
     pub fn process_service<'a>(
         &self,
         out: &mut RustFileSpec<'a>,
@@ -384,22 +947,147 @@ impl RustBackend {
 
         t.push(toks!["pub trait ", name.clone(), " {"]);
 
-        let endpoints = body.endpoints.values().map(Loc::as_ref);
+        t.nested({
+            let mut t = Tokens::new();
+            t.push("type Error;");
 
-        endpoints.for_each_loc(|e| {
-            t.nested({
-                toks!["fn ", e.id.as_str(), "();"]
-            });
+            let mut methods = Tokens::new();
+            let endpoints = body.endpoints.values().map(Loc::as_ref);
 
-            Ok(()) as Result<()>
-        })?;
+            endpoints.for_each_loc(|e| {
+                methods.push(toks![self.endpoint_signature(e)?, ";"]);
+                Ok(()) as Result<()>
+            })?;
+
+            t.push_unless_empty(methods);
+            t.join_line_spacing()
+        });
 
         t.push("}");
 
         out.0.push(t);
 
+        self.listeners.service_added(&mut ServiceAdded {
+            backend: self,
+            body: body,
+            out: &mut out.0,
+        })?;
+
         Ok(())
     }
 }
 
+/// Add the helpers which encode any of the given fields.
+fn add_helpers(helpers: &mut BTreeSet<Helper>, fields: &[Loc<RpField>]) -> Result<()> {
+    for field in fields {
+        helpers.extend(Helper::of_field(field)?);
+    }
+
+    Ok(())
+}
+
 impl PackageUtils for RustBackend {}
+
+impl<'a> ValueBuilder<'a> for RustBackend {
+    type Output = Tokens<'a, Rust<'a>>;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        toks![Rc::new(literal)]
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        toks![string.to_string().quoted(), ".to_string()"]
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        let mut args = Tokens::new();
+
+        for item in items {
+            args.append(item);
+        }
+
+        toks!["vec![", args.join(", "), "]"]
+    }
+
+    fn number_literal(&self, ty: &RpType, number: &RpNumber) -> Self::Output {
+        let literal = match *ty {
+            RpType::Float => format!("{}f32", number),
+            RpType::Double => format!("{}f64", number),
+            _ => number.to_string(),
+        };
+
+        self.literal(literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use repository::Resolvers;
+
+    fn backend() -> RustBackend {
+        let env = Environment::new(None, Box::new(Resolvers::new(vec![])));
+        let listeners: Vec<Box<Listeners>> = Vec::new();
+        RustBackend::new(env, RustOptions::new(), Box::new(listeners), None)
+    }
+
+    #[test]
+    fn test_repr_serialize() {
+        let out = backend()
+            .repr_serialize(Rc::new("Foo".to_string()))
+            .to_file()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "impl ::serde::Serialize for Foo {",
+                "  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>",
+                "  where",
+                "    S: ::serde::Serializer,",
+                "  {",
+                "    ::serde::Serialize::serialize(&self.value(), serializer)",
+                "  }",
+                "}",
+            ],
+            out.lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_repr_deserialize() {
+        let mut from_value_body = Tokens::new();
+        from_value_body.push("-1 => Ok(Foo::A),");
+        from_value_body.push("1 => Ok(Foo::B),");
+
+        let out = backend()
+            .repr_deserialize(
+                Rc::new("Foo".to_string()),
+                Rc::new("i32".to_string()),
+                from_value_body,
+            )
+            .to_file()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "impl<'de> ::serde::Deserialize<'de> for Foo {",
+                "  fn deserialize<D>(deserializer: D) -> Result<Foo, D::Error>",
+                "  where",
+                "    D: ::serde::Deserializer<'de>,",
+                "  {",
+                "    use ::serde::de::Error;",
+                "",
+                "    let value: i32 = ::serde::Deserialize::deserialize(deserializer)?;",
+                "",
+                "    match value {",
+                "      -1 => Ok(Foo::A),",
+                "      1 => Ok(Foo::B),",
+                "      _ => Err(D::Error::custom(format!(\"unknown value: {}\", value))),",
+                "    }",
+                "  }",
+                "}",
+            ],
+            out.lines().collect::<Vec<_>>()
+        );
+    }
+}
@@ -3,18 +3,28 @@
 use backend::IntoBytes;
 use backend::errors::*;
 use genco::{Rust, Tokens};
+use helper::Helper;
 use rust_compiler::RustCompiler;
+use std::collections::BTreeSet;
 
-pub struct RustFileSpec<'a>(pub Tokens<'a, Rust<'a>>);
+pub struct RustFileSpec<'a>(
+    pub Tokens<'a, Rust<'a>>,
+    /// Helper modules used by the file.
+    pub BTreeSet<Helper>,
+);
 
 impl<'processor> Default for RustFileSpec<'processor> {
     fn default() -> Self {
-        RustFileSpec(Tokens::new())
+        RustFileSpec(Tokens::new(), BTreeSet::new())
     }
 }
 
 impl<'processor> IntoBytes<RustCompiler<'processor>> for RustFileSpec<'processor> {
-    fn into_bytes(self, _: &RustCompiler<'processor>) -> Result<Vec<u8>> {
+    fn into_bytes(mut self, _: &RustCompiler<'processor>) -> Result<Vec<u8>> {
+        for helper in &self.1 {
+            self.0.push(helper.module());
+        }
+
         let out = self.0.join_line_spacing().to_file()?;
         Ok(out.into_bytes())
     }
@@ -2,10 +2,20 @@ use genco::{Rust, Tokens};
 
 pub struct RustOptions {
     pub datetime: Option<Tokens<'static, Rust<'static>>>,
+    pub date: Option<Tokens<'static, Rust<'static>>>,
+    pub duration: Option<Tokens<'static, Rust<'static>>>,
+    pub uuid: Option<Tokens<'static, Rust<'static>>>,
+    pub decimal: Option<Tokens<'static, Rust<'static>>>,
 }
 
 impl RustOptions {
     pub fn new() -> RustOptions {
-        RustOptions { datetime: None }
+        RustOptions {
+            datetime: None,
+            date: None,
+            duration: None,
+            uuid: None,
+            decimal: None,
+        }
     }
 }
@@ -1,6 +1,6 @@
 //! # Converter for core data structures into processor-specific ones.
 
-use core::{RpName, RpVariant};
+use core::{RpName, RpVariant, RpVariantValue};
 use errors::*;
 use genco::{Custom, Quoted, Tokens};
 use std::rc::Rc;
@@ -16,6 +16,13 @@ pub trait Converter<'el> {
 
     /// Build an ordinal value.
     fn ordinal<'a>(&self, variant: &'a RpVariant) -> Result<Tokens<'el, Self::Custom>> {
-        Ok(Rc::new(variant.ordinal().to_string()).quoted().into())
+        let mut out = Tokens::new();
+
+        match variant.ordinal() {
+            RpVariantValue::String(string) => out.append(Rc::new(string.to_string()).quoted()),
+            RpVariantValue::Number(number) => out.append(Rc::new(number.to_string())),
+        }
+
+        Ok(out)
     }
 }
@@ -2,22 +2,54 @@
 
 use base_decode::BaseDecode;
 use converter::Converter;
-use core::{Loc, RpInterfaceBody, RpType};
+use core::{Loc, RpConstraints, RpInterfaceBody, RpType, RpTypeInfo};
 use dynamic_converter::DynamicConverter;
 use errors::*;
-use genco::Tokens;
+use genco::{Custom, Tokens};
+use std::rc::Rc;
 
 pub trait DynamicDecode<'el>
 where
     Self: Converter<'el>,
     Self: DynamicConverter<'el>,
 {
-    fn assign_type_var(&self, data: &'el str, type_var: &'el str) -> Tokens<'el, Self::Custom>;
+    /// Assign the name of the sub-type in `data` to `type_var`, according to `type_info`.
+    fn assign_type_var(
+        &self,
+        data: &'el str,
+        type_var: &'el str,
+        type_info: &'el RpTypeInfo,
+    ) -> Tokens<'el, Self::Custom>;
 
-    fn check_type_var(
+    /// Access the object containing the fields of a sub-type, according to `type_info`.
+    fn sub_type_data(
         &self,
         data: &'el str,
         type_var: &'el str,
+        type_info: &'el RpTypeInfo,
+    ) -> Tokens<'el, Self::Custom> {
+        use self::RpTypeInfo::*;
+
+        let mut out = Tokens::new();
+        out.append(data);
+
+        match *type_info {
+            TypeField { .. } => {}
+            Array => out.append("[1]"),
+            ObjectKeys => {
+                out.append("[");
+                out.append(type_var);
+                out.append("]");
+            }
+        }
+
+        out
+    }
+
+    fn check_type_var(
+        &self,
+        data: Tokens<'el, Self::Custom>,
+        type_var: &'el str,
         name: &'el Loc<String>,
         type_name: Tokens<'el, Self::Custom>,
     ) -> Tokens<'el, Self::Custom>;
@@ -49,6 +81,99 @@ where
         value: Tokens<'el, Self::Custom>,
     ) -> Tokens<'el, Self::Custom>;
 
+    /// Build the length of `var`, which is a string, an array, or a map according to `ty`.
+    fn length_of(
+        &self,
+        ty: &'el RpType,
+        var: Tokens<'el, Self::Custom>,
+    ) -> Tokens<'el, Self::Custom>;
+
+    /// Build a condition which holds if the string `var` does not match `pattern`.
+    fn pattern_mismatch(
+        &self,
+        var: Tokens<'el, Self::Custom>,
+        pattern: &'el str,
+    ) -> Tokens<'el, Self::Custom>;
+
+    /// Raise an error with the given message if `condition` holds.
+    fn raise_constraint(
+        &self,
+        condition: Tokens<'el, Self::Custom>,
+        message: String,
+    ) -> Tokens<'el, Self::Custom>;
+
+    /// Build checks that the decoded value `var` of the field `name` satisfies `constraints`.
+    fn constraints_check(
+        &self,
+        name: &'el str,
+        ty: &'el RpType,
+        constraints: &'el RpConstraints,
+        var: Tokens<'el, Self::Custom>,
+    ) -> Vec<Tokens<'el, Self::Custom>> {
+        let mut out = Vec::new();
+
+        if let Some(ref min) = constraints.min {
+            let condition = compare(var.clone(), " < ", min.to_string());
+            let message = format!("{}: must be at least {}", name, min);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(ref max) = constraints.max {
+            let condition = compare(var.clone(), " > ", max.to_string());
+            let message = format!("{}: must be at most {}", name, max);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(min_length) = constraints.min_length {
+            let condition = compare(self.length_of(ty, var.clone()), " < ", min_length.to_string());
+            let message = format!("{}: length must be at least {}", name, min_length);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(max_length) = constraints.max_length {
+            let condition = compare(self.length_of(ty, var.clone()), " > ", max_length.to_string());
+            let message = format!("{}: length must be at most {}", name, max_length);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(ref pattern) = constraints.pattern {
+            let condition = self.pattern_mismatch(var.clone(), pattern.as_str());
+            let message = format!("{}: must match the pattern {}", name, pattern);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(min_items) = constraints.min_items {
+            let condition = compare(self.length_of(ty, var.clone()), " < ", min_items.to_string());
+            let message = format!("{}: must have at least {} item(s)", name, min_items);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if let Some(max_items) = constraints.max_items {
+            let condition = compare(self.length_of(ty, var.clone()), " > ", max_items.to_string());
+            let message = format!("{}: must have at most {} item(s)", name, max_items);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        if constraints.non_empty {
+            let condition = compare(self.length_of(ty, var.clone()), " == ", "0".to_string());
+            let message = format!("{}: must not be empty", name);
+            out.push(self.raise_constraint(condition, message));
+        }
+
+        return out;
+
+        fn compare<'el, C>(left: Tokens<'el, C>, op: &'el str, right: String) -> Tokens<'el, C>
+        where
+            C: Custom,
+        {
+            let mut out = Tokens::new();
+            out.append(left);
+            out.append(op);
+            out.append(Rc::new(right));
+            out
+        }
+    }
+
     /// Handle the decoding of a datetime object.
     fn datetime_decode(&self, input: Tokens<'el, Self::Custom>) -> Tokens<'el, Self::Custom> {
         input
@@ -71,6 +196,7 @@ where
             Float | Double => input,
             String => input,
             DateTime => self.datetime_decode(input),
+            Date | Duration | Uuid | Decimal => input,
             Boolean => input,
             Bytes => input,
             Any => input,
@@ -103,7 +229,8 @@ where
 
         let data = "data";
         let type_var = "f_type";
-        decode_body.push(self.assign_type_var(data, type_var));
+        decode_body.push(self.assign_type_var(data, type_var, &body.type_info));
+        let sub_type_data = self.sub_type_data(data, type_var, &body.type_info);
 
         for sub_type in body.sub_types.values() {
             for sub_type_name in &sub_type.names {
@@ -112,7 +239,7 @@ where
                 })?;
 
                 decode_body.push(self.check_type_var(
-                    data,
+                    sub_type_data.clone(),
                     type_var,
                     sub_type_name,
                     type_name,
@@ -1,14 +1,15 @@
 use super::into_model::IntoModel;
 use super::naming::{FromNaming, Naming, SnakeCase};
 use super::scope::Scope;
-use core::{Loc, Object, Options, PathObject, RpDecl, RpFile, RpName, RpPackage, RpReg,
-           RpRequiredPackage, RpVersionedPackage, VersionReq, WithPos};
+use core::{Loc, Object, Options, PathObject, Pos, RpDecl, RpEndpoint, RpField, RpFile, RpName,
+           RpPackage, RpReg, RpRequiredPackage, RpType, RpVersionedPackage, VersionReq, WithPos};
 use errors::*;
 use linked_hash_map::LinkedHashMap;
 use parser;
 use parser::ast::UseDecl;
 use repository::{Resolved, Resolver};
-use std::collections::{BTreeMap, HashMap, LinkedList, btree_map};
+use std::cmp;
+use std::collections::{BTreeMap, HashMap, HashSet, LinkedList, btree_map};
 use std::path::Path;
 use std::rc::Rc;
 use std::vec;
@@ -57,6 +58,59 @@ impl<'a> Iterator for DeclIter<'a> {
     }
 }
 
+/// Check if the registered declaration can be used as a type.
+fn is_type(reg: &RpReg) -> bool {
+    match *reg {
+        RpReg::Service(_) | RpReg::EnumVariant(_, _) => false,
+        _ => true,
+    }
+}
+
+/// Collect the types that a registered declaration refers to, and the position that they are
+/// referred to from.
+fn type_uses(reg: &RpReg) -> Vec<(&RpType, &Pos)> {
+    use core::RpReg::*;
+
+    let fields: Vec<&Loc<RpField>> = match *reg {
+        Type(ref target) => target.fields.iter().collect(),
+        Tuple(ref target) => target.fields.iter().collect(),
+        Interface(ref target) => target.fields.iter().collect(),
+        // fields of the interface are collected through the interface.
+        SubType(_, ref target) => target.fields.iter().collect(),
+        Service(ref target) => {
+            return target
+                .endpoints
+                .values()
+                .flat_map(|e| e.request.iter().chain(e.response.iter()))
+                .map(|c| (c.ty(), c.pos()))
+                .collect();
+        }
+        _ => vec![],
+    };
+
+    fields.into_iter().map(|f| (&f.ty, f.pos())).collect()
+}
+
+/// Edit distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..b.len() + 1).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + if ca == *cb { 0 } else { 1 };
+            let value = cmp::min(substitute, cmp::min(previous[j + 1], current[j]) + 1);
+            current.push(value);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 /// Scoped environment for evaluating reproto IDLs.
 pub struct Environment {
     /// Global package prefix.
@@ -69,6 +123,8 @@ pub struct Environment {
     types: LinkedHashMap<RpName, RpReg>,
     /// Files and associated declarations.
     files: BTreeMap<RpVersionedPackage, RpFile>,
+    /// Aliases introduced by `use` declarations in each file.
+    uses: HashMap<RpVersionedPackage, Vec<Loc<String>>>,
 }
 
 /// Environment containing all loaded declarations.
@@ -80,6 +136,7 @@ impl Environment {
             visited: HashMap::new(),
             types: LinkedHashMap::new(),
             files: BTreeMap::new(),
+            uses: HashMap::new(),
         }
     }
 
@@ -102,14 +159,23 @@ impl Environment {
         path: P,
         package: Option<RpVersionedPackage>,
     ) -> Result<RpVersionedPackage> {
-        let object = PathObject::new(None, path);
+        self.import_object(PathObject::new(None, path), package)
+    }
 
+    /// Import an object into the environment.
+    ///
+    /// This permits importing content which is not stored in a file, like an unsaved document.
+    pub fn import_object<O: Into<Box<Object>>>(
+        &mut self,
+        object: O,
+        package: Option<RpVersionedPackage>,
+    ) -> Result<RpVersionedPackage> {
         let package = package.unwrap_or_else(|| RpVersionedPackage::new(RpPackage::empty(), None));
         let required = RpRequiredPackage::new(package.package.clone(), VersionReq::any());
 
         if !self.visited.contains_key(&required) {
-            let file = self.load_object(object, &package)?;
-            self.process_file(package.clone(), file)?;
+            let (file, uses) = self.load_file(object, &package)?;
+            self.process_file(package.clone(), file, uses)?;
             self.visited.insert(required, Some(package.clone()));
         }
 
@@ -134,7 +200,7 @@ impl Environment {
             debug!("loading: {}", object);
 
             let package = RpVersionedPackage::new(required.package.clone(), version);
-            let file = self.load_object(object, &package)?;
+            let file = self.load_file(object, &package)?;
 
             candidates.entry(package).or_insert_with(Vec::new).push(
                 file,
@@ -144,8 +210,8 @@ impl Environment {
         let result = if let Some((versioned, files)) = candidates.into_iter().last() {
             debug!("found: {} ({})", versioned, required);
 
-            for file in files.into_iter() {
-                self.process_file(versioned.clone(), file)?;
+            for (file, uses) in files.into_iter() {
+                self.process_file(versioned.clone(), file, uses)?;
             }
 
             Some(versioned)
@@ -158,7 +224,389 @@ impl Environment {
     }
 
     /// Verify all declarations.
+    ///
+    /// Every error that is found is collected, instead of stopping at the first one.
     pub fn verify(&mut self) -> Result<()> {
+        let mut errors = Vec::new();
+
+        for file in self.files.values() {
+            self.verify_references(&mut errors, file);
+        }
+
+        self.verify_cycles(&mut errors);
+
+        for decl in self.decl_iter() {
+            if let RpDecl::Service(ref service) = *decl.value() {
+                for endpoint in service.endpoints.values() {
+                    if let Err(e) = self.verify_endpoint_http(endpoint) {
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ErrorKind::Errors(errors).into());
+        }
+
+        Ok(())
+    }
+
+    /// Verify that the options of every endpoint are understood by a backend.
+    ///
+    /// `known` are the names of the options that the backend understands.
+    pub fn verify_endpoint_options(&self, known: &[&str]) -> Result<()> {
+        let mut errors = Vec::new();
+
+        for decl in self.decl_iter() {
+            if let RpDecl::Service(ref service) = *decl.value() {
+                for endpoint in service.endpoints.values() {
+                    for option in &endpoint.options {
+                        if !known.contains(&option.name.as_str()) {
+                            errors.push(Error::pos(
+                                format!(
+                                    "option `{}` is not supported by this backend",
+                                    option.name
+                                ),
+                                option.pos().into(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ErrorKind::Errors(errors).into());
+        }
+
+        Ok(())
+    }
+
+    /// Aliases introduced by `use` declarations in the given file, which are never used.
+    pub fn unused_uses(&self, package: &RpVersionedPackage, file: &RpFile) -> Vec<&Loc<String>> {
+        let uses = match self.uses.get(package) {
+            Some(uses) => uses,
+            None => return vec![],
+        };
+
+        let mut used = HashSet::new();
+
+        for reg in file.decls.iter().flat_map(|d| d.into_reg()) {
+            for (ty, _) in type_uses(&reg) {
+                prefixes(&mut used, ty);
+            }
+        }
+
+        return uses.iter().filter(|alias| !used.contains(alias.value())).collect();
+
+        /// Collect the prefixes of all names in the given type.
+        fn prefixes(used: &mut HashSet<String>, ty: &RpType) {
+            match *ty {
+                RpType::Name { ref name } => {
+                    if let Some(ref prefix) = name.prefix {
+                        used.insert(prefix.clone());
+                    }
+                }
+                RpType::Array { ref inner } => prefixes(used, inner),
+                RpType::Map { ref key, ref value } => {
+                    prefixes(used, key);
+                    prefixes(used, value);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Verify that every type referenced in a file is declared.
+    fn verify_references(&self, errors: &mut Vec<Error>, file: &RpFile) {
+        for reg in file.decls.iter().flat_map(|d| d.into_reg()) {
+            for (ty, pos) in type_uses(&reg) {
+                self.verify_type(errors, ty, pos);
+            }
+        }
+    }
+
+    /// Verify that the names in a type refer to declared types.
+    fn verify_type(&self, errors: &mut Vec<Error>, ty: &RpType, pos: &Pos) {
+        match *ty {
+            RpType::Name { ref name } => {
+                match self.lookup(name) {
+                    Ok(reg) if !is_type(reg) => {
+                        errors.push(Error::pos(
+                            format!("`{}` is not a type, but a {}", name, reg.kind().0),
+                            pos.into(),
+                        ));
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        let message = match self.suggest_name(name) {
+                            Some(suggestion) => {
+                                format!("unknown type `{}`, did you mean `{}`?", name, suggestion)
+                            }
+                            None => format!("unknown type `{}`", name),
+                        };
+
+                        errors.push(Error::pos(message, pos.into()));
+                    }
+                }
+            }
+            RpType::Array { ref inner } => {
+                self.verify_type(errors, inner, pos);
+            }
+            RpType::Map { ref key, ref value } => {
+                self.verify_type(errors, key, pos);
+                self.verify_type(errors, value, pos);
+            }
+            _ => {}
+        }
+    }
+
+    /// Find the declared type in the package of the given name, which is the closest match to it.
+    fn suggest_name(&self, name: &RpName) -> Option<RpName> {
+        let wanted = name.join("::");
+        let max = cmp::max(1, wanted.len() / 3);
+
+        self.types
+            .iter()
+            .filter(|&(key, reg)| key.package == name.package && is_type(reg))
+            .map(|(key, _)| (distance(&wanted, &key.join("::")), key))
+            .filter(|&(d, _)| d <= max)
+            .min_by_key(|&(d, _)| d)
+            .map(|(_, key)| name.clone().with_parts(key.parts.clone()))
+    }
+
+    /// Declarations that must be instantiated to instantiate the given declaration, and the
+    /// position that they are required from.
+    ///
+    /// An interface requires _any_ of its sub-types, anything else requires _all_ of them.
+    fn requirements<'a>(&self, reg: &'a RpReg) -> Vec<(RpName, &'a Pos)> {
+        if let RpReg::Interface(ref interface) = *reg {
+            return interface
+                .sub_types
+                .values()
+                .map(|s| (s.name.clone().without_prefix(), s.pos()))
+                .collect();
+        }
+
+        let fields = match reg.fields() {
+            Ok(fields) => fields,
+            Err(_) => return vec![],
+        };
+
+        fields
+            .filter(|f| !f.is_optional())
+            .filter_map(|f| match f.ty {
+                RpType::Name { ref name } => Some((name.clone().without_prefix(), f.pos())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Verify that every declaration can be instantiated.
+    ///
+    /// Declarations which require a field of their own type, directly or through other
+    /// declarations, can never be instantiated since the nesting has no end.
+    fn verify_cycles(&self, errors: &mut Vec<Error>) {
+        let mut instantiable = HashSet::new();
+
+        // grow the set of declarations known to be instantiable until it is stable.
+        loop {
+            let mut changed = false;
+
+            for (key, reg) in &self.types {
+                if instantiable.contains(key) {
+                    continue;
+                }
+
+                let ok = {
+                    let requirements = self.requirements(reg);
+
+                    // unknown names are reported when verifying references.
+                    let mut satisfied = requirements.iter().map(|&(ref name, _)| {
+                        instantiable.contains(name) || !self.types.contains_key(name)
+                    });
+
+                    match *reg {
+                        RpReg::Interface(_) => requirements.is_empty() || satisfied.any(|s| s),
+                        _ => satisfied.all(|s| s),
+                    }
+                };
+
+                if ok {
+                    instantiable.insert(key.clone());
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        let mut reported = HashSet::new();
+
+        for (key, reg) in &self.types {
+            if instantiable.contains(key) {
+                continue;
+            }
+
+            // follow requirements which can't be instantiated, until a declaration repeats.
+            let mut path: Vec<(RpName, &Pos)> = Vec::new();
+            let mut current = (key.clone(), reg);
+
+            let cycle = loop {
+                if let Some(index) = path.iter().position(|&(ref name, _)| *name == current.0) {
+                    break path.split_off(index);
+                }
+
+                let next = self.requirements(current.1).into_iter().find(|&(ref name, _)| {
+                    !instantiable.contains(name) && self.types.contains_key(name)
+                });
+
+                let (name, pos) = match next {
+                    Some(next) => next,
+                    None => break vec![],
+                };
+
+                let reg = match self.types.get(&name) {
+                    Some(reg) => reg,
+                    None => break vec![],
+                };
+
+                path.push((current.0, pos));
+                current = (name, reg);
+            };
+
+            let (first, pos) = match cycle.first() {
+                Some(&(ref first, pos)) => (first, pos),
+                None => continue,
+            };
+
+            let mut names: Vec<String> = cycle
+                .iter()
+                .map(|&(ref name, _)| format!("`{}`", name))
+                .collect();
+
+            let mut id = names.clone();
+            id.sort();
+
+            if !reported.insert(id) {
+                continue;
+            }
+
+            names.push(format!("`{}`", first));
+
+            errors.push(Error::pos(
+                format!(
+                    "`{}` can never be instantiated, its required fields form a cycle: {}",
+                    first,
+                    names.join(" -> ")
+                ),
+                pos.into(),
+            ));
+        }
+    }
+
+    /// Verify that the path variables of an endpoint are bound to fields in its request.
+    ///
+    /// Methods without a body must bind every field in the request, since there is no other way to
+    /// transfer them.
+    fn verify_endpoint_http(&self, endpoint: &RpEndpoint) -> Result<()> {
+        use core::RpType::*;
+
+        let http = match endpoint.http {
+            Some(ref http) => http,
+            None => return Ok(()),
+        };
+
+        let request = match endpoint.request {
+            Some(ref request) => request,
+            None => return Ok(()),
+        };
+
+        let path_pos = http.path.pos();
+        let variables = http.path.variables();
+
+        let name = match *request.ty() {
+            Name { ref name } => name,
+            ref ty => {
+                if variables.is_empty() && http.method.has_body() {
+                    return Ok(());
+                }
+
+                return Err(Error::pos(
+                    format!("request `{}` has no fields to bind in path", ty),
+                    request.pos().into(),
+                ));
+            }
+        };
+
+        // unknown names are reported when verifying references.
+        let reg = match self.lookup(name) {
+            Ok(reg) => reg,
+            Err(_) => return Ok(()),
+        };
+
+        let fields: Vec<&Loc<RpField>> = reg.fields().with_pos(request.pos())?.collect();
+
+        for variable in &variables {
+            let field = match fields.iter().find(|f| f.ident() == variable.value().as_str()) {
+                Some(field) => field,
+                None => {
+                    return Err(Error::pos(
+                        format!(
+                            "path variable `{}` is not a field in `{}`",
+                            variable.value(),
+                            name
+                        ),
+                        path_pos.into(),
+                    ));
+                }
+            };
+
+            if field.is_optional() {
+                return Err(Error::pos(
+                    format!(
+                        "path variable `{}` is bound to an optional field",
+                        variable.value()
+                    ),
+                    field.pos().into(),
+                ));
+            }
+
+            match field.ty {
+                Signed { .. } | Unsigned { .. } | Float | Double | Boolean | String | Uuid => {}
+                ref ty => {
+                    return Err(Error::pos(
+                        format!(
+                            "path variable `{}` has type `{}`, expected a primitive type",
+                            variable.value(),
+                            ty
+                        ),
+                        field.pos().into(),
+                    ));
+                }
+            }
+        }
+
+        if !http.method.has_body() {
+            let unbound = fields.iter().find(|f| {
+                !variables.iter().any(|v| v.value().as_str() == f.ident())
+            });
+
+            if let Some(field) = unbound {
+                return Err(Error::pos(
+                    format!(
+                        "field `{}` is not bound in path, {} requests have no body",
+                        field.ident(),
+                        http.method.value()
+                    ),
+                    field.pos().into(),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -187,7 +635,7 @@ impl Environment {
     /// Parse a naming option.
     ///
     /// Since lower_camel is default, do nothing on that case.
-    fn parse_naming(&self, naming: Loc<String>) -> Result<Option<Box<Naming>>> {
+    pub(crate) fn parse_naming(naming: Loc<String>) -> Result<Option<Box<Naming>>> {
         let (naming, pos) = naming.take_pair();
 
         let result = match naming.as_str() {
@@ -207,21 +655,31 @@ impl Environment {
         object: O,
         package: &RpVersionedPackage,
     ) -> Result<RpFile> {
+        Ok(self.load_file(object, package)?.0)
+    }
+
+    /// Load the provided Object into an `RpFile`, together with the aliases of its `use`
+    /// declarations.
+    fn load_file<O: Into<Box<Object>>>(
+        &mut self,
+        object: O,
+        package: &RpVersionedPackage,
+    ) -> Result<(RpFile, Vec<Loc<String>>)> {
         let object = object.into();
         let content = parser::read_reader(object.read()?)?;
         let object = Rc::new(object);
 
         let file = parser::parse_string(object, content.as_str())?;
 
-        let prefixes = self.process_uses(&file.uses)?;
+        let (prefixes, uses) = self.process_uses(&file.uses)?;
 
         let endpoint_naming = match file.options.find_one_identifier("endpoint_naming")? {
-            Some(naming) => self.parse_naming(naming)?,
+            Some(naming) => Self::parse_naming(naming)?,
             _ => None,
         };
 
         let field_naming = match file.options.find_one_identifier("field_naming")? {
-            Some(naming) => self.parse_naming(naming)?,
+            Some(naming) => Self::parse_naming(naming)?,
             _ => None,
         };
 
@@ -233,7 +691,7 @@ impl Environment {
             field_naming,
         );
 
-        Ok(file.into_model(&scope)?)
+        Ok((file.into_model(&scope)?, uses))
     }
 
     /// Apply global package prefix.
@@ -252,14 +710,17 @@ impl Environment {
     }
 
     /// Process use declarations found at the top of each object.
+    ///
+    /// Returns the packages that each alias refers to, and the aliases in declaration order.
     fn process_uses(
         &mut self,
         uses: &[Loc<UseDecl>],
-    ) -> Result<HashMap<String, RpVersionedPackage>> {
+    ) -> Result<(HashMap<String, RpVersionedPackage>, Vec<Loc<String>>)> {
         use std::collections::hash_map::Entry;
         use self::ErrorKind::*;
 
         let mut prefixes = HashMap::new();
+        let mut aliases = Vec::new();
 
         for use_decl in uses {
             let package = use_decl.package.value().clone();
@@ -286,6 +747,8 @@ impl Environment {
                             return Err(format!("alias {} already in use", alias).into())
                         }
                     };
+
+                    aliases.push(Loc::new(alias.to_owned(), use_decl.pos().clone()));
                 }
 
                 continue;
@@ -295,11 +758,16 @@ impl Environment {
             return Err(Pos(error, use_decl.pos().into()).into());
         }
 
-        Ok(prefixes)
+        Ok((prefixes, aliases))
     }
 
     /// Process a single file, populating the environment.
-    fn process_file(&mut self, package: RpVersionedPackage, file: RpFile) -> Result<()> {
+    fn process_file(
+        &mut self,
+        package: RpVersionedPackage,
+        file: RpFile,
+        uses: Vec<Loc<String>>,
+    ) -> Result<()> {
         use linked_hash_map::Entry::*;
         use self::ErrorKind::*;
 
@@ -321,6 +789,266 @@ impl Environment {
             };
         }
 
+        self.uses.insert(package, uses);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Environment, distance};
+    use core::{BytesObject, Object, RpDecl, RpPackage, RpVersionedPackage};
+    use errors::*;
+    use repository::Resolvers;
+    use std::sync::Arc;
+
+    /// Build an environment containing a single file.
+    fn environment(input: &str) -> Result<Environment> {
+        let mut env = Environment::new(None, Box::new(Resolvers::new(vec![])));
+
+        let package = RpVersionedPackage::new(RpPackage::parse("test"), None);
+        let bytes = Arc::new(input.as_bytes().to_vec());
+        let object: Box<Object> = Box::new(BytesObject::new("test".to_string(), bytes));
+
+        let (file, uses) = env.load_file(object, &package)?;
+        env.process_file(package, file, uses)?;
+        Ok(env)
+    }
+
+    /// Verify a single file, and return the message of every error.
+    fn verify(input: &str) -> Vec<String> {
+        let mut env = environment(input).unwrap();
+
+        let e = match env.verify() {
+            Ok(()) => return vec![],
+            Err(e) => e,
+        };
+
+        match *e.kind() {
+            ErrorKind::Errors(ref errors) => errors.iter().map(ToString::to_string).collect(),
+            _ => panic!("expected errors, got: {}", e),
+        }
+    }
+
+    #[test]
+    pub fn test_distance() {
+        assert_eq!(0, distance("Foo", "Foo"));
+        assert_eq!(1, distance("Foo", "Fo"));
+        assert_eq!(3, distance("kitten", "sitting"));
+    }
+
+    #[test]
+    pub fn test_verify_unknown_type() {
+        let errors = verify("type Foo { bar: Bax; baz: [Unknown]; }\ntype Bar {}");
+
+        assert_eq!(
+            vec![
+                "unknown type `Bax`, did you mean `Bar`?".to_string(),
+                "unknown type `Unknown`".to_string(),
+            ],
+            errors
+        );
+    }
+
+    #[test]
+    pub fn test_verify_not_a_type() {
+        let errors = verify("type Foo { bar: Bar; }\nservice Bar {}");
+        assert_eq!(vec!["`Bar` is not a type, but a service".to_string()], errors);
+    }
+
+    #[test]
+    pub fn test_verify_cycle() {
+        let errors = verify("type A { b: B; }\ntype B { a: A; }\ntype C { a: A; }");
+
+        assert_eq!(
+            vec![
+                "`A` can never be instantiated, its required fields form a cycle: `A` -> `B` -> \
+                 `A`"
+                    .to_string(),
+            ],
+            errors
+        );
+    }
+
+    #[test]
+    pub fn test_verify_bounded_cycle() {
+        assert!(verify("type A { b?: B; }\ntype B { a: A; }").is_empty());
+        assert!(verify("type A { a: [A]; }").is_empty());
+        assert!(verify("interface A { B { a: A; } C {} }").is_empty());
+    }
+
+    #[test]
+    pub fn test_endpoint_options() {
+        let env = environment(
+            "service Foo {
+                get_foo() -> string {
+                    option http_status = 201;
+                }
+            }",
+        ).unwrap();
+
+        let decl = env.decl_iter().next().unwrap();
+
+        let endpoint = match *decl.value() {
+            RpDecl::Service(ref service) => service.endpoints.values().next().unwrap().clone(),
+            _ => panic!("expected service"),
+        };
+
+        assert_eq!(1, endpoint.options.len());
+        assert_eq!(Some(201), endpoint.http_status().unwrap());
+        assert!(env.verify_endpoint_options(&["http_status"]).is_ok());
+    }
+
+    /// Load a single file which is expected to fail, and return the message of the error and
+    /// the input that it is reported at.
+    fn pos_error(input: &str) -> (String, &str) {
+        let e = match environment(input) {
+            Ok(_) => panic!("expected error"),
+            Err(e) => e,
+        };
+
+        match *e.kind() {
+            ErrorKind::Pos(ref message, ref pos) => (message.clone(), &input[pos.start..pos.end]),
+            _ => panic!("expected positional error, got: {}", e),
+        }
+    }
+
+    #[test]
+    pub fn test_illegal_http_status() {
+        let input = "service Foo {\n  get_foo() {\n    option http_status = 600;\n  }\n}";
+
+        assert_eq!(
+            ("illegal http status".to_string(), "600"),
+            pos_error(input)
+        );
+    }
+
+    #[test]
+    pub fn test_constraints() {
+        assert!(
+            environment(
+                "type Foo {
+                    a: u32(min = 1, max = 1);
+                    b: string(min_length = 1, max_length = 2, pattern = \"^[a-z]\");
+                    c?: [string](min_items = 0, max_items = 2);
+                    d: {string: u32}(non_empty = true);
+                }",
+            ).is_ok()
+        );
+
+        assert_eq!(
+            ("`min` (2) is larger than `max` (1)".to_string(), "max = 1"),
+            pos_error("type Foo { a: u32(min = 2, max = 1); }")
+        );
+
+        assert_eq!(
+            ("`min_length` (3) is larger than `max_length` (2)".to_string(), "max_length = 2"),
+            pos_error("type Foo { a: string(max_length = 2, min_length = 3); }")
+        );
+
+        assert_eq!(
+            ("`min_items` (3) is larger than `max_items` (2)".to_string(), "max_items = 2"),
+            pos_error("type Foo { a: [string](min_items = 3, max_items = 2); }")
+        );
+
+        assert_eq!(
+            ("constraint `min_length` does not apply to type `u32`".to_string(), "min_length = 1"),
+            pos_error("type Foo { a: u32(min_length = 1); }")
+        );
+
+        assert_eq!(
+            ("expected integer".to_string(), "1.5"),
+            pos_error("type Foo { a: u32(max = 1.5); }")
+        );
+    }
+
+    #[test]
+    pub fn test_numeric_enums() {
+        assert!(
+            environment(
+                "enum Foo as i32 {
+                    A as -1;
+                    B as 0;
+                }
+
+                enum Bar as u32 {
+                    A as 0;
+                    B as 1;
+                }",
+            ).is_ok()
+        );
+
+        assert_eq!(
+            ("value `1` is already used by variant `A`".to_string(), "B as 1;"),
+            pos_error("enum Foo as u32 { A as 1; B as 1; }")
+        );
+
+        assert_eq!(
+            ("expected value of type i32, like `as 0`".to_string(), "B;"),
+            pos_error("enum Foo as i32 { A as 0; B; }")
+        );
+
+        assert_eq!(
+            ("expected value of type u32, like `as 0`".to_string(), "A;"),
+            pos_error("enum Foo as u32 { A; }")
+        );
+    }
+
+    #[test]
+    pub fn test_defaults() {
+        assert!(
+            environment(
+                "type Foo {
+                    a?: u32 = 100;
+                    b?: string = \"name\" as \"sort_by\";
+                    c?: [boolean] = [true, false];
+                    d?: double = 1.5;
+                }",
+            ).is_ok()
+        );
+
+        assert_eq!(
+            ("default values are only permitted on optional fields".to_string(), "100"),
+            pos_error("type Foo { a: u32 = 100; }")
+        );
+
+        assert_eq!(
+            ("default value is not a valid value of type `u32`".to_string(), "\"100\""),
+            pos_error("type Foo { a?: u32 = \"100\"; }")
+        );
+
+        assert_eq!(
+            ("default value is not a valid value of type `u8`".to_string(), "256"),
+            pos_error("type Foo { a?: u8 = 256; }")
+        );
+
+        assert_eq!(
+            ("default value is not a valid value of type `[string]`".to_string(), "[\"a\", 1]"),
+            pos_error("type Foo { a?: [string] = [\"a\", 1]; }")
+        );
+    }
+
+    #[test]
+    pub fn test_unsupported_endpoint_options() {
+        let env = environment(
+            "service Foo {
+                get_foo() {
+                    option http_status = 200;
+                    option retries = 3;
+                }
+            }",
+        ).unwrap();
+
+        let e = env.verify_endpoint_options(&["http_status"]).unwrap_err();
+
+        let errors: Vec<String> = match *e.kind() {
+            ErrorKind::Errors(ref errors) => errors.iter().map(ToString::to_string).collect(),
+            _ => panic!("expected errors, got: {}", e),
+        };
+
+        assert_eq!(
+            vec!["option `retries` is not supported by this backend".to_string()],
+            errors
+        );
+    }
+}
@@ -3,6 +3,7 @@ use super::scope::Scope;
 pub use core::*;
 use linked_hash_map::LinkedHashMap;
 pub use parser::ast::*;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet, hash_map};
 use std::option;
 use std::path::{Path, PathBuf};
@@ -30,6 +31,10 @@ impl IntoModel for Type {
             Boolean => RpType::Boolean,
             String => RpType::String,
             DateTime => RpType::DateTime,
+            Date => RpType::Date,
+            Duration => RpType::Duration,
+            Uuid => RpType::Uuid,
+            Decimal => RpType::Decimal,
             Name { name } => RpType::Name { name: name.into_model(scope)? },
             Array { inner } => RpType::Array { inner: inner.into_model(scope)? },
             Map { key, value } => RpType::Map {
@@ -81,7 +86,7 @@ impl<'input> IntoModel for EnumBody<'input> {
         let variant_type = if let Some(ty) = ty {
             ty.and_then(|ty| {
                 ty.as_enum_type().ok_or_else(
-                    || "expected string or absent".into(),
+                    || "expected string, integer, or absent".into(),
                 ) as Result<RpEnumType>
             })?
         } else {
@@ -105,6 +110,16 @@ impl<'input> IntoModel for EnumBody<'input> {
                 );
             }
 
+            if let Some(other) = variants.iter().find(|v| v.ordinal() == variant.ordinal()) {
+                return Err(
+                    format!(
+                        "value `{}` is already used by variant `{}`",
+                        variant.ordinal(),
+                        other.local_name.value()
+                    ).into(),
+                ).with_pos(&pos);
+            }
+
             variants.push(Rc::new(Loc::new(variant, pos)));
         }
 
@@ -136,7 +151,13 @@ impl<'input, 'a> IntoModel for (EnumVariant<'input>, &'a RpEnumType) {
 
             argument.and_then(|value| value.to_ordinal())?
         } else {
-            RpEnumOrdinal::Generated
+            match *ty {
+                RpEnumType::Signed { .. } |
+                RpEnumType::Unsigned { .. } => {
+                    return Err(format!("expected value of type {}, like `as 0`", ty).into());
+                }
+                _ => RpEnumOrdinal::Generated,
+            }
         };
 
         Ok(RpVariant {
@@ -158,16 +179,172 @@ impl<'input> IntoModel for Field<'input> {
             scope.field_naming().map(|n| n.convert(name))
         });
 
+        let ty = self.ty.into_model(scope)?;
+        let constraints = constraints_into_model(&ty, self.constraints, scope)?;
+
+        let default = match self.default.into_model(scope)? {
+            Some(default) => {
+                if self.modifier != RpModifier::Optional {
+                    return Err(
+                        "default values are only permitted on optional fields".into(),
+                    ).with_pos(default.pos());
+                }
+
+                if !ty.is_assignable_from(&default) {
+                    return Err(
+                        format!("default value is not a valid value of type `{}`", ty).into(),
+                    ).with_pos(default.pos());
+                }
+
+                Some(default)
+            }
+            None => None,
+        };
+
         Ok(RpField {
             modifier: self.modifier,
             name: self.name.to_string(),
             comment: self.comment.into_iter().map(ToOwned::to_owned).collect(),
-            ty: self.ty.into_model(scope)?,
+            ty: ty,
             field_as: field_as,
+            constraints: constraints,
+            default: default,
         })
     }
 }
 
+/// Convert the constraints of a field, checking that each applies to the type of the field.
+fn constraints_into_model<'input>(
+    ty: &RpType,
+    constraints: Vec<Loc<Constraint<'input>>>,
+    scope: &Scope,
+) -> Result<RpConstraints> {
+    let mut out = RpConstraints::default();
+    // position of every constraint, by name.
+    let mut seen = HashMap::new();
+
+    for constraint in constraints {
+        let (constraint, pos) = constraint.take_pair();
+
+        if seen.insert(constraint.name, pos.clone()).is_some() {
+            return Err(
+                format!("constraint `{}` specified more than once", constraint.name).into(),
+            ).with_pos(&pos);
+        }
+
+        let applies = match constraint.name {
+            "min" | "max" => {
+                match *ty {
+                    RpType::Signed { .. } |
+                    RpType::Unsigned { .. } |
+                    RpType::Float |
+                    RpType::Double => true,
+                    _ => false,
+                }
+            }
+            "min_length" | "max_length" | "pattern" => *ty == RpType::String,
+            "min_items" | "max_items" => {
+                match *ty {
+                    RpType::Array { .. } => true,
+                    _ => false,
+                }
+            }
+            "non_empty" => {
+                match *ty {
+                    RpType::Map { .. } => true,
+                    _ => false,
+                }
+            }
+            name => {
+                return Err(format!("unknown constraint `{}`", name).into()).with_pos(&pos);
+            }
+        };
+
+        if !applies {
+            return Err(
+                format!(
+                    "constraint `{}` does not apply to type `{}`",
+                    constraint.name,
+                    ty
+                ).into(),
+            ).with_pos(&pos);
+        }
+
+        let (value, value_pos) = constraint.value.into_model(scope)?.take_pair();
+
+        match constraint.name {
+            "min" => out.min = Some(number(ty, value).with_pos(&value_pos)?),
+            "max" => out.max = Some(number(ty, value).with_pos(&value_pos)?),
+            "min_length" => out.min_length = Some(size(value).with_pos(&value_pos)?),
+            "max_length" => out.max_length = Some(size(value).with_pos(&value_pos)?),
+            "min_items" => out.min_items = Some(size(value).with_pos(&value_pos)?),
+            "max_items" => out.max_items = Some(size(value).with_pos(&value_pos)?),
+            "pattern" => {
+                out.pattern = match value {
+                    RpValue::String(pattern) => Some(pattern),
+                    _ => return Err("expected string".into()).with_pos(&value_pos),
+                };
+            }
+            "non_empty" => {
+                out.non_empty = match value {
+                    RpValue::Boolean(non_empty) => non_empty,
+                    _ => return Err("expected boolean".into()).with_pos(&value_pos),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    if let (Some(min), Some(max)) = (out.min.as_ref(), out.max.as_ref()) {
+        if min.cmp_value(max) == Ordering::Greater {
+            return Err(format!("`min` ({}) is larger than `max` ({})", min, max).into())
+                .with_pos(&seen["max"]);
+        }
+    }
+
+    if let (Some(min), Some(max)) = (out.min_length, out.max_length) {
+        if min > max {
+            return Err(
+                format!("`min_length` ({}) is larger than `max_length` ({})", min, max).into(),
+            ).with_pos(&seen["max_length"]);
+        }
+    }
+
+    if let (Some(min), Some(max)) = (out.min_items, out.max_items) {
+        if min > max {
+            return Err(
+                format!("`min_items` ({}) is larger than `max_items` ({})", min, max).into(),
+            ).with_pos(&seen["max_items"]);
+        }
+    }
+
+    return Ok(out);
+
+    fn number(ty: &RpType, value: RpValue) -> Result<RpNumber> {
+        let number = match value {
+            RpValue::Number(number) => number,
+            _ => return Err("expected number".into()),
+        };
+
+        match *ty {
+            RpType::Signed { .. } |
+            RpType::Unsigned { .. } if number.decimal != 0 => Err("expected integer".into()),
+            _ => Ok(number),
+        }
+    }
+
+    fn size(value: RpValue) -> Result<usize> {
+        match value {
+            RpValue::Number(ref number) if number.decimal == 0 => {
+                number.to_usize().ok_or_else(
+                    || "expected non-negative integer".into(),
+                )
+            }
+            _ => Err("expected non-negative integer".into()),
+        }
+    }
+}
+
 impl<'input> IntoModel for File<'input> {
     type Output = RpFile;
 
@@ -194,7 +371,9 @@ impl<'input> IntoModel for InterfaceBody<'input> {
     fn into_model(self, scope: &Scope) -> Result<Self::Output> {
         use std::collections::btree_map::Entry::*;
 
-        let (fields, codes, _options, decls) = members_into_model(scope, self.members)?;
+        let (fields, codes, options, decls) = members_into_model(scope, self.members)?;
+
+        let type_info = interface_type_info(&options)?;
 
         let mut sub_types: BTreeMap<String, Rc<Loc<RpSubType>>> = BTreeMap::new();
 
@@ -226,10 +405,62 @@ impl<'input> IntoModel for InterfaceBody<'input> {
             fields: fields,
             codes: codes,
             sub_types: sub_types,
+            type_info: type_info,
         })
     }
 }
 
+/// Build the type info for an interface from its options.
+fn interface_type_info(options: &Vec<Loc<RpOptionDecl>>) -> Result<RpTypeInfo> {
+    let type_field_name = options.find_one_string("type_field_name")?;
+
+    let type_info = match options.find_one_identifier("type_info")? {
+        Some(type_info) => {
+            let (type_info, pos) = type_info.take_pair();
+
+            match type_info.as_str() {
+                "type_field" => None,
+                "array" => Some(RpTypeInfo::Array),
+                "object_keys" => Some(RpTypeInfo::ObjectKeys),
+                _ => {
+                    return Err(Error::pos(
+                        "expected one of: type_field, array, object_keys".to_string(),
+                        pos.into(),
+                    ));
+                }
+            }
+        }
+        None => None,
+    };
+
+    if let Some(type_info) = type_info {
+        if let Some(type_field_name) = type_field_name {
+            return Err(Error::pos(
+                "`type_field_name` is only valid with `type_info = type_field`"
+                    .to_string(),
+                type_field_name.pos().into(),
+            ));
+        }
+
+        return Ok(type_info);
+    }
+
+    if let Some(type_field_name) = type_field_name {
+        let (name, pos) = type_field_name.take_pair();
+
+        if name.is_empty() {
+            return Err(Error::pos(
+                "type field name must not be empty".to_string(),
+                pos.into(),
+            ));
+        }
+
+        return Ok(RpTypeInfo::TypeField { name: name });
+    }
+
+    Ok(RpTypeInfo::default())
+}
+
 /// Generic implementation for vectors.
 impl<T> IntoModel for Loc<T>
 where
@@ -426,14 +657,164 @@ impl<'input> IntoModel for Endpoint<'input> {
                 .unwrap_or_else(|| id.to_string())
         });
 
+        let options: Vec<Loc<RpOptionDecl>> = self.options.into_model(scope)?;
+
+        if let Some(http_status) = options.find_one_u32("http_status")? {
+            let (http_status, pos) = http_status.take_pair();
+
+            match http_status.to_u32() {
+                Some(100...599) => {}
+                _ => return Err(Error::pos("illegal http status".to_string(), pos.into())),
+            }
+        }
+
+        let request: Option<Loc<RpChannel>> = self.request.into_model(scope)?;
+        let response: Option<Loc<RpChannel>> = self.response.into_model(scope)?;
+        let http = self.http.into_model(scope)?;
+
+        if let Some(ref http) = http {
+            let streaming = request.iter().chain(response.iter()).any(
+                |c| c.is_streaming(),
+            );
+
+            if streaming {
+                return Err(Error::pos(
+                    "http bindings are not supported for streaming endpoints".to_string(),
+                    http.method.pos().into(),
+                ));
+            }
+
+            if request.is_none() {
+                if let Some(variable) = http.path.variables().into_iter().next() {
+                    return Err(Error::pos(
+                        format!("path variable `{}` requires a request", variable.value()),
+                        http.path.pos().into(),
+                    ));
+                }
+            }
+        }
+
         return Ok(RpEndpoint {
             id: id,
             name: name,
             comment: self.comment.into_iter().map(ToOwned::to_owned).collect(),
-            request: self.request.into_model(scope)?,
-            response: self.response.into_model(scope)?,
+            options: options,
+            request: request,
+            response: response,
+            http: http,
+        });
+    }
+}
+
+impl<'input> IntoModel for EndpointHttp<'input> {
+    type Output = RpEndpointHttp;
+
+    fn into_model(self, _: &Scope) -> Result<Self::Output> {
+        let (method, method_pos) = self.method.take_pair();
+
+        let method = match RpHttpMethod::parse(method) {
+            Some(method) => method,
+            None => {
+                let expected: Vec<&str> = RpHttpMethod::ALL.iter().map(|m| m.as_str()).collect();
+
+                return Err(Error::pos(
+                    format!(
+                        "illegal http method `{}`, expected one of: {}",
+                        method,
+                        expected.join(", ")
+                    ),
+                    method_pos.into(),
+                ));
+            }
+        };
+
+        let (path, path_pos) = self.path.take_pair();
+        let path = path_spec(&path, &path_pos)?;
+
+        Ok(RpEndpointHttp {
+            method: Loc::new(method, method_pos),
+            path: Loc::new(path, path_pos),
+        })
+    }
+}
+
+/// Parse a path template like `/toys/{id}` into its segments.
+///
+/// Every segment is either a literal, or a variable which spans the entire segment.
+fn path_spec(path: &str, pos: &Pos) -> Result<RpPathSpec> {
+    if !path.starts_with('/') {
+        return Err(Error::pos(
+            "path must start with `/`".to_string(),
+            pos.into(),
+        ));
+    }
+
+    let mut segments = Vec::new();
+    let mut variables = HashSet::new();
+
+    // the root path has no segments.
+    if path == "/" {
+        return Ok(RpPathSpec { segments: segments });
+    }
+
+    for segment in path[1..].split('/') {
+        if segment.is_empty() {
+            return Err(Error::pos(
+                "path must not contain empty segments".to_string(),
+                pos.into(),
+            ));
+        }
+
+        if segment.starts_with('{') && segment.ends_with('}') {
+            let name = &segment[1..segment.len() - 1];
+
+            let valid = match name.chars().next() {
+                Some('_') | Some('a'...'z') => {
+                    name.chars().all(|c| match c {
+                        '_' | 'a'...'z' | '0'...'9' => true,
+                        _ => false,
+                    })
+                }
+                _ => false,
+            };
+
+            if !valid {
+                return Err(Error::pos(
+                    format!("illegal path variable `{}`", segment),
+                    pos.into(),
+                ));
+            }
+
+            if !variables.insert(name) {
+                return Err(Error::pos(
+                    format!("path variable `{}` specified more than once", name),
+                    pos.into(),
+                ));
+            }
+
+            segments.push(RpPathSegment::Variable {
+                name: Loc::new(name.to_string(), pos.clone()),
+            });
+
+            continue;
+        }
+
+        if segment.contains('{') || segment.contains('}') {
+            return Err(Error::pos(
+                format!(
+                    "illegal path segment `{}`, variables must span the entire segment",
+                    segment
+                ),
+                pos.into(),
+            ));
+        }
+
+        segments.push(RpPathSegment::Literal {
+            value: Loc::new(segment.to_string(), pos.clone()),
         });
     }
+
+    Ok(RpPathSpec { segments: segments })
 }
 
 impl<'input> IntoModel for Channel {
@@ -28,8 +28,10 @@ mod options;
 mod package_processor;
 mod package_utils;
 mod scope;
+mod value_builder;
 mod code;
 pub mod errors;
+pub mod lint;
 
 pub use self::base_decode::BaseDecode;
 pub use self::base_encode::BaseEncode;
@@ -46,4 +48,5 @@ pub use self::naming::{CamelCase, FromNaming, Naming, SnakeCase};
 pub use self::options::Options;
 pub use self::package_processor::PackageProcessor;
 pub use self::package_utils::PackageUtils;
+pub use self::value_builder::ValueBuilder;
 pub use clap::{App, Arg, ArgMatches};
@@ -0,0 +1,458 @@
+//! Lints over the declarations in an environment.
+//!
+//! Lints are named rules which report things that are permitted, but are likely to be mistakes or
+//! make specifications harder to use. Every rule has a default level, which can be overridden.
+
+use super::environment::Environment;
+use super::naming::Naming;
+use core::{ErrorPos, LintLevel, Loc, Options, Pos, RpDecl, RpEnumOrdinal, RpField, RpFile, RpType};
+use errors::*;
+use std::collections::BTreeMap;
+
+/// A named lint rule.
+#[derive(Debug)]
+pub struct Rule {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Level that the rule is reported at, unless overridden.
+    pub default: LintLevel,
+}
+
+/// All available rules.
+pub const RULES: &[Rule] = &[
+    Rule {
+        name: "missing_docs",
+        description: "declarations without documentation",
+        default: LintLevel::Allow,
+    },
+    Rule {
+        name: "missing_endpoint_docs",
+        description: "endpoints without documentation",
+        default: LintLevel::Allow,
+    },
+    Rule {
+        name: "field_naming",
+        description: "fields not in snake case, or not named according to `field_naming`",
+        default: LintLevel::Warn,
+    },
+    Rule {
+        name: "implicit_ordinals",
+        description: "enum variants without an explicit `as` ordinal",
+        default: LintLevel::Allow,
+    },
+    Rule {
+        name: "any_type",
+        description: "fields and endpoints which use the `any` type",
+        default: LintLevel::Warn,
+    },
+    Rule {
+        name: "reserved_fields",
+        description: "fields which are declared even though they are reserved",
+        default: LintLevel::Warn,
+    },
+    Rule {
+        name: "unused_uses",
+        description: "`use` declarations which are never used",
+        default: LintLevel::Warn,
+    },
+];
+
+/// A lint which has been reported.
+#[derive(Debug)]
+pub struct Lint {
+    /// Name of the rule which reported the lint.
+    pub rule: &'static str,
+    pub level: LintLevel,
+    pub message: String,
+    pub pos: ErrorPos,
+}
+
+/// Check all declarations in the environment.
+///
+/// `levels` override the default levels of rules, and `include` decides if a lint at the given
+/// position is reported, which permits only linting local specifications.
+pub fn lint<F>(
+    env: &Environment,
+    levels: &BTreeMap<String, LintLevel>,
+    include: F,
+) -> Result<Vec<Lint>>
+where
+    F: Fn(&Pos) -> bool,
+{
+    for name in levels.keys() {
+        if !RULES.iter().any(|rule| rule.name == name) {
+            return Err(format!("no such lint: {}", name).into());
+        }
+    }
+
+    let mut linter = Linter {
+        levels: levels,
+        include: &include,
+        lints: Vec::new(),
+    };
+
+    for (package, file) in env.for_each_file() {
+        linter.file(file)?;
+
+        for alias in env.unused_uses(package, file) {
+            let message = format!("unused use `{}`", alias.value());
+            linter.report("unused_uses", message, alias.pos());
+        }
+    }
+
+    Ok(linter.lints)
+}
+
+struct Linter<'a> {
+    levels: &'a BTreeMap<String, LintLevel>,
+    include: &'a Fn(&Pos) -> bool,
+    lints: Vec<Lint>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(&mut self, rule: &'static str, message: String, pos: &Pos) {
+        let level = match self.levels.get(rule) {
+            Some(level) => *level,
+            None => {
+                RULES
+                    .iter()
+                    .find(|r| r.name == rule)
+                    .map(|r| r.default)
+                    .unwrap_or(LintLevel::Allow)
+            }
+        };
+
+        if level == LintLevel::Allow || !(self.include)(pos) {
+            return;
+        }
+
+        self.lints.push(Lint {
+            rule: rule,
+            level: level,
+            message: message,
+            pos: pos.into(),
+        });
+    }
+
+    fn file(&mut self, file: &RpFile) -> Result<()> {
+        // the naming is only enforced if the file asks for one.
+        let naming = match file.options.find_one_identifier("field_naming")? {
+            Some(naming) => Some(Environment::parse_naming(naming)?),
+            None => None,
+        };
+
+        let naming: Option<Option<&Naming>> =
+            naming.as_ref().map(|n| n.as_ref().map(AsRef::as_ref));
+
+        for decl in file.for_each_decl() {
+            self.decl(decl, naming)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check a single declaration, `naming` is the field naming of the file, if one is set.
+    fn decl(&mut self, decl: &Loc<RpDecl>, naming: Option<Option<&Naming>>) -> Result<()> {
+        use core::RpDecl::*;
+
+        if decl.comment().is_empty() {
+            let message = format!("missing documentation for {} `{}`", decl.kind(), decl.name());
+            self.report("missing_docs", message, decl.pos());
+        }
+
+        match **decl {
+            Type(ref body) => {
+                self.fields(&body.fields, naming);
+
+                for field in &body.fields {
+                    if body.reserved.iter().any(|r| {
+                        r.value() == field.ident() || r.value() == field.name()
+                    })
+                    {
+                        let message = format!("field `{}` is reserved", field.ident());
+                        self.report("reserved_fields", message, field.pos());
+                    }
+                }
+            }
+            Tuple(ref body) => {
+                self.fields(&body.fields, naming);
+            }
+            Interface(ref body) => {
+                self.fields(&body.fields, naming);
+
+                for sub_type in body.sub_types.values() {
+                    if sub_type.comment.is_empty() {
+                        let message = format!("missing documentation for `{}`", sub_type.name);
+                        self.report("missing_docs", message, sub_type.pos());
+                    }
+
+                    self.fields(&sub_type.fields, naming);
+                }
+            }
+            Enum(ref body) => {
+                for variant in &body.variants {
+                    if let RpEnumOrdinal::Generated = variant.ordinal {
+                        let message = format!(
+                            "variant `{}` has no explicit ordinal",
+                            variant.local_name.value()
+                        );
+
+                        self.report("implicit_ordinals", message, variant.pos());
+                    }
+                }
+            }
+            Service(ref body) => {
+                for endpoint in body.endpoints.values() {
+                    if endpoint.comment.is_empty() {
+                        let message = format!(
+                            "missing documentation for endpoint `{}`",
+                            endpoint.id.value()
+                        );
+
+                        self.report("missing_endpoint_docs", message, endpoint.pos());
+                    }
+
+                    let channels = endpoint.request.iter().chain(endpoint.response.iter());
+
+                    for channel in channels {
+                        if is_any(channel.ty()) {
+                            let message = format!(
+                                "endpoint `{}` uses the `any` type",
+                                endpoint.id.value()
+                            );
+
+                            self.report("any_type", message, channel.pos());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fields(&mut self, fields: &[Loc<RpField>], naming: Option<Option<&Naming>>) {
+        for field in fields {
+            if is_any(&field.ty) {
+                let message = format!("field `{}` uses the `any` type", field.ident());
+                self.report("any_type", message, field.pos());
+            }
+
+            let naming = match naming {
+                Some(naming) => naming,
+                None => continue,
+            };
+
+            let ident = field.ident();
+
+            // `field_naming` converts identifiers from snake case, where every word is separated
+            // by a single underscore.
+            if ident.split('_').any(str::is_empty) {
+                let words: Vec<&str> = ident.split('_').filter(|w| !w.is_empty()).collect();
+
+                let message = format!(
+                    "field `{}` is not in snake case, `field_naming` expects `{}`",
+                    ident,
+                    words.join("_")
+                );
+
+                self.report("field_naming", message, field.pos());
+            }
+
+            let expected = naming.map(|n| n.convert(ident)).unwrap_or_else(
+                || ident.to_string(),
+            );
+
+            if field.name() != expected {
+                let message = format!(
+                    "field `{}` is named `{}`, but `field_naming` names it `{}`",
+                    ident,
+                    field.name(),
+                    expected
+                );
+
+                self.report("field_naming", message, field.pos());
+            }
+        }
+    }
+}
+
+/// Check if the type is, or contains, `any`.
+fn is_any(ty: &RpType) -> bool {
+    match *ty {
+        RpType::Any => true,
+        RpType::Array { ref inner } => is_any(inner),
+        RpType::Map { ref key, ref value } => is_any(key) || is_any(value),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{BytesObject, Object, RpPackage, RpRequiredPackage, RpVersionedPackage};
+    use repository::{self, Resolved, ResolvedByPrefix, Resolver};
+    use std::sync::Arc;
+
+    /// Resolves every package to a file declaring `Other`.
+    struct OtherResolver;
+
+    impl Resolver for OtherResolver {
+        fn resolve(
+            &mut self,
+            _: &RpRequiredPackage,
+        ) -> repository::errors::Result<Vec<Resolved>> {
+            let bytes = Arc::new(b"type Other {}".to_vec());
+
+            Ok(vec![
+                Resolved {
+                    version: None,
+                    object: Box::new(BytesObject::new("other".to_string(), bytes)),
+                },
+            ])
+        }
+
+        fn resolve_by_prefix(
+            &mut self,
+            _: &RpPackage,
+        ) -> repository::errors::Result<Vec<ResolvedByPrefix>> {
+            Ok(vec![])
+        }
+    }
+
+    /// Lint a single file, and return the rule and message of every lint.
+    fn lint_file(input: &str, levels: &[(&str, LintLevel)]) -> Vec<(&'static str, String)> {
+        let mut env = Environment::new(None, Box::new(OtherResolver));
+
+        let package = RpVersionedPackage::new(RpPackage::parse("test"), None);
+        let bytes = Arc::new(input.as_bytes().to_vec());
+        let object: Box<Object> = Box::new(BytesObject::new("test".to_string(), bytes));
+
+        env.import_object(object, Some(package)).unwrap();
+
+        let levels = levels
+            .iter()
+            .map(|&(name, level)| (name.to_string(), level))
+            .collect();
+
+        lint(&env, &levels, |_| true)
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.rule, l.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let lints = lint_file(
+            "option field_naming = lower_camel;
+
+            type Foo {
+                foo_bar: string;
+                data: any;
+                bad: string as \"other\";
+            }
+
+            enum Baz as string {
+                ONE;
+            }",
+            &[],
+        );
+
+        assert_eq!(
+            vec![
+                ("any_type", "field `data` uses the `any` type".to_string()),
+                (
+                    "field_naming",
+                    "field `bad` is named `other`, but `field_naming` names it `bad`".to_string(),
+                ),
+            ],
+            lints
+        );
+    }
+
+    #[test]
+    fn test_field_naming() {
+        let lints = lint_file(
+            "option field_naming = lower_camel;
+
+            type Foo {
+                foo_bar: string;
+                foo__baz: string;
+                qux_: string;
+            }",
+            &[],
+        );
+
+        assert_eq!(
+            vec![
+                (
+                    "field_naming",
+                    "field `foo__baz` is not in snake case, `field_naming` expects `foo_baz`"
+                        .to_string(),
+                ),
+                (
+                    "field_naming",
+                    "field `qux_` is not in snake case, `field_naming` expects `qux`".to_string(),
+                ),
+            ],
+            lints
+        );
+    }
+
+    #[test]
+    fn test_levels() {
+        let lints = lint_file(
+            "/// Documented.
+            type Foo {
+                data: any;
+
+                option reserved = old;
+                old: string;
+            }
+
+            enum Baz as string {
+                ONE;
+            }",
+            &[
+                ("any_type", LintLevel::Allow),
+                ("missing_docs", LintLevel::Deny),
+                ("implicit_ordinals", LintLevel::Warn),
+            ],
+        );
+
+        assert_eq!(
+            vec![
+                ("reserved_fields", "field `old` is reserved".to_string()),
+                ("missing_docs", "missing documentation for enum `Baz`".to_string()),
+                ("implicit_ordinals", "variant `ONE` has no explicit ordinal".to_string()),
+            ],
+            lints
+        );
+    }
+
+    #[test]
+    fn test_unused_uses() {
+        let lints = lint_file(
+            "use a;
+            use b;
+            use c;
+
+            type Foo {
+                a: a::Other;
+                c: [c::Other];
+            }",
+            &[],
+        );
+
+        assert_eq!(vec![("unused_uses", "unused use `b`".to_string())], lints);
+    }
+
+    #[test]
+    fn test_unknown_lint() {
+        let env = Environment::new(None, Box::new(OtherResolver));
+        let mut levels = BTreeMap::new();
+        levels.insert("not_a_lint".to_string(), LintLevel::Warn);
+        assert!(lint(&env, &levels, |_| true).is_err());
+    }
+}
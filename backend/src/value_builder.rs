@@ -0,0 +1,46 @@
+//! # Helper trait for building literals out of values
+
+use core::{RpNumber, RpType, RpValue};
+
+pub trait ValueBuilder<'el> {
+    type Output;
+
+    /// Build a literal out of its representation in the target language.
+    fn literal(&self, literal: String) -> Self::Output;
+
+    /// Build a string literal.
+    fn string_literal(&self, string: &str) -> Self::Output;
+
+    /// Build an array literal out of the literals of its items.
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output;
+
+    /// Build a number literal, of the given type.
+    fn number_literal(&self, _: &RpType, number: &RpNumber) -> Self::Output {
+        self.literal(number.to_string())
+    }
+
+    /// Build a boolean literal.
+    fn boolean_literal(&self, boolean: bool) -> Self::Output {
+        self.literal(boolean.to_string())
+    }
+
+    /// Build a literal out of the given value, of the given type.
+    fn value(&self, ty: &RpType, value: &RpValue) -> Self::Output {
+        match *value {
+            RpValue::String(ref string) => self.string_literal(string),
+            RpValue::Number(ref number) => self.number_literal(ty, number),
+            RpValue::Boolean(boolean) => self.boolean_literal(boolean),
+            RpValue::Identifier(ref identifier) => self.literal(identifier.clone()),
+            RpValue::Array(ref values) => {
+                // items are values of the inner type of the array.
+                let inner = match *ty {
+                    RpType::Array { ref inner } => inner.as_ref(),
+                    _ => ty,
+                };
+
+                let items = values.iter().map(|v| self.value(inner, v.value())).collect();
+                self.array_literal(items)
+            }
+        }
+    }
+}
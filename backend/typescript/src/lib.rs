@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate genco;
+extern crate reproto_backend as backend;
+extern crate reproto_core as core;
+extern crate reproto_manifest as manifest;
+extern crate toml;
+
+mod listeners;
+mod typescript_backend;
+mod typescript_compiler;
+mod typescript_field;
+mod typescript_file_spec;
+mod typescript_options;
+
+use self::backend::{ArgMatches, CompilerOptions, Environment, Options};
+use self::backend::errors::*;
+use self::listeners::Listeners;
+use self::typescript_backend::TypeScriptBackend;
+use self::typescript_options::TypeScriptOptions;
+use manifest::{Lang, Manifest, NoModule, TryFromToml, self as m};
+use std::path::Path;
+
+const TYPE_SEP: &str = "_";
+const EXT: &str = "ts";
+const TYPESCRIPT_CONTEXT: &str = "typescript";
+
+#[derive(Default)]
+pub struct TypeScriptLang;
+
+impl Lang for TypeScriptLang {
+    type Module = TypeScriptModule;
+}
+
+#[derive(Debug)]
+pub enum TypeScriptModule {
+}
+
+impl TryFromToml for TypeScriptModule {
+    fn try_from_string(path: &Path, id: &str, value: String) -> m::errors::Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+
+    fn try_from_value(path: &Path, id: &str, value: toml::Value) -> m::errors::Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+}
+
+fn setup_listeners(
+    modules: &[TypeScriptModule],
+) -> Result<(TypeScriptOptions, Box<Listeners>)> {
+    let listeners: Vec<Box<Listeners>> = Vec::new();
+
+    for module in modules {
+        match *module {
+        }
+    }
+
+    let mut options = TypeScriptOptions::new();
+
+    for listener in &listeners {
+        listener.configure(&mut options)?;
+    }
+
+    Ok((options, Box::new(listeners)))
+}
+
+pub fn compile(
+    env: Environment,
+    opts: Options,
+    compiler_options: CompilerOptions,
+    _matches: &ArgMatches,
+    manifest: Manifest<TypeScriptLang>,
+) -> Result<()> {
+    let id_converter = opts.id_converter;
+    let (options, listeners) = setup_listeners(&manifest.modules)?;
+    let backend = TypeScriptBackend::new(env, options, listeners, id_converter);
+    let compiler = backend.compiler(compiler_options)?;
+    compiler.compile()
+}
@@ -0,0 +1,19 @@
+use backend::errors::*;
+use typescript_options::TypeScriptOptions;
+
+pub trait Listeners {
+    fn configure(&self, _: &mut TypeScriptOptions) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A vector of listeners is a valid listener.
+impl Listeners for Vec<Box<Listeners>> {
+    fn configure(&self, options: &mut TypeScriptOptions) -> Result<()> {
+        for listeners in self {
+            listeners.configure(options)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,821 @@
+//! Backend for TypeScript
+
+use super::{TYPESCRIPT_CONTEXT, TYPE_SEP};
+use backend::{Code, CompilerOptions, Converter, DynamicConverter, DynamicDecode, DynamicEncode,
+              Environment, Naming, PackageUtils, ValueBuilder};
+use backend::errors::*;
+use core::{ForEachLoc, Loc, RpEnumBody, RpEnumType, RpField, RpInterfaceBody, RpModifier,
+           RpName, RpTupleBody, RpType, RpTypeBody, RpTypeInfo};
+use genco::{Element, JavaScript, Quoted, Tokens};
+use genco::js::imported_alias;
+use listeners::Listeners;
+use std::borrow::Cow;
+use std::rc::Rc;
+use typescript_compiler::TypeScriptCompiler;
+use typescript_field::TypeScriptField;
+use typescript_file_spec::TypeScriptFileSpec;
+use typescript_options::TypeScriptOptions;
+
+pub struct TypeScriptBackend {
+    pub env: Environment,
+    listeners: Box<Listeners>,
+    id_converter: Option<Box<Naming>>,
+}
+
+impl TypeScriptBackend {
+    pub fn new(
+        env: Environment,
+        _: TypeScriptOptions,
+        listeners: Box<Listeners>,
+        id_converter: Option<Box<Naming>>,
+    ) -> TypeScriptBackend {
+        TypeScriptBackend {
+            env: env,
+            listeners: listeners,
+            id_converter: id_converter,
+        }
+    }
+
+    pub fn compiler(&self, options: CompilerOptions) -> Result<TypeScriptCompiler> {
+        Ok(TypeScriptCompiler {
+            out_path: options.out_path,
+            backend: self,
+        })
+    }
+
+    fn is_defined<'el>(&self, toks: Tokens<'el, JavaScript<'el>>) -> Tokens<'el, JavaScript<'el>> {
+        toks![toks.clone(), " !== null && ", toks, " !== undefined"]
+    }
+
+    fn is_not_defined<'el>(
+        &self,
+        toks: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        toks![toks.clone(), " === null || ", toks, " === undefined"]
+    }
+
+    fn if_block<'el>(
+        &self,
+        cond: Tokens<'el, JavaScript<'el>>,
+        body: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+        t.push(toks!["if (", cond, ") {"]);
+        t.nested(body);
+        t.push("}");
+        t
+    }
+
+    fn throw<'el>(&self, message: Tokens<'el, JavaScript<'el>>) -> Tokens<'el, JavaScript<'el>> {
+        toks!["throw new Error(", message, ");"]
+    }
+
+    /// Convert the given type into a type annotation.
+    fn into_type<'el>(&self, ty: &'el RpType) -> Result<Tokens<'el, JavaScript<'el>>> {
+        use self::RpType::*;
+
+        let ty = match *ty {
+            String | DateTime | Date | Duration | Uuid | Decimal | Bytes => toks!["string"],
+            Signed { .. } | Unsigned { .. } | Float | Double => toks!["number"],
+            Boolean => toks!["boolean"],
+            Any => toks!["any"],
+            Name { ref name } => self.convert_type(name)?,
+            Array { ref inner } => toks![self.into_type(inner)?, "[]"],
+            Map { ref value, .. } => toks!["{[key: string]: ", self.into_type(value)?, "}"],
+        };
+
+        Ok(ty)
+    }
+
+    /// Type annotation of a field, optional fields are nullable.
+    fn field_type<'el>(
+        &self,
+        field: &TypeScriptField<'el>,
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let ty = self.into_type(field.ty)?;
+
+        if let RpModifier::Optional = *field.modifier {
+            return Ok(toks![ty, " | null"]);
+        }
+
+        Ok(ty)
+    }
+
+    fn encode_method<'el>(
+        &self,
+        fields: &[Loc<TypeScriptField<'el>>],
+        extra: Option<Tokens<'el, JavaScript<'el>>>,
+        result: Tokens<'el, JavaScript<'el>>,
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let mut body = Tokens::new();
+
+        body.push("const data: any = {};");
+
+        if let Some(extra) = extra {
+            body.push(extra);
+        }
+
+        fields.for_each_loc(|field| {
+            let var_string = field.name.quoted();
+            let field_toks = toks!["this.", field.ident.clone()];
+            let value_toks = self.dynamic_encode(field.ty, field_toks.clone())?;
+            let assign = toks!["data[", var_string, "] = ", value_toks, ";"];
+
+            match *field.modifier {
+                RpModifier::Optional => {
+                    body.push(self.if_block(self.is_defined(field_toks), assign));
+                }
+                _ => {
+                    let message = format!("{}: is a required field", field.name).quoted();
+
+                    body.push(self.if_block(
+                        self.is_not_defined(field_toks),
+                        self.throw(toks![message]),
+                    ));
+
+                    body.push(assign);
+                }
+            }
+
+            Ok(()) as Result<()>
+        })?;
+
+        body.push(toks!["return ", result, ";"]);
+
+        let mut encode = Tokens::new();
+        encode.push("encode(): any {");
+        encode.nested(body.join_line_spacing());
+        encode.push("}");
+        Ok(encode)
+    }
+
+    fn encode_tuple_method<'el>(
+        &self,
+        fields: &[Loc<TypeScriptField<'el>>],
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let mut values = Tokens::new();
+        let mut body = Tokens::new();
+
+        fields.for_each_loc(|field| {
+            let toks = toks!["this.", field.ident.clone()];
+            let message = format!("{}: is a required field", field.name).quoted();
+
+            body.push(self.if_block(
+                self.is_not_defined(toks.clone()),
+                self.throw(toks![message]),
+            ));
+
+            values.append(self.dynamic_encode(field.ty, toks)?);
+            Ok(()) as Result<()>
+        })?;
+
+        body.push(toks!["return [", values.join(", "), "];"]);
+
+        let mut encode = Tokens::new();
+        encode.push("encode(): any {");
+        encode.nested(body.join_line_spacing());
+        encode.push("}");
+        Ok(encode)
+    }
+
+    fn decode_method<'el, F>(
+        &self,
+        fields: &[Loc<TypeScriptField<'el>>],
+        type_name: Rc<String>,
+        variable_fn: F,
+    ) -> Result<Tokens<'el, JavaScript<'el>>>
+    where
+        F: Fn(usize, &TypeScriptField<'el>) -> Element<'el, JavaScript<'el>>,
+    {
+        let mut arguments = Tokens::new();
+        let mut body = Tokens::new();
+
+        for (i, field) in fields.iter().enumerate() {
+            let var_name = Rc::new(format!("v_{}", field.ident));
+            let data = toks!["data[", variable_fn(i, field), "]"];
+            let ty = self.field_type(field)?;
+            let decode = self.dynamic_decode(field.ty, data.clone())?;
+
+            let mut checks = Tokens::new();
+
+            for check in self.constraints_check(
+                field.name,
+                field.ty,
+                field.constraints,
+                toks![var_name.clone()],
+            )
+            {
+                checks.push(check);
+            }
+
+            match *field.modifier {
+                RpModifier::Optional => {
+                    let absent = match field.default {
+                        Some(default) => self.value(field.ty, default.value()),
+                        None => toks!["null"],
+                    };
+
+                    let mut t = Tokens::new();
+                    t.push(toks!["let ", var_name.clone(), ": ", ty, " = ", absent, ";"]);
+                    t.push(self.if_block(
+                        self.is_defined(data),
+                        toks![var_name.clone(), " = ", decode, ";"],
+                    ));
+
+                    if !checks.is_empty() {
+                        t.push(self.if_block(toks![var_name.clone(), " !== null"], checks));
+                    }
+
+                    body.push(t);
+                }
+                _ => {
+                    let message = format!("{}: required field", field.name).quoted();
+
+                    let mut t = Tokens::new();
+                    t.push(self.if_block(self.is_not_defined(data), self.throw(toks![message])));
+                    t.push(toks!["const ", var_name.clone(), ": ", ty, " = ", decode, ";"]);
+
+                    if !checks.is_empty() {
+                        t.push(checks);
+                    }
+
+                    body.push(t);
+                }
+            }
+
+            arguments.append(var_name);
+        }
+
+        body.push(toks![
+            "return new ",
+            type_name.clone(),
+            "(",
+            arguments.join(", "),
+            ");",
+        ]);
+
+        let mut decode = Tokens::new();
+        decode.push(toks!["static decode(data: any): ", type_name, " {"]);
+        decode.nested(body.join_line_spacing());
+        decode.push("}");
+        Ok(decode)
+    }
+
+    fn field_by_name<'el>(
+        _i: usize,
+        field: &TypeScriptField<'el>,
+    ) -> Element<'el, JavaScript<'el>> {
+        field.name.quoted()
+    }
+
+    fn field_by_index<'el>(
+        i: usize,
+        _field: &TypeScriptField<'el>,
+    ) -> Element<'el, JavaScript<'el>> {
+        i.to_string().into()
+    }
+
+    pub fn ident(&self, name: &str) -> String {
+        if let Some(ref id_converter) = self.id_converter {
+            id_converter.convert(name)
+        } else {
+            name.to_owned()
+        }
+    }
+
+    fn into_field<'el>(&self, field: &'el RpField) -> TypeScriptField<'el> {
+        TypeScriptField {
+            modifier: &field.modifier,
+            ty: &field.ty,
+            constraints: &field.constraints,
+            default: field.default.as_ref(),
+            name: field.name(),
+            ident: Rc::new(self.ident(field.ident())),
+        }
+    }
+
+    /// Build field declarations and a constructor assigning all of them.
+    fn build_fields<'el>(
+        &self,
+        fields: &[Loc<TypeScriptField<'el>>],
+    ) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let mut declarations = Tokens::new();
+        let mut arguments = Tokens::new();
+        let mut assignments = Tokens::new();
+
+        for field in fields {
+            let ty = self.field_type(field)?;
+
+            declarations.push(toks![field.ident.clone(), ": ", ty.clone(), ";"]);
+            arguments.append(toks![field.ident.clone(), ": ", ty]);
+            assignments.push(toks![
+                "this.",
+                field.ident.clone(),
+                " = ",
+                field.ident.clone(),
+                ";",
+            ]);
+        }
+
+        let mut ctor = Tokens::new();
+        ctor.push(toks!["constructor(", arguments.join(", "), ") {"]);
+        ctor.nested(assignments);
+        ctor.push("}");
+
+        let mut t = Tokens::new();
+        t.push_unless_empty(declarations);
+        t.push(ctor);
+        Ok(t.join_line_spacing())
+    }
+
+    fn class<'el>(
+        &self,
+        name: Rc<String>,
+        body: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut class = Tokens::new();
+        class.push(toks!["export class ", name, " {"]);
+        class.nested(body.join_line_spacing());
+        class.push("}");
+        class
+    }
+
+    pub fn process_tuple<'el>(
+        &self,
+        out: &mut TypeScriptFileSpec<'el>,
+        body: &'el RpTupleBody,
+    ) -> Result<()> {
+        let type_name = Rc::new(body.name.join(TYPE_SEP));
+
+        let fields: Vec<Loc<TypeScriptField>> = body.fields
+            .iter()
+            .map(|f| f.as_ref().map(|f| self.into_field(f)))
+            .collect();
+
+        let mut class_body = Tokens::new();
+
+        class_body.push(self.build_fields(&fields)?);
+        class_body.push(self.decode_method(
+            &fields,
+            type_name.clone(),
+            Self::field_by_index,
+        )?);
+        class_body.push(self.encode_tuple_method(&fields)?);
+        class_body.push_unless_empty(Code(&body.codes, TYPESCRIPT_CONTEXT));
+
+        out.0.push(self.class(type_name, class_body));
+        Ok(())
+    }
+
+    pub fn process_enum<'el>(
+        &self,
+        out: &mut TypeScriptFileSpec<'el>,
+        body: &'el RpEnumBody,
+    ) -> Result<()> {
+        let type_name = Rc::new(body.name.join(TYPE_SEP));
+
+        let value_type = match body.variant_type {
+            RpEnumType::String | RpEnumType::Generated => "string",
+            RpEnumType::Signed { .. } | RpEnumType::Unsigned { .. } => "number",
+        };
+
+        let mut members = Tokens::new();
+        let mut values = Tokens::new();
+
+        body.variants.iter().for_each_loc(|variant| {
+            let name = variant.local_name.as_str();
+
+            members.push(toks![
+                "static ",
+                name,
+                ": ",
+                type_name.clone(),
+                " = new ",
+                type_name.clone(),
+                "(",
+                name.quoted(),
+                ", ",
+                self.ordinal(variant)?,
+                ");",
+            ]);
+
+            values.append(toks![type_name.clone(), ".", name]);
+            Ok(()) as Result<()>
+        })?;
+
+        members.push(toks![
+            "static values: ",
+            type_name.clone(),
+            "[] = [",
+            values.join(", "),
+            "];",
+        ]);
+
+        let mut class_body = Tokens::new();
+
+        class_body.push(members);
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push("name: string;");
+            t.push(toks!["value: ", value_type, ";"]);
+            t
+        });
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push(toks!["constructor(name: string, value: ", value_type, ") {"]);
+            t.nested("this.name = name;");
+            t.nested("this.value = value;");
+            t.push("}");
+            t
+        });
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push(toks!["encode(): ", value_type, " {"]);
+            t.nested("return this.value;");
+            t.push("}");
+            t
+        });
+
+        class_body.push({
+            let mut t = Tokens::new();
+            t.push(toks!["static decode(data: any): ", type_name.clone(), " {"]);
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push({
+                    let mut t = Tokens::new();
+                    t.push(toks![
+                        "for (let i = 0, l = ",
+                        type_name.clone(),
+                        ".values.length; i < l; i++) {",
+                    ]);
+                    t.nested(toks!["const member = ", type_name.clone(), ".values[i];"]);
+                    t.nested(self.if_block(
+                        toks!["member.value === data"],
+                        toks!["return member;"],
+                    ));
+                    t.push("}");
+                    t
+                });
+
+                t.push(self.throw(toks!["no matching value: ".quoted(), " + data"]));
+                t.join_line_spacing()
+            });
+
+            t.push("}");
+            t
+        });
+
+        class_body.push_unless_empty(Code(&body.codes, TYPESCRIPT_CONTEXT));
+
+        out.0.push(self.class(type_name, class_body));
+        Ok(())
+    }
+
+    pub fn process_type<'el>(
+        &self,
+        out: &mut TypeScriptFileSpec<'el>,
+        body: &'el RpTypeBody,
+    ) -> Result<()> {
+        let type_name = Rc::new(body.name.join(TYPE_SEP));
+
+        let fields: Vec<Loc<TypeScriptField>> = body.fields
+            .iter()
+            .map(|f| f.as_ref().map(|f| self.into_field(f)))
+            .collect();
+
+        let mut class_body = Tokens::new();
+
+        class_body.push(self.build_fields(&fields)?);
+        class_body.push(self.decode_method(
+            &fields,
+            type_name.clone(),
+            Self::field_by_name,
+        )?);
+        class_body.push(self.encode_method(&fields, None, toks!["data"])?);
+        class_body.push_unless_empty(Code(&body.codes, TYPESCRIPT_CONTEXT));
+
+        out.0.push(self.class(type_name, class_body));
+        Ok(())
+    }
+
+    /// Interfaces are represented as a union of their sub-types, which are discriminated by their
+    /// `TYPE` member.
+    pub fn process_interface<'el>(
+        &self,
+        out: &mut TypeScriptFileSpec<'el>,
+        body: &'el RpInterfaceBody,
+    ) -> Result<()> {
+        let interface_name = Rc::new(body.name.join(TYPE_SEP));
+
+        let mut elements = Tokens::new();
+        let mut sub_type_names = Tokens::new();
+
+        let interface_fields: Vec<Loc<TypeScriptField>> = body.fields
+            .iter()
+            .map(|f| f.as_ref().map(|f| self.into_field(f)))
+            .collect();
+
+        let mut classes = Tokens::new();
+
+        let sub_types = body.sub_types.values().map(|l| l.loc_ref());
+
+        sub_types.for_each_loc(|sub_type| {
+            let type_name = Rc::new(sub_type.name.join(TYPE_SEP));
+            sub_type_names.append(type_name.clone());
+
+            let fields: Vec<Loc<TypeScriptField>> = interface_fields
+                .iter()
+                .cloned()
+                .chain(sub_type.fields.iter().map(
+                    |f| f.as_ref().map(|f| self.into_field(f)),
+                ))
+                .collect();
+
+            let name = sub_type.name().quoted();
+
+            let mut class_body = Tokens::new();
+
+            class_body.push({
+                let mut t = Tokens::new();
+                t.push(toks!["static readonly TYPE: ", name.clone(), " = ", name.clone(), ";"]);
+                t.push(toks!["readonly TYPE: ", name.clone(), " = ", name.clone(), ";"]);
+                t
+            });
+
+            class_body.push(self.build_fields(&fields)?);
+            class_body.push(self.decode_method(
+                &fields,
+                type_name.clone(),
+                Self::field_by_name,
+            )?);
+
+            let type_toks = toks![type_name.clone(), ".TYPE"];
+
+            let (extra, result) = match body.type_info {
+                RpTypeInfo::TypeField { ref name } => {
+                    let extra = toks!["data[", name.as_str().quoted(), "] = ", type_toks, ";"];
+                    (Some(extra), toks!["data"])
+                }
+                RpTypeInfo::Array => (None, toks!["[", type_toks, ", data]"]),
+                RpTypeInfo::ObjectKeys => (None, toks!["{[", type_toks, "]: data}"]),
+            };
+
+            class_body.push(self.encode_method(&fields, extra, result)?);
+            class_body.push_unless_empty(Code(&sub_type.codes, TYPESCRIPT_CONTEXT));
+
+            classes.push(self.class(type_name, class_body));
+            Ok(()) as Result<()>
+        })?;
+
+        let union = if sub_type_names.is_empty() {
+            toks!["never"]
+        } else {
+            sub_type_names.join(" | ")
+        };
+
+        elements.push(toks!["export type ", interface_name.clone(), " = ", union, ";"]);
+
+        elements.push({
+            let mut namespace_body = Tokens::new();
+            namespace_body.push(self.interface_decode_method(body)?);
+            namespace_body.push_unless_empty(Code(&body.codes, TYPESCRIPT_CONTEXT));
+
+            let mut t = Tokens::new();
+            t.push(toks!["export namespace ", interface_name.clone(), " {"]);
+            t.nested(namespace_body.join_line_spacing());
+            t.push("}");
+            t
+        });
+
+        elements.push_unless_empty(classes.join_line_spacing());
+
+        out.0.push(elements.join_line_spacing());
+        Ok(())
+    }
+}
+
+impl PackageUtils for TypeScriptBackend {}
+
+impl<'el> Converter<'el> for TypeScriptBackend {
+    type Custom = JavaScript<'el>;
+
+    fn convert_type(&self, name: &'el RpName) -> Result<Tokens<'el, JavaScript<'el>>> {
+        let registered = self.env.lookup(name)?;
+
+        let local_name = registered.local_name(name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
+
+        if let Some(ref used) = name.prefix {
+            let package = self.package(&name.package).parts.join(".");
+            return Ok(
+                imported_alias(
+                    Cow::Owned(package),
+                    Cow::Owned(local_name),
+                    Cow::Borrowed(used),
+                ).into(),
+            );
+        }
+
+        Ok(local_name.into())
+    }
+}
+
+impl<'el> DynamicConverter<'el> for TypeScriptBackend {
+    fn is_native(&self, ty: &RpType) -> bool {
+        use self::RpType::*;
+
+        match *ty {
+            Signed { .. } | Unsigned { .. } => true,
+            Float | Double => true,
+            String => true,
+            Any => true,
+            Boolean => true,
+            Array { ref inner } => self.is_native(inner),
+            Map { ref key, ref value } => self.is_native(key) && self.is_native(value),
+            _ => false,
+        }
+    }
+
+    fn map_key_var(&self) -> Tokens<'el, JavaScript<'el>> {
+        toks!["k"]
+    }
+
+    fn map_value_var(&self) -> Tokens<'el, JavaScript<'el>> {
+        toks!["data[k]"]
+    }
+
+    fn array_inner_var(&self) -> Tokens<'el, JavaScript<'el>> {
+        toks!["v"]
+    }
+}
+
+impl<'el> DynamicDecode<'el> for TypeScriptBackend {
+    fn name_decode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        name: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        toks![name, ".decode(", input, ")"]
+    }
+
+    fn array_decode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        inner: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        toks![input, ".map((v: any) => ", inner, ")"]
+    }
+
+    fn map_decode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        _key: Tokens<'el, JavaScript<'el>>,
+        value: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+
+        t.append("((data: any) => {");
+        t.append(" const o: any = {};");
+        t.append(" for (let k in data) {");
+        t.append(toks![" o[k] = ", value, ";"]);
+        t.append(" }");
+        t.append(" return o;");
+        t.append(toks![" })(", input, ")"]);
+
+        t
+    }
+
+    fn assign_type_var(
+        &self,
+        data: &'el str,
+        type_var: &'el str,
+        type_info: &'el RpTypeInfo,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        use self::RpTypeInfo::*;
+
+        let value = match *type_info {
+            TypeField { ref name } => toks![data, "[", name.as_str().quoted(), "]"],
+            Array => toks![data, "[0]"],
+            ObjectKeys => toks!["Object.keys(", data, ")[0]"],
+        };
+
+        toks!["const ", type_var, " = ", value, ";"]
+    }
+
+    fn check_type_var(
+        &self,
+        data: Tokens<'el, JavaScript<'el>>,
+        type_var: &'el str,
+        name: &'el Loc<String>,
+        type_name: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let cond = toks![type_var, " === ", name.as_str().quoted()];
+        self.if_block(cond, toks!["return ", type_name, ".decode(", data, ");"])
+    }
+
+    fn raise_bad_type(&self, type_var: &'el str) -> Tokens<'el, JavaScript<'el>> {
+        self.throw(toks!["bad type: ".quoted(), " + ", type_var])
+    }
+
+    fn length_of(
+        &self,
+        ty: &'el RpType,
+        var: Tokens<'el, Self::Custom>,
+    ) -> Tokens<'el, Self::Custom> {
+        match *ty {
+            RpType::Map { .. } => toks!["Object.keys(", var, ").length"],
+            _ => toks![var, ".length"],
+        }
+    }
+
+    fn pattern_mismatch(
+        &self,
+        var: Tokens<'el, Self::Custom>,
+        pattern: &'el str,
+    ) -> Tokens<'el, Self::Custom> {
+        toks!["!new RegExp(", pattern.quoted(), ").test(", var, ")"]
+    }
+
+    fn raise_constraint(
+        &self,
+        condition: Tokens<'el, Self::Custom>,
+        message: String,
+    ) -> Tokens<'el, Self::Custom> {
+        self.if_block(condition, self.throw(toks![message.quoted()]))
+    }
+
+    fn new_decode_method(
+        &self,
+        data: &'el str,
+        body: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut decode = Tokens::new();
+        decode.push(toks!["export function decode(", data, ": any) {"]);
+        decode.nested(body);
+        decode.push("}");
+        decode
+    }
+}
+
+impl<'el> DynamicEncode<'el> for TypeScriptBackend {
+    fn name_encode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        _: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        toks![input, ".encode()"]
+    }
+
+    fn array_encode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        inner: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        toks![input, ".map((v: any) => ", inner, ")"]
+    }
+
+    fn map_encode(
+        &self,
+        input: Tokens<'el, JavaScript<'el>>,
+        _: Tokens<'el, JavaScript<'el>>,
+        value: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+
+        t.append("((data: any) => {");
+        t.append(" const o: any = {};");
+        t.append(" for (let k in data) {");
+        t.append(toks![" o[k] = ", value, ";"]);
+        t.append(" }");
+        t.append(" return o;");
+        t.append(toks![" })(", input, ")"]);
+
+        t
+    }
+}
+
+impl<'el> ValueBuilder<'el> for TypeScriptBackend {
+    type Output = Tokens<'el, JavaScript<'el>>;
+
+    fn literal(&self, literal: String) -> Self::Output {
+        toks![Rc::new(literal)]
+    }
+
+    fn string_literal(&self, string: &str) -> Self::Output {
+        toks![string.to_string().quoted()]
+    }
+
+    fn array_literal(&self, items: Vec<Self::Output>) -> Self::Output {
+        let mut args = Tokens::new();
+
+        for item in items {
+            args.append(item);
+        }
+
+        toks!["[", args.join(", "), "]"]
+    }
+}
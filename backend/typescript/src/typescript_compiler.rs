@@ -0,0 +1,62 @@
+//! Compiler for TypeScript Backend
+
+use super::EXT;
+use backend::{Environment, PackageProcessor, PackageUtils};
+use backend::errors::*;
+use core::{Loc, RpEnumBody, RpInterfaceBody, RpPackage, RpTupleBody, RpTypeBody,
+           RpVersionedPackage};
+use std::path::{Path, PathBuf};
+use typescript_backend::TypeScriptBackend;
+use typescript_file_spec::TypeScriptFileSpec;
+
+pub struct TypeScriptCompiler<'el> {
+    pub out_path: PathBuf,
+    pub backend: &'el TypeScriptBackend,
+}
+
+impl<'el> TypeScriptCompiler<'el> {
+    pub fn compile(&self) -> Result<()> {
+        let files = self.populate_files()?;
+        self.write_files(files)
+    }
+}
+
+impl<'el> PackageProcessor<'el> for TypeScriptCompiler<'el> {
+    type Out = TypeScriptFileSpec<'el>;
+
+    fn ext(&self) -> &str {
+        EXT
+    }
+
+    fn env(&self) -> &'el Environment {
+        &self.backend.env
+    }
+
+    fn out_path(&self) -> &Path {
+        &self.out_path
+    }
+
+    fn processed_package(&self, package: &RpVersionedPackage) -> RpPackage {
+        self.backend.package(package)
+    }
+
+    fn process_tuple(&self, out: &mut Self::Out, body: &'el Loc<RpTupleBody>) -> Result<()> {
+        self.backend.process_tuple(out, body)
+    }
+
+    fn process_enum(&self, out: &mut Self::Out, body: &'el Loc<RpEnumBody>) -> Result<()> {
+        self.backend.process_enum(out, body)
+    }
+
+    fn process_type(&self, out: &mut Self::Out, body: &'el Loc<RpTypeBody>) -> Result<()> {
+        self.backend.process_type(out, body)
+    }
+
+    fn process_interface(
+        &self,
+        out: &mut Self::Out,
+        body: &'el Loc<RpInterfaceBody>,
+    ) -> Result<()> {
+        self.backend.process_interface(out, body)
+    }
+}
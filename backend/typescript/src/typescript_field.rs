@@ -0,0 +1,12 @@
+use core::{Loc, RpConstraints, RpModifier, RpType, RpValue};
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct TypeScriptField<'a> {
+    pub modifier: &'a RpModifier,
+    pub ty: &'a RpType,
+    pub constraints: &'a RpConstraints,
+    pub default: Option<&'a Loc<RpValue>>,
+    pub name: &'a str,
+    pub ident: Rc<String>,
+}
@@ -0,0 +1,21 @@
+//! File spec collecting results from backends
+
+use backend::IntoBytes;
+use backend::errors::*;
+use genco::{JavaScript, Tokens};
+use typescript_compiler::TypeScriptCompiler;
+
+pub struct TypeScriptFileSpec<'el>(pub Tokens<'el, JavaScript<'el>>);
+
+impl<'el> Default for TypeScriptFileSpec<'el> {
+    fn default() -> Self {
+        TypeScriptFileSpec(Tokens::new())
+    }
+}
+
+impl<'el> IntoBytes<TypeScriptCompiler<'el>> for TypeScriptFileSpec<'el> {
+    fn into_bytes(self, _: &TypeScriptCompiler<'el>) -> Result<Vec<u8>> {
+        let out = self.0.join_line_spacing().to_file()?;
+        Ok(out.into_bytes())
+    }
+}
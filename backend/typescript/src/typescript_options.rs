@@ -0,0 +1,9 @@
+//! Options for TypeScript
+
+pub struct TypeScriptOptions {}
+
+impl TypeScriptOptions {
+    pub fn new() -> TypeScriptOptions {
+        TypeScriptOptions {}
+    }
+}
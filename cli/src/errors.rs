@@ -22,6 +22,7 @@ error_chain! {
         Fmt(::std::fmt::Error);
         Log(::log::SetLoggerError);
         Toml(::toml::de::Error);
+        Json(::serde_json::Error);
         UrlParseError(::url::ParseError);
         FromUtf8Error(::std::string::FromUtf8Error);
     }
@@ -49,6 +50,12 @@ error_chain! {
         MissingBackend {
         }
 
+        /// Lints were reported which are denied.
+        DeniedLints(count: usize) {
+            description("denied lints")
+            display("aborting due to {} denied lint(s)", count)
+        }
+
         /// An instance creation is missing a set of required fields.
         MissingRequired(names: Vec<String>, pos: ErrorPos, fields: Vec<ErrorPos>) {
             description("missing required")
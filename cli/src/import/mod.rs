@@ -0,0 +1,1200 @@
+//! Import declarations from JSON Schema and OpenAPI documents.
+//!
+//! Schemas are mapped to the closest matching declarations:
+//!
+//!  * Objects become types, and objects nested in properties become inner types.
+//!  * `oneOf` over local references with a discriminator becomes an interface.
+//!  * String enumerations become enums.
+//!  * OpenAPI paths become endpoints in a single service.
+//!
+//! Anything which can't be represented is imported as `any`.
+
+mod model;
+
+use self::model::*;
+use core::{BytesObject, Object, RpHttpMethod};
+use errors::*;
+use parser;
+use parser::printer;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Prefixes of local references to named schemas.
+const REF_PREFIXES: &[&str] = &["#/definitions/", "#/$defs/", "#/components/schemas/"];
+
+/// Kind of document being imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    JsonSchema,
+    Swagger,
+    OpenApi,
+}
+
+impl Source {
+    fn detect(document: &Value) -> Source {
+        if document.get("openapi").is_some() {
+            return Source::OpenApi;
+        }
+
+        if document.get("swagger").is_some() {
+            return Source::Swagger;
+        }
+
+        Source::JsonSchema
+    }
+}
+
+/// How a named schema is imported.
+enum Kind {
+    Type,
+    Enum,
+    /// Interface, with the discriminating property and the sub-types as `(definition, tag)`.
+    Interface(String, Vec<(String, String)>),
+    /// Schemas which are not declarations are inlined where they are referenced.
+    Alias,
+}
+
+/// Import the given document, returning the specification in the reproto language.
+pub fn import(document: &Value) -> Result<String> {
+    let model = Importer::new(document).import()?;
+
+    let mut out = String::new();
+    printer::print(&mut out, &model.to_file())?;
+
+    // the printed specification is expected to always be valid
+    {
+        let object: Rc<Box<Object>> = Rc::new(Box::new(BytesObject::new(
+            "import".to_string(),
+            Arc::new(out.clone().into_bytes()),
+        )));
+
+        parser::parse_string(object, &out)?;
+    }
+
+    Ok(out)
+}
+
+struct Importer<'a> {
+    document: &'a Value,
+    source: Source,
+    /// Named schemas, by their definition name.
+    definitions: BTreeMap<&'a str, &'a Value>,
+    /// Full path of the declaration that each definition is imported as.
+    names: HashMap<String, Vec<String>>,
+    /// Names in use at the top level of the package.
+    used: HashSet<String>,
+    /// Aliases currently being inlined, to guard against cycles.
+    inlining: HashSet<String>,
+}
+
+impl<'a> Importer<'a> {
+    fn new(document: &'a Value) -> Importer<'a> {
+        let source = Source::detect(document);
+
+        let pointers: &[&str] = match source {
+            Source::OpenApi => &["/components/schemas"],
+            Source::Swagger | Source::JsonSchema => &["/definitions", "/$defs"],
+        };
+
+        let mut definitions = BTreeMap::new();
+
+        for pointer in pointers {
+            if let Some(map) = document.pointer(pointer).and_then(Value::as_object) {
+                for (key, value) in map {
+                    definitions.insert(key.as_str(), value);
+                }
+            }
+        }
+
+        Importer {
+            document: document,
+            source: source,
+            definitions: definitions,
+            names: HashMap::new(),
+            used: HashSet::new(),
+            inlining: HashSet::new(),
+        }
+    }
+
+    fn import(mut self) -> Result<Model> {
+        let mut kinds = Vec::new();
+        let mut consumed = HashSet::new();
+
+        for (&key, &schema) in &self.definitions {
+            let kind = self.kind(schema, &consumed);
+
+            if let Kind::Interface(_, ref sub_types) = kind {
+                consumed.extend(sub_types.iter().map(|s| s.0.clone()));
+            }
+
+            kinds.push((key, schema, kind));
+        }
+
+        // assign names before importing, since definitions can reference each other in any order
+        for &(key, _, ref kind) in &kinds {
+            match *kind {
+                Kind::Alias => continue,
+                _ if consumed.contains(key) => continue,
+                _ => {}
+            }
+
+            let name = self.unique(type_name(key));
+            self.names.insert(key.to_string(), vec![name.clone()]);
+
+            if let Kind::Interface(_, ref sub_types) = *kind {
+                for &(ref sub_type, _) in sub_types {
+                    let path = vec![name.clone(), type_name(sub_type)];
+                    self.names.insert(sub_type.clone(), path);
+                }
+            }
+        }
+
+        let mut decls = Vec::new();
+
+        for (key, schema, kind) in kinds {
+            let path = match self.names.get(key) {
+                Some(path) if path.len() == 1 => path.clone(),
+                _ => continue,
+            };
+
+            let decl = match kind {
+                Kind::Type => self.type_decl(path, schema)?,
+                Kind::Enum => self.enum_decl(path, schema),
+                Kind::Interface(field, sub_types) => {
+                    self.interface_decl(path, schema, field, sub_types)?
+                }
+                Kind::Alias => continue,
+            };
+
+            decls.push(decl);
+        }
+
+        let document = self.document;
+        let root = self.source == Source::JsonSchema && document.get("properties").is_some();
+
+        if root {
+            let name = document.get("title").and_then(Value::as_str).unwrap_or("Root");
+            let name = self.unique(type_name(name));
+            let decl = self.type_decl(vec![name], document)?;
+            decls.push(decl);
+        }
+
+        if let Some(service) = self.service(&mut decls)? {
+            decls.push(service);
+        }
+
+        // a root schema which is declared is described by its declaration instead
+        let comment = match self.source {
+            Source::JsonSchema if root => vec![],
+            Source::JsonSchema => description(document),
+            _ => document.get("info").map(description).unwrap_or_default(),
+        };
+
+        Ok(Model {
+            comment: comment,
+            decls: decls,
+        })
+    }
+
+    /// Pick a top-level name which is not already in use.
+    fn unique(&mut self, name: String) -> String {
+        let name = unique_in(&self.used, name);
+        self.used.insert(name.clone());
+        name
+    }
+
+    /// Determine how the given named schema should be imported.
+    fn kind(&self, schema: &Value, consumed: &HashSet<String>) -> Kind {
+        if string_enum(schema).is_some() {
+            return Kind::Enum;
+        }
+
+        if let Some((field, sub_types)) = self.interface(schema) {
+            if sub_types.iter().all(|s| !consumed.contains(&s.0)) {
+                return Kind::Interface(field, sub_types);
+            }
+        }
+
+        if is_object(schema) {
+            return Kind::Type;
+        }
+
+        Kind::Alias
+    }
+
+    /// Find the discriminating property and the sub-types of a `oneOf` schema.
+    fn interface(&self, schema: &Value) -> Option<(String, Vec<(String, String)>)> {
+        let one_of = schema.get("oneOf").and_then(Value::as_array)?;
+
+        if one_of.is_empty() {
+            return None;
+        }
+
+        let mut members = Vec::new();
+
+        for member in one_of {
+            let reference = member.get("$ref").and_then(Value::as_str)?;
+            let key = local_reference(reference)?;
+            let schema = *self.definitions.get(key)?;
+
+            if !is_object(schema) {
+                return None;
+            }
+
+            members.push((reference, key, self.properties(schema)));
+        }
+
+        let discriminator = schema.get("discriminator");
+
+        let field = match discriminator {
+            Some(&Value::String(ref field)) => field.to_string(),
+            Some(d) => d.get("propertyName").and_then(Value::as_str)?.to_string(),
+            None => {
+                // a property which has a single constant value in every member
+                let &(_, _, (ref first, _)) = members.first()?;
+
+                first
+                    .iter()
+                    .map(|&(name, _)| name)
+                    .find(|name| {
+                        members.iter().all(|&(_, _, (ref properties, _))| {
+                            properties.iter().any(|&(n, p)| n == *name && constant(p).is_some())
+                        })
+                    })?
+                    .to_string()
+            }
+        };
+
+        let mapping = discriminator.and_then(|d| d.get("mapping")).and_then(Value::as_object);
+
+        let mut sub_types = Vec::new();
+        let mut tags = HashSet::new();
+
+        for (reference, key, (properties, _)) in members {
+            let tag = mapping
+                .and_then(|m| m.iter().find(|&(_, v)| v.as_str() == Some(reference)))
+                .map(|(tag, _)| tag.as_str())
+                .or_else(|| {
+                    properties.iter().find(|&&(n, _)| n == field).and_then(|&(_, p)| constant(p))
+                })
+                .unwrap_or(key);
+
+            if !tags.insert(tag) {
+                return None;
+            }
+
+            sub_types.push((key.to_string(), tag.to_string()));
+        }
+
+        Some((field, sub_types))
+    }
+
+    /// Collect all properties of an object schema, merging `allOf`.
+    ///
+    /// Also returns the names of all required properties.
+    fn properties(&self, schema: &'a Value) -> (Vec<(&'a str, &'a Value)>, HashSet<&'a str>) {
+        let mut properties = Vec::new();
+        let mut required = HashSet::new();
+        self.collect_properties(schema, &mut properties, &mut required, &mut HashSet::new());
+        (properties, required)
+    }
+
+    fn collect_properties(
+        &self,
+        schema: &'a Value,
+        properties: &mut Vec<(&'a str, &'a Value)>,
+        required: &mut HashSet<&'a str>,
+        visited: &mut HashSet<&'a str>,
+    ) {
+        if let Some(key) = schema.get("$ref").and_then(Value::as_str).and_then(local_reference) {
+            if visited.insert(key) {
+                if let Some(&schema) = self.definitions.get(key) {
+                    self.collect_properties(schema, properties, required, visited);
+                }
+            }
+
+            return;
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            for schema in all_of {
+                self.collect_properties(schema, properties, required, visited);
+            }
+        }
+
+        if let Some(map) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in map {
+                if !properties.iter().any(|&(n, _)| n == name.as_str()) {
+                    properties.push((name.as_str(), property));
+                }
+            }
+        }
+
+        if let Some(names) = schema.get("required").and_then(Value::as_array) {
+            required.extend(names.iter().flat_map(Value::as_str));
+        }
+    }
+
+    fn type_decl(&mut self, path: Vec<String>, schema: &'a Value) -> Result<Decl> {
+        let mut inner = Vec::new();
+        let fields = self.fields(&path, schema, None, &mut inner)?;
+
+        Ok(Decl {
+            name: path.last().cloned().unwrap_or_default(),
+            comment: description(schema),
+            body: Body::Type {
+                fields: fields,
+                inner: inner,
+            },
+        })
+    }
+
+    fn enum_decl(&mut self, path: Vec<String>, schema: &'a Value) -> Decl {
+        let mut names = HashSet::new();
+        let mut variants = Vec::new();
+
+        for value in string_enum(schema).unwrap_or_default() {
+            let name = unique_in(&names, type_name(value));
+            names.insert(name.clone());
+
+            variants.push(Variant {
+                name: name,
+                value: value.to_string(),
+            });
+        }
+
+        Decl {
+            name: path.last().cloned().unwrap_or_default(),
+            comment: description(schema),
+            body: Body::Enum { variants: variants },
+        }
+    }
+
+    fn interface_decl(
+        &mut self,
+        path: Vec<String>,
+        schema: &'a Value,
+        field: String,
+        sub_types: Vec<(String, String)>,
+    ) -> Result<Decl> {
+        let mut out = Vec::new();
+
+        for (key, tag) in sub_types {
+            let sub_type = match self.definitions.get(key.as_str()) {
+                Some(sub_type) => *sub_type,
+                None => continue,
+            };
+
+            let sub_path = self.names[&key].clone();
+
+            let mut inner = Vec::new();
+            let fields = self.fields(&sub_path, sub_type, Some(field.as_str()), &mut inner)?;
+
+            out.push(SubType {
+                name: sub_path.last().cloned().unwrap_or_default(),
+                alias: tag,
+                comment: description(sub_type),
+                fields: fields,
+                inner: inner,
+            });
+        }
+
+        let type_field = if field != "type" { Some(field) } else { None };
+
+        Ok(Decl {
+            name: path.last().cloned().unwrap_or_default(),
+            comment: description(schema),
+            body: Body::Interface {
+                type_field: type_field,
+                sub_types: out,
+            },
+        })
+    }
+
+    /// Import the properties of an object schema as fields.
+    ///
+    /// Inline declarations are added to `inner`, and are scoped under `path`.
+    fn fields(
+        &mut self,
+        path: &[String],
+        schema: &'a Value,
+        exclude: Option<&str>,
+        inner: &mut Vec<Decl>,
+    ) -> Result<Vec<Field>> {
+        let (properties, required) = self.properties(schema);
+        let mut fields: Vec<Field> = Vec::new();
+
+        for (key, property) in properties {
+            if Some(key) == exclude {
+                continue;
+            }
+
+            let name = unique_in(
+                &fields.iter().map(|f| f.name.clone()).collect(),
+                field_name(key),
+            );
+            let ty = self.ty(path, &type_name(key), property, inner)?;
+
+            fields.push(Field {
+                alias: if name != key { Some(key.to_string()) } else { None },
+                name: name,
+                optional: !required.contains(key) || is_nullable(property),
+                comment: description(property),
+                ty: ty,
+            });
+        }
+
+        Ok(fields)
+    }
+
+    /// Import the type of a schema.
+    ///
+    /// Inline declarations are named after `hint`, added to `decls`, and scoped under `path`.
+    fn ty(
+        &mut self,
+        path: &[String],
+        hint: &str,
+        schema: &'a Value,
+        decls: &mut Vec<Decl>,
+    ) -> Result<Ty> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.reference(path, hint, reference, decls);
+        }
+
+        if string_enum(schema).is_some() {
+            let decl_path = self.inline_path(path, hint, &decls[..]);
+            let decl = self.enum_decl(decl_path.clone(), schema);
+            decls.push(decl);
+            return Ok(Ty::Name(decl_path));
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            if all_of.len() == 1 && schema.get("properties").is_none() {
+                return self.ty(path, hint, &all_of[0], decls);
+            }
+        }
+
+        if let Some(one_of) = schema.get("oneOf").or(schema.get("anyOf")).and_then(
+            Value::as_array,
+        )
+        {
+            let mut schemas = one_of.iter().filter(|s| s.get("type") != Some(&json!("null")));
+
+            return match (schemas.next(), schemas.next()) {
+                (Some(schema), None) => self.ty(path, hint, schema, decls),
+                _ => Ok(Ty::Any),
+            };
+        }
+
+        let ty = match schema_type(schema) {
+            Some(ty) => ty,
+            None if is_object(schema) => "object",
+            None if schema.get("const").map(Value::is_string) == Some(true) => "string",
+            None => return Ok(Ty::Any),
+        };
+
+        let format = schema.get("format").and_then(Value::as_str);
+
+        let out = match ty {
+            "string" => {
+                match format {
+                    Some("date-time") => Ty::DateTime,
+                    Some("date") => Ty::Date,
+                    Some("duration") => Ty::Duration,
+                    Some("uuid") => Ty::Uuid,
+                    Some("byte") => Ty::Bytes,
+                    _ if schema.get("contentEncoding") == Some(&json!("base64")) => Ty::Bytes,
+                    _ => Ty::String,
+                }
+            }
+            "integer" => integer(schema, format),
+            "number" => {
+                match format {
+                    Some("float") => Ty::Float,
+                    _ => Ty::Double,
+                }
+            }
+            "boolean" => Ty::Boolean,
+            "array" => {
+                let inner = match schema.get("items") {
+                    Some(items) if items.is_object() => self.ty(path, hint, items, decls)?,
+                    _ => Ty::Any,
+                };
+
+                Ty::Array(Box::new(inner))
+            }
+            "object" => {
+                if is_object(schema) {
+                    let decl_path = self.inline_path(path, hint, &decls[..]);
+                    let decl = self.type_decl(decl_path.clone(), schema)?;
+                    decls.push(decl);
+                    return Ok(Ty::Name(decl_path));
+                }
+
+                let value = match schema.get("additionalProperties") {
+                    Some(value) if value.is_object() => self.ty(path, hint, value, decls)?,
+                    _ => Ty::Any,
+                };
+
+                Ty::Map(Box::new(value))
+            }
+            _ => Ty::Any,
+        };
+
+        Ok(out)
+    }
+
+    /// Import a reference to a schema.
+    fn reference(
+        &mut self,
+        path: &[String],
+        hint: &str,
+        reference: &str,
+        decls: &mut Vec<Decl>,
+    ) -> Result<Ty> {
+        let key = match local_reference(reference) {
+            Some(key) => key,
+            None => {
+                warn!("unsupported reference `{}`, importing as any", reference);
+                return Ok(Ty::Any);
+            }
+        };
+
+        if let Some(name) = self.names.get(key) {
+            return Ok(Ty::Name(name.clone()));
+        }
+
+        let schema = match self.definitions.get(key) {
+            Some(schema) => *schema,
+            None => return Err(format!("no such definition: {}", reference).into()),
+        };
+
+        if !self.inlining.insert(key.to_string()) {
+            warn!("recursive reference `{}`, importing as any", reference);
+            return Ok(Ty::Any);
+        }
+
+        let ty = self.ty(path, hint, schema, decls);
+        self.inlining.remove(key);
+        ty
+    }
+
+    /// Build the path for an inline declaration.
+    fn inline_path(&mut self, path: &[String], hint: &str, decls: &[Decl]) -> Vec<String> {
+        let name = if path.is_empty() {
+            self.unique(hint.to_string())
+        } else {
+            let used = decls.iter().map(|d| d.name.clone()).collect();
+            unique_in(&used, hint.to_string())
+        };
+
+        let mut path = path.to_vec();
+        path.push(name);
+        path
+    }
+
+    /// Import all paths of an OpenAPI document as a single service.
+    fn service(&mut self, decls: &mut Vec<Decl>) -> Result<Option<Decl>> {
+        let document = self.document;
+
+        let paths = match document.get("paths").and_then(Value::as_object) {
+            Some(paths) if !paths.is_empty() => paths,
+            _ => return Ok(None),
+        };
+
+        let mut endpoints = Vec::new();
+        let mut ids = HashSet::new();
+
+        for (path, item) in paths {
+            for method in RpHttpMethod::ALL {
+                let operation = match item.get(&method.as_str().to_lowercase()) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+
+                let endpoint = self.endpoint(decls, &mut ids, *method, path, item, operation)?;
+                endpoints.push(endpoint);
+            }
+        }
+
+        let name = document
+            .pointer("/info/title")
+            .and_then(Value::as_str)
+            .unwrap_or("Api");
+
+        Ok(Some(Decl {
+            name: self.unique(type_name(name)),
+            comment: vec![],
+            body: Body::Service { endpoints: endpoints },
+        }))
+    }
+
+    fn endpoint(
+        &mut self,
+        decls: &mut Vec<Decl>,
+        ids: &mut HashSet<String>,
+        method: RpHttpMethod,
+        path: &str,
+        item: &'a Value,
+        operation: &'a Value,
+    ) -> Result<Endpoint> {
+        let operation_id = operation.get("operationId").and_then(Value::as_str);
+
+        let id = match operation_id {
+            Some(operation_id) => field_name(operation_id),
+            None => field_name(&format!("{} {}", method.as_str().to_lowercase(), path)),
+        };
+
+        let id = unique_in(ids, id);
+        ids.insert(id.clone());
+
+        let alias = operation_id.and_then(|operation_id| if operation_id != id {
+            Some(operation_id.to_string())
+        } else {
+            None
+        });
+
+        let mut comment = Vec::new();
+
+        if let Some(summary) = operation.get("summary").and_then(Value::as_str) {
+            comment.extend(lines(summary));
+        }
+
+        if let Some(description) = operation.get("description").and_then(Value::as_str) {
+            if !comment.is_empty() {
+                comment.push("".to_string());
+            }
+
+            comment.extend(lines(description));
+        }
+
+        let parameters: Vec<&'a Value> = item.get("parameters")
+            .into_iter()
+            .chain(operation.get("parameters"))
+            .flat_map(Value::as_array)
+            .flat_map(|p| p.iter())
+            .collect();
+
+        let mut body = None;
+        let mut path_parameters = HashMap::new();
+
+        for parameter in parameters {
+            let name = parameter.get("name").and_then(Value::as_str).unwrap_or("");
+
+            match parameter.get("in").and_then(Value::as_str) {
+                Some("path") => {
+                    // OpenAPI 3 has a schema, Swagger 2 describes the type in the parameter
+                    let schema = parameter.get("schema").unwrap_or(parameter);
+                    path_parameters.insert(name, schema);
+                }
+                Some("body") => {
+                    body = parameter.get("schema");
+                }
+                Some(location) => {
+                    warn!(
+                        "{} {}: {} parameter `{}` is not supported, skipping",
+                        method,
+                        path,
+                        location,
+                        name
+                    );
+                }
+                None => {}
+            }
+        }
+
+        if let Some(request_body) = operation.get("requestBody") {
+            body = json_schema(request_body);
+        }
+
+        let http_path = http_path(path);
+
+        if http_path.is_none() {
+            warn!("{} {}: path is not supported, skipping http binding", method, path);
+        }
+
+        let name = type_name(&id);
+
+        let request = match http_path {
+            Some((_, ref variables)) if !variables.is_empty() => {
+                let mut fields = Vec::new();
+                let request_path = vec![self.unique(format!("{}Request", name))];
+                let mut inner = Vec::new();
+
+                for &(ref variable, original) in variables {
+                    // path variables are always primitive, so nothing is declared
+                    let ty = match path_parameters.get(original) {
+                        Some(&schema) => {
+                            self.ty(&request_path, &type_name(original), schema, &mut Vec::new())?
+                        }
+                        None => Ty::String,
+                    };
+
+                    fields.push(Field {
+                        name: variable.clone(),
+                        alias: None,
+                        optional: false,
+                        comment: vec![],
+                        ty: if ty.is_primitive() { ty } else { Ty::String },
+                    });
+                }
+
+                match body {
+                    Some(body) if method.has_body() => {
+                        if self.is_object_body(body) {
+                            let body_fields = self.fields(&request_path, body, None, &mut inner)?;
+
+                            for field in body_fields {
+                                if fields.iter().any(|f| f.name == field.name) {
+                                    warn!(
+                                        "{} {}: body field `{}` conflicts with path variable",
+                                        method,
+                                        path,
+                                        field.name
+                                    );
+                                    continue;
+                                }
+
+                                fields.push(field);
+                            }
+                        } else {
+                            let ty = self.ty(&request_path, "Body", body, &mut inner)?;
+
+                            fields.push(Field {
+                                name: unique_in(
+                                    &fields.iter().map(|f| f.name.clone()).collect(),
+                                    "body".to_string(),
+                                ),
+                                alias: None,
+                                optional: false,
+                                comment: vec![],
+                                ty: ty,
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+
+                decls.push(Decl {
+                    name: request_path[0].clone(),
+                    comment: vec![],
+                    body: Body::Type {
+                        fields: fields,
+                        inner: inner,
+                    },
+                });
+
+                Some(Ty::Name(request_path))
+            }
+            _ => {
+                match body {
+                    Some(body) if method.has_body() || http_path.is_none() => {
+                        Some(self.ty(&[], &format!("{}Request", name), body, decls)?)
+                    }
+                    _ => None,
+                }
+            }
+        };
+
+        let response = match response_schema(operation) {
+            Some(schema) => Some(self.ty(&[], &format!("{}Response", name), schema, decls)?),
+            None => None,
+        };
+
+        let http = http_path.map(|(p, _)| (method.as_str().to_string(), p));
+
+        Ok(Endpoint {
+            id: id,
+            alias: alias,
+            comment: comment,
+            http: http,
+            request: request,
+            response: response,
+        })
+    }
+
+    /// Check if the body of a request is an object, whose fields can be merged into the request.
+    fn is_object_body(&self, schema: &Value) -> bool {
+        if let Some(key) = schema.get("$ref").and_then(Value::as_str).and_then(local_reference) {
+            return self.definitions.get(key).map(|s| self.is_object_body(s)).unwrap_or(false);
+        }
+
+        is_object(schema) && string_enum(schema).is_none() && self.interface(schema).is_none()
+    }
+}
+
+/// Get the definition name of a local reference.
+fn local_reference(reference: &str) -> Option<&str> {
+    REF_PREFIXES
+        .iter()
+        .filter(|p| reference.starts_with(*p))
+        .map(|p| &reference[p.len()..])
+        .next()
+}
+
+/// Get the type of a schema, ignoring `null`.
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type") {
+        Some(&Value::String(ref ty)) => Some(ty.as_str()),
+        Some(&Value::Array(ref types)) => {
+            let mut types = types.iter().flat_map(Value::as_str).filter(|t| *t != "null");
+
+            match (types.next(), types.next()) {
+                (Some(ty), None) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    if schema.get("nullable") == Some(&Value::Bool(true)) {
+        return true;
+    }
+
+    match schema.get("type") {
+        Some(&Value::Array(ref types)) => types.iter().any(|t| t == "null"),
+        _ => false,
+    }
+}
+
+/// Check if the schema describes an object with properties.
+fn is_object(schema: &Value) -> bool {
+    match schema_type(schema) {
+        Some("object") | None => {}
+        Some(_) => return false,
+    }
+
+    if schema.get("properties").and_then(Value::as_object).is_some() {
+        return true;
+    }
+
+    schema.get("allOf").and_then(Value::as_array).map(|a| a.len() > 1) == Some(true)
+}
+
+/// Get the values of a string enumeration.
+fn string_enum(schema: &Value) -> Option<Vec<&str>> {
+    let values = schema.get("enum").and_then(Value::as_array)?;
+    values.iter().map(Value::as_str).collect()
+}
+
+/// Get the constant string value of a schema.
+fn constant(schema: &Value) -> Option<&str> {
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return Some(value);
+    }
+
+    match string_enum(schema) {
+        Some(ref values) if values.len() == 1 => Some(values[0]),
+        _ => None,
+    }
+}
+
+fn integer(schema: &Value, format: Option<&str>) -> Ty {
+    let minimum = schema.get("minimum").and_then(Value::as_f64);
+    let maximum = schema.get("maximum").and_then(Value::as_f64);
+
+    let unsigned = minimum.map(|m| m >= 0f64).unwrap_or(false);
+
+    let fits = match (minimum, maximum) {
+        (Some(_), Some(max)) if unsigned => max <= u32::max_value() as f64,
+        (Some(min), Some(max)) => min >= i32::min_value() as f64 && max <= i32::max_value() as f64,
+        _ => false,
+    };
+
+    let size = match format {
+        Some("int32") => 32,
+        Some("int64") => 64,
+        _ if fits => 32,
+        _ => 64,
+    };
+
+    if unsigned {
+        Ty::Unsigned(size)
+    } else {
+        Ty::Signed(size)
+    }
+}
+
+/// Find the JSON schema of a request body or response.
+fn json_schema(value: &Value) -> Option<&Value> {
+    if let Some(schema) = value.get("schema") {
+        return Some(schema);
+    }
+
+    let content = value.get("content").and_then(Value::as_object)?;
+
+    content
+        .iter()
+        .find(|&(mime, _)| mime.contains("json"))
+        .and_then(|(_, media)| media.get("schema"))
+}
+
+/// Find the schema of the first successful response.
+fn response_schema(operation: &Value) -> Option<&Value> {
+    let responses = operation.get("responses").and_then(Value::as_object)?;
+
+    responses
+        .iter()
+        .filter(|&(status, _)| status.starts_with('2'))
+        .flat_map(|(_, response)| json_schema(response))
+        .next()
+}
+
+/// Convert an OpenAPI path into an http path.
+///
+/// Returns the path and its variables as `(name, original name)`, or `None` if the path can't be
+/// represented.
+fn http_path(path: &str) -> Option<(String, Vec<(String, &str)>)> {
+    let mut segments = Vec::new();
+    let mut variables = Vec::new();
+
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        if segment.starts_with('{') && segment.ends_with('}') {
+            let original = &segment[1..segment.len() - 1];
+
+            if original.contains(|c| c == '{' || c == '}') {
+                return None;
+            }
+
+            let name = field_name(original);
+            segments.push(format!("{{{}}}", name));
+            variables.push((name, original));
+            continue;
+        }
+
+        if segment.contains(|c| c == '{' || c == '}') {
+            return None;
+        }
+
+        segments.push(segment.to_string());
+    }
+
+    Some((format!("/{}", segments.join("/")), variables))
+}
+
+/// Pick a name which is not in the given set, by adding a numeric suffix.
+fn unique_in(used: &HashSet<String>, name: String) -> String {
+    if !used.contains(&name) {
+        return name;
+    }
+
+    (2..)
+        .map(|n| format!("{}{}", name, n))
+        .find(|n| !used.contains(n))
+        .unwrap_or(name)
+}
+
+/// Split a name into words, on separators and lower to upper case transitions.
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+
+    for c in name.chars() {
+        if !c.is_ascii() || !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(current.clone());
+                current.clear();
+            }
+
+            previous_lower = false;
+            continue;
+        }
+
+        if c.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(current.clone());
+            current.clear();
+        }
+
+        previous_lower = c.is_lowercase() || c.is_numeric();
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// Convert into the name of a type, like `FooBar`.
+fn type_name(name: &str) -> String {
+    let mut out = String::new();
+
+    for word in words(name) {
+        let mut chars = word.chars();
+
+        if let Some(first) = chars.next() {
+            out.extend(first.to_uppercase());
+            out.extend(chars);
+        }
+    }
+
+    match out.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(c) if c.is_numeric() => format!("T{}", out),
+        Some(_) => out,
+    }
+}
+
+/// Convert into the name of a field or endpoint, like `foo_bar`.
+fn field_name(name: &str) -> String {
+    let words: Vec<String> = words(name).iter().map(|w| w.to_lowercase()).collect();
+    let out = words.join("_");
+
+    match out.chars().next() {
+        None => "unnamed".to_string(),
+        Some(c) if c.is_numeric() => format!("n_{}", out),
+        Some(_) => out,
+    }
+}
+
+/// Convert text into comment lines.
+fn lines(text: &str) -> Vec<String> {
+    text.trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim_right();
+
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", line)
+            }
+        })
+        .collect()
+}
+
+/// Get the description of a schema as comment lines.
+fn description(schema: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+
+    if let Some(title) = schema.get("title").and_then(Value::as_str) {
+        out.extend(lines(title));
+    }
+
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        if !out.is_empty() {
+            out.push("".to_string());
+        }
+
+        out.extend(lines(description));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!("FooBar", type_name("foo_bar"));
+        assert_eq!("FooBar", type_name("fooBar"));
+        assert_eq!("HTTPError", type_name("HTTPError"));
+        assert_eq!("T2fa", type_name("2fa"));
+        assert_eq!("foo_bar", field_name("fooBar"));
+        assert_eq!("get_pets_pet_id", field_name("get /pets/{petId}"));
+        assert_eq!("n_2fa", field_name("2fa"));
+    }
+
+    #[test]
+    fn test_http_path() {
+        assert_eq!(
+            Some(("/pets/{pet_id}".to_string(), vec![("pet_id".to_string(), "petId")])),
+            http_path("/pets/{petId}/")
+        );
+
+        assert_eq!(None, http_path("/pets/{id}.json"));
+    }
+
+    #[test]
+    fn test_import_json_schema() {
+        let document = json!({
+            "definitions": {
+                "Pet": {
+                    "type": "object",
+                    "description": "A pet.",
+                    "properties": {
+                        "name": {"type": "string"},
+                        "petType": {"$ref": "#/definitions/PetType"},
+                        "tags": {"type": "array", "items": {"type": "string"}},
+                        "owner": {
+                            "type": "object",
+                            "properties": {"id": {"type": "integer", "format": "int64"}}
+                        }
+                    },
+                    "required": ["name"]
+                },
+                "PetType": {"type": "string", "enum": ["cat", "dog"]},
+                "Shape": {
+                    "oneOf": [{"$ref": "#/definitions/Circle"}, {"$ref": "#/definitions/Square"}]
+                },
+                "Circle": {
+                    "type": "object",
+                    "properties": {"kind": {"const": "circle"}, "radius": {"type": "number"}}
+                },
+                "Square": {
+                    "type": "object",
+                    "properties": {"kind": {"const": "square"}, "size": {"type": "number"}}
+                }
+            }
+        });
+
+        let out = import(&document).unwrap();
+
+        assert!(out.contains("type Pet {"));
+        assert!(out.contains("owner?: Pet::Owner;"));
+        assert!(out.contains("pet_type?: PetType as \"petType\";"));
+        assert!(out.contains("enum PetType as string {"));
+        assert!(out.contains("interface Shape {"));
+        assert!(out.contains("option type_field_name = \"kind\";"));
+        assert!(out.contains("Circle as \"circle\" {"));
+        assert!(!out.contains("type Circle"));
+    }
+
+    #[test]
+    fn test_import_openapi() {
+        let document = json!({
+            "openapi": "3.0.0",
+            "info": {"title": "pet store"},
+            "paths": {
+                "/pets/{petId}": {
+                    "get": {
+                        "operationId": "getPet",
+                        "parameters": [
+                            {"name": "petId", "in": "path", "schema": {"type": "string"}}
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"$ref": "#/components/schemas/Pet"}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {"type": "object", "properties": {"name": {"type": "string"}}}
+                }
+            }
+        });
+
+        let out = import(&document).unwrap();
+
+        assert!(out.contains("service PetStore {"));
+        assert!(out.contains("type GetPetRequest {"));
+        assert!(out.contains(
+            "GET \"/pets/{pet_id}\" get_pet(GetPetRequest) -> Pet as \"getPet\";",
+        ));
+    }
+}
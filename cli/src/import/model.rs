@@ -0,0 +1,294 @@
+//! Owned model of imported declarations.
+//!
+//! The AST borrows from its input, so declarations are built here first and then converted into
+//! an AST which borrows from the model.
+
+use core::{BytesObject, Loc, Object, Pos, RpModifier};
+use parser::ast;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub enum Ty {
+    Double,
+    Float,
+    Signed(usize),
+    Unsigned(usize),
+    Boolean,
+    String,
+    Bytes,
+    Any,
+    DateTime,
+    Date,
+    Duration,
+    Uuid,
+    /// Full path to a declaration in the imported package.
+    Name(Vec<String>),
+    Array(Box<Ty>),
+    Map(Box<Ty>),
+}
+
+impl Ty {
+    /// Check if the type can be bound to a path variable.
+    pub fn is_primitive(&self) -> bool {
+        use self::Ty::*;
+
+        match *self {
+            Name(_) | Array(_) | Map(_) | Any | Date | Duration => false,
+            _ => true,
+        }
+    }
+
+    fn to_ast(&self) -> ast::Type {
+        use self::Ty::*;
+
+        match *self {
+            Double => ast::Type::Double,
+            Float => ast::Type::Float,
+            Signed(size) => ast::Type::Signed { size: size },
+            Unsigned(size) => ast::Type::Unsigned { size: size },
+            Boolean => ast::Type::Boolean,
+            String => ast::Type::String,
+            Bytes => ast::Type::Bytes,
+            Any => ast::Type::Any,
+            DateTime => ast::Type::DateTime,
+            Date => ast::Type::Date,
+            Duration => ast::Type::Duration,
+            Uuid => ast::Type::Uuid,
+            Name(ref parts) => ast::Type::Name {
+                name: ast::Name::Absolute {
+                    prefix: None,
+                    parts: parts.clone(),
+                },
+            },
+            Array(ref inner) => ast::Type::Array { inner: Box::new(inner.to_ast()) },
+            Map(ref value) => ast::Type::Map {
+                key: Box::new(ast::Type::String),
+                value: Box::new(value.to_ast()),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub name: String,
+    /// Name of the field on the wire, if it differs from `name`.
+    pub alias: Option<String>,
+    pub optional: bool,
+    pub comment: Vec<String>,
+    pub ty: Ty,
+}
+
+#[derive(Debug)]
+pub struct SubType {
+    pub name: String,
+    /// Value of the type field which identifies this sub-type.
+    pub alias: String,
+    pub comment: Vec<String>,
+    pub fields: Vec<Field>,
+    pub inner: Vec<Decl>,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug)]
+pub struct Endpoint {
+    pub id: String,
+    pub alias: Option<String>,
+    pub comment: Vec<String>,
+    /// HTTP method and path.
+    pub http: Option<(String, String)>,
+    pub request: Option<Ty>,
+    pub response: Option<Ty>,
+}
+
+#[derive(Debug)]
+pub enum Body {
+    Type { fields: Vec<Field>, inner: Vec<Decl> },
+    Interface {
+        /// Name of the type field, if it is not the default.
+        type_field: Option<String>,
+        sub_types: Vec<SubType>,
+    },
+    Enum { variants: Vec<Variant> },
+    Service { endpoints: Vec<Endpoint> },
+}
+
+#[derive(Debug)]
+pub struct Decl {
+    pub name: String,
+    pub comment: Vec<String>,
+    pub body: Body,
+}
+
+#[derive(Debug)]
+pub struct Model {
+    pub comment: Vec<String>,
+    pub decls: Vec<Decl>,
+}
+
+impl Model {
+    /// Convert into an AST which can be printed.
+    pub fn to_file<'a>(&'a self) -> ast::File<'a> {
+        let object: Rc<Box<Object>> = Rc::new(Box::new(
+            BytesObject::new("import".to_string(), Arc::new(vec![])),
+        ));
+
+        let c = Converter { object: object };
+
+        ast::File {
+            comment: comment(&self.comment),
+            options: vec![],
+            uses: vec![],
+            decls: self.decls.iter().map(|d| c.loc(c.decl(d))).collect(),
+        }
+    }
+}
+
+fn comment<'a>(comment: &'a [String]) -> Vec<&'a str> {
+    comment.iter().map(String::as_str).collect()
+}
+
+struct Converter {
+    object: Rc<Box<Object>>,
+}
+
+impl Converter {
+    fn pos(&self) -> Pos {
+        (self.object.clone(), 0, 0).into()
+    }
+
+    fn loc<T>(&self, value: T) -> Loc<T> {
+        Loc::new(value, self.pos())
+    }
+
+    fn decl<'a>(&self, decl: &'a Decl) -> ast::Decl<'a> {
+        match decl.body {
+            Body::Type {
+                ref fields,
+                ref inner,
+            } => {
+                ast::Decl::Type(self.loc(ast::TypeBody {
+                    name: &decl.name,
+                    comment: comment(&decl.comment),
+                    members: self.members(fields, inner),
+                }))
+            }
+            Body::Interface {
+                ref type_field,
+                ref sub_types,
+            } => {
+                let mut members = Vec::new();
+
+                if let Some(ref type_field) = *type_field {
+                    members.push(self.loc(ast::Member::Option(ast::OptionDecl {
+                        name: "type_field_name",
+                        value: self.loc(ast::Value::String(type_field.clone())),
+                    })));
+                }
+
+                let sub_types = sub_types
+                    .iter()
+                    .map(|s| {
+                        self.loc(ast::SubType {
+                            name: self.loc(s.name.as_str()),
+                            comment: comment(&s.comment),
+                            members: self.members(&s.fields, &s.inner),
+                            alias: Some(self.loc(ast::Value::String(s.alias.clone()))),
+                        })
+                    })
+                    .collect();
+
+                ast::Decl::Interface(self.loc(ast::InterfaceBody {
+                    name: &decl.name,
+                    comment: comment(&decl.comment),
+                    members: members,
+                    sub_types: sub_types,
+                }))
+            }
+            Body::Enum { ref variants } => {
+                let variants = variants
+                    .iter()
+                    .map(|v| {
+                        self.loc(ast::EnumVariant {
+                            name: self.loc(v.name.as_str()),
+                            comment: vec![],
+                            argument: Some(self.loc(ast::Value::String(v.value.clone()))),
+                        })
+                    })
+                    .collect();
+
+                ast::Decl::Enum(self.loc(ast::EnumBody {
+                    name: &decl.name,
+                    comment: comment(&decl.comment),
+                    ty: Some(self.loc(ast::Type::String)),
+                    variants: variants,
+                    members: vec![],
+                }))
+            }
+            Body::Service { ref endpoints } => {
+                let endpoints = endpoints.iter().map(|e| self.loc(self.endpoint(e))).collect();
+
+                ast::Decl::Service(self.loc(ast::ServiceBody {
+                    name: &decl.name,
+                    comment: comment(&decl.comment),
+                    endpoints: endpoints,
+                }))
+            }
+        }
+    }
+
+    fn members<'a>(&self, fields: &'a [Field], inner: &'a [Decl]) -> Vec<Loc<ast::Member<'a>>> {
+        let mut members = Vec::new();
+
+        for field in fields {
+            let modifier = if field.optional {
+                RpModifier::Optional
+            } else {
+                RpModifier::Required
+            };
+
+            members.push(self.loc(ast::Member::Field(ast::Field {
+                modifier: modifier,
+                name: &field.name,
+                comment: comment(&field.comment),
+                ty: field.ty.to_ast(),
+                field_as: field.alias.clone(),
+                constraints: vec![],
+                default: None,
+            })));
+        }
+
+        for decl in inner {
+            members.push(self.loc(ast::Member::InnerDecl(self.decl(decl))));
+        }
+
+        members
+    }
+
+    fn endpoint<'a>(&self, endpoint: &'a Endpoint) -> ast::Endpoint<'a> {
+        let http = endpoint.http.as_ref().map(|&(ref method, ref path)| {
+            ast::EndpointHttp {
+                method: self.loc(method.as_str()),
+                path: self.loc(path.clone()),
+            }
+        });
+
+        let channel = |ty: &Ty| self.loc(ast::Channel::Unary { ty: ty.to_ast() });
+
+        ast::Endpoint {
+            id: self.loc(endpoint.id.as_str()),
+            comment: comment(&endpoint.comment),
+            alias: endpoint.alias.clone(),
+            options: vec![],
+            request: endpoint.request.as_ref().map(&channel),
+            response: endpoint.response.as_ref().map(&channel),
+            http: http,
+        }
+    }
+}
@@ -0,0 +1,1215 @@
+//! Language server for specifications, speaking the language server protocol over stdio.
+//!
+//! Every open document is analyzed on change by importing it into a fresh environment, which
+//! resolves its `use` declarations the same way as the compiler does. The environment of the last
+//! analysis is kept around to answer navigation requests for the document.
+
+mod models;
+mod transport;
+
+use self::models::*;
+use backend::Environment;
+use backend::lint;
+use core::{self, BytesObject, ErrorPos, LintLevel, Loc, Object, RpDecl, RpPackage,
+           RpVersionedPackage, Version};
+use errors::*;
+use log;
+use output::Output;
+use parser;
+use parser::ast;
+use serde::Serialize;
+use serde_json::{self, Value};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+use url::Url;
+
+const SOURCE: &str = "reproto";
+
+/// Keywords which are offered as completions.
+const KEYWORDS: &[&str] = &[
+    "type",
+    "interface",
+    "enum",
+    "tuple",
+    "service",
+    "use",
+    "as",
+    "option",
+    "stream",
+    "any",
+    "float",
+    "double",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "boolean",
+    "string",
+    "datetime",
+    "date",
+    "duration",
+    "uuid",
+    "decimal",
+    "bytes",
+];
+
+/// Options understood by the compiler, which are offered as completions.
+const OPTIONS: &[&str] = &[
+    "endpoint_naming",
+    "field_naming",
+    "http_status",
+    "type_field_name",
+    "type_info",
+];
+
+/// Run the language server until the client asks it to exit.
+///
+/// `new_env` builds the environment that documents are analyzed in, `paths` are the paths that
+/// packages are resolved from, and are used to determine the package of a document.
+pub fn run<F, R, W>(
+    new_env: F,
+    package_prefix: Option<RpPackage>,
+    paths: Vec<PathBuf>,
+    lints: BTreeMap<String, LintLevel>,
+    mut input: R,
+    mut output: W,
+) -> Result<()>
+where
+    F: Fn() -> Result<Environment>,
+    R: BufRead,
+    W: Write,
+{
+    let mut server = Server::new(new_env, package_prefix, paths, lints);
+
+    while let Some(content) = transport::read_content(&mut input)? {
+        let message = match serde_json::from_slice::<Message>(&content) {
+            Ok(message) => message,
+            Err(e) => {
+                // the id of a message which couldn't be decoded is unknown.
+                let error = json!({"code": PARSE_ERROR, "message": e.to_string()});
+                let response = json!({"jsonrpc": "2.0", "id": Value::Null, "error": error});
+                transport::write_message(&mut output, &response)?;
+                continue;
+            }
+        };
+
+        for outgoing in server.handle(message)? {
+            transport::write_message(&mut output, &outgoing)?;
+        }
+
+        if server.exit {
+            break;
+        }
+    }
+
+    if !server.shutdown {
+        return Err("exited without a shutdown request".into());
+    }
+
+    Ok(())
+}
+
+/// An open document.
+struct Document {
+    text: String,
+    /// Package declared by the document.
+    package: RpVersionedPackage,
+    /// Packages that the aliases of `use` declarations refer to.
+    prefixes: HashMap<String, RpPackage>,
+    /// Environment of the last analysis where the document could be imported.
+    env: Option<Environment>,
+}
+
+impl Document {
+    /// Object used to refer to the content of the document.
+    fn object(&self, uri: &str) -> BytesObject {
+        BytesObject::new(uri.to_string(), Arc::new(self.text.as_bytes().to_vec()))
+    }
+}
+
+/// A possibly qualified name referenced in a document, like `c::Foo::Bar`.
+#[derive(Debug, PartialEq, Eq)]
+struct NameRef {
+    /// Alias of an imported package.
+    prefix: Option<String>,
+    parts: Vec<String>,
+    /// If the name is relative to the enclosing declaration, like `::Bar`.
+    relative: bool,
+}
+
+struct Server<F> {
+    new_env: F,
+    package_prefix: Option<RpPackage>,
+    paths: Vec<PathBuf>,
+    /// Levels of lints, which are reported as diagnostics.
+    lints: BTreeMap<String, LintLevel>,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+    exit: bool,
+}
+
+impl<F> Server<F>
+where
+    F: Fn() -> Result<Environment>,
+{
+    fn new(
+        new_env: F,
+        package_prefix: Option<RpPackage>,
+        paths: Vec<PathBuf>,
+        lints: BTreeMap<String, LintLevel>,
+    ) -> Server<F> {
+        // documents are identified by absolute paths.
+        let paths = paths
+            .into_iter()
+            .map(|p| fs::canonicalize(&p).unwrap_or(p))
+            .collect();
+
+        Server {
+            new_env: new_env,
+            package_prefix: package_prefix,
+            paths: paths,
+            lints: lints,
+            documents: HashMap::new(),
+            shutdown: false,
+            exit: false,
+        }
+    }
+
+    /// Handle a single message, returning the messages to send in response.
+    fn handle(&mut self, message: Message) -> Result<Vec<Value>> {
+        let mut out = Vec::new();
+
+        // responses to requests, which are never sent by this server.
+        let method = match message.method {
+            Some(method) => method,
+            None => return Ok(out),
+        };
+
+        let id = match message.id {
+            Some(id) => id,
+            None => {
+                // notifications can't be answered with an error, so it is logged to the client.
+                if let Err(e) = self.notification(&mut out, method.as_str(), message.params) {
+                    out.push(log_message(format!("{}: {}", method, e))?);
+                }
+
+                return Ok(out);
+            }
+        };
+
+        let result = match method.as_str() {
+            "initialize" => Ok(Some(self.initialize())),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Some(Value::Null))
+            }
+            "textDocument/definition" => {
+                self.definition(message.params).and_then(|r| to_value(r))
+            }
+            "textDocument/hover" => self.hover(message.params).and_then(|r| to_value(r)),
+            "textDocument/completion" => {
+                self.completion(message.params).and_then(|r| to_value(r))
+            }
+            "textDocument/documentSymbol" => {
+                self.document_symbol(message.params).and_then(|r| to_value(r))
+            }
+            _ => Ok(None),
+        };
+
+        let response = match result {
+            Ok(Some(result)) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Ok(None) => {
+                let error = json!({
+                    "code": METHOD_NOT_FOUND,
+                    "message": format!("unsupported method: {}", method),
+                });
+
+                json!({"jsonrpc": "2.0", "id": id, "error": error})
+            }
+            Err(e) => {
+                let error = json!({"code": INTERNAL_ERROR, "message": e.to_string()});
+                json!({"jsonrpc": "2.0", "id": id, "error": error})
+            }
+        };
+
+        out.push(response);
+        return Ok(out);
+
+        fn to_value<T: Serialize>(value: T) -> Result<Option<Value>> {
+            Ok(Some(serde_json::to_value(value)?))
+        }
+    }
+
+    fn notification(&mut self, out: &mut Vec<Value>, method: &str, params: Value) -> Result<()> {
+        match method {
+            "exit" => {
+                self.exit = true;
+            }
+            "textDocument/didOpen" => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(params)?;
+                let uri = params.text_document.uri;
+                self.open(uri.clone(), params.text_document.text);
+                out.push(self.analyze(&uri)?);
+            }
+            "textDocument/didChange" => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(params)?;
+                let uri = params.text_document.uri;
+
+                // only full synchronization is supported, so the last change is the content.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.open(uri.clone(), change.text);
+                    out.push(self.analyze(&uri)?);
+                }
+            }
+            "textDocument/didClose" => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                out.push(publish_diagnostics(uri, Vec::new())?);
+            }
+            // other notifications, like `initialized`, require no action.
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn initialize(&self) -> Value {
+        json!({
+            "capabilities": {
+                "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                "hoverProvider": true,
+                "definitionProvider": true,
+                "documentSymbolProvider": true,
+                "completionProvider": {
+                    "triggerCharacters": [":"],
+                },
+            },
+        })
+    }
+
+    /// Open, or replace the content of, a document.
+    fn open(&mut self, uri: String, text: String) {
+        if let Some(document) = self.documents.get_mut(&uri) {
+            document.text = text;
+            return;
+        }
+
+        let package = self.package_of(&uri);
+
+        self.documents.insert(
+            uri,
+            Document {
+                text: text,
+                package: package,
+                prefixes: HashMap::new(),
+                env: None,
+            },
+        );
+    }
+
+    /// Determine the package of a document from where it is located in the paths.
+    fn package_of(&self, uri: &str) -> RpVersionedPackage {
+        let path = match Url::parse(uri).ok().and_then(|u| u.to_file_path().ok()) {
+            Some(path) => fs::canonicalize(&path).unwrap_or(path),
+            None => return RpVersionedPackage::new(RpPackage::empty(), None),
+        };
+
+        let relative = self.paths
+            .iter()
+            .flat_map(|p| path.strip_prefix(p).ok())
+            .next()
+            .unwrap_or_else(|| Path::new(path.file_name().unwrap_or_else(|| OsStr::new(""))));
+
+        let mut parts: Vec<String> = relative
+            .parent()
+            .into_iter()
+            .flat_map(|p| p.components())
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+
+        let stem = relative
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        // versioned files are named `<name>-<version>.reproto`.
+        let mut it = stem.splitn(2, '-');
+
+        let version = match (it.next(), it.next()) {
+            (Some(base), Some(version)) => {
+                parts.push(base.to_string());
+                Version::parse(version).ok()
+            }
+            (Some(base), None) => {
+                parts.push(base.to_string());
+                None
+            }
+            _ => None,
+        };
+
+        RpVersionedPackage::new(RpPackage::new(parts), version)
+    }
+
+    /// Apply the package prefix of the manifest, like the environment does.
+    fn prefixed(&self, package: RpPackage) -> RpPackage {
+        match self.package_prefix {
+            Some(ref prefix) => prefix.join(&package),
+            None => package,
+        }
+    }
+
+    /// Analyze the given document, and build the diagnostics to publish for it.
+    fn analyze(&mut self, uri: &str) -> Result<Value> {
+        let prefixes = match self.documents.get(uri) {
+            Some(document) => self.use_prefixes(uri, document),
+            None => return publish_diagnostics(uri.to_string(), Vec::new()),
+        };
+
+        let document = match self.documents.get_mut(uri) {
+            Some(document) => document,
+            None => return publish_diagnostics(uri.to_string(), Vec::new()),
+        };
+
+        let object: Box<Object> = Box::new(document.object(uri));
+        let object_name = object.to_string();
+        let collector = Collector::new();
+
+        // building the environment fails if, for example, a repository can't be set up.
+        let import = (self.new_env)().and_then(|mut env| {
+            env.import_object(object, Some(document.package.clone()))?;
+            Ok(env)
+        });
+
+        match import {
+            Ok(mut env) => {
+                if let Err(e) = env.verify() {
+                    collector.collect(&e.into())?;
+                }
+
+                let lints = lint::lint(&env, &self.lints, |pos| {
+                    pos.object.to_string() == object_name
+                });
+
+                match lints {
+                    Ok(lints) => {
+                        for lint in &lints {
+                            collector.lint(lint)?;
+                        }
+                    }
+                    Err(e) => collector.collect(&e.into())?,
+                }
+
+                document.env = Some(env);
+            }
+            Err(e) => {
+                collector.collect(&e)?;
+            }
+        }
+
+        document.prefixes = prefixes;
+
+        let mut diagnostics = Vec::new();
+
+        for (severity, message, pos) in collector.errors.into_inner() {
+            let (message, range) = match pos {
+                Some(ref pos) if pos.object.to_string() == object_name => {
+                    (message, range(&document.text, pos.start, pos.end))
+                }
+                // errors in other files are reported at the top of the document.
+                Some(ref pos) => {
+                    let message = format!("{}: {}", pos.object, message);
+                    (message, range(&document.text, 0, 0))
+                }
+                None => (message, range(&document.text, 0, 0)),
+            };
+
+            diagnostics.push(Diagnostic {
+                range: range,
+                severity: severity,
+                source: SOURCE,
+                message: message,
+            });
+        }
+
+        publish_diagnostics(uri.to_string(), diagnostics)
+    }
+
+    /// Resolve the packages that the aliases of `use` declarations in a document refer to.
+    fn use_prefixes(&self, uri: &str, document: &Document) -> HashMap<String, RpPackage> {
+        let mut prefixes = HashMap::new();
+
+        let object: Rc<Box<Object>> = Rc::new(Box::new(document.object(uri)));
+
+        let file = match parser::parse_string(object, &document.text) {
+            Ok(file) => file,
+            Err(_) => return document.prefixes.clone(),
+        };
+
+        for use_decl in &file.uses {
+            let package = use_decl.package.value().clone();
+
+            let alias = match use_decl.alias {
+                Some(ref alias) => alias.value().to_string(),
+                None => {
+                    match package.parts.iter().last() {
+                        Some(last) => last.to_string(),
+                        None => continue,
+                    }
+                }
+            };
+
+            prefixes.insert(alias, self.prefixed(package));
+        }
+
+        prefixes
+    }
+
+    /// Find the declaration referenced at the given position in a document.
+    fn find_decl<'a>(
+        &self,
+        document: &'a Document,
+        position: &Position,
+    ) -> Option<&'a Rc<Loc<RpDecl>>> {
+        let env = match document.env {
+            Some(ref env) => env,
+            None => return None,
+        };
+
+        let offset = offset(&document.text, position);
+
+        let name = match name_at(&document.text, offset) {
+            Some(name) => name,
+            None => return None,
+        };
+
+        let package = match name.prefix {
+            Some(ref prefix) => {
+                match document.prefixes.get(prefix) {
+                    Some(package) => package.clone(),
+                    None => return None,
+                }
+            }
+            None => self.prefixed(document.package.package.clone()),
+        };
+
+        let mut decls = env.decl_iter().filter(|d| d.name().package.package == package);
+
+        if name.relative {
+            return decls.find(|d| d.name().parts.ends_with(&name.parts));
+        }
+
+        decls.find(|d| d.name().parts == name.parts)
+    }
+
+    fn definition(&self, params: Value) -> Result<Option<Location>> {
+        let params: TextDocumentPositionParams = serde_json::from_value(params)?;
+        let uri = params.text_document.uri;
+
+        let document = match self.documents.get(&uri) {
+            Some(document) => document,
+            None => return Ok(None),
+        };
+
+        let decl = match self.find_decl(document, &params.position) {
+            Some(decl) => decl,
+            None => return Ok(None),
+        };
+
+        let pos = decl.pos();
+
+        // declarations without a path are declared in the document itself.
+        let path = match pos.object.path() {
+            Some(path) => path.to_owned(),
+            None => {
+                return Ok(Some(Location {
+                    uri: uri.clone(),
+                    range: range(&document.text, pos.start, pos.end),
+                }));
+            }
+        };
+
+        let mut text = String::new();
+        File::open(&path)?.read_to_string(&mut text)?;
+
+        let uri = Url::from_file_path(&path)
+            .map_err(|_| format!("not an absolute path: {}", path.display()))?;
+
+        Ok(Some(Location {
+            uri: uri.to_string(),
+            range: range(&text, pos.start, pos.end),
+        }))
+    }
+
+    fn hover(&self, params: Value) -> Result<Option<Hover>> {
+        let params: TextDocumentPositionParams = serde_json::from_value(params)?;
+
+        let document = match self.documents.get(&params.text_document.uri) {
+            Some(document) => document,
+            None => return Ok(None),
+        };
+
+        let decl = match self.find_decl(document, &params.position) {
+            Some(decl) => decl,
+            None => return Ok(None),
+        };
+
+        let name = decl.name();
+
+        let mut value = String::new();
+        value.push_str("```reproto\n");
+        value.push_str(&format!("{} {}\n", decl.kind(), name.parts.join("::")));
+        value.push_str("```\n");
+        value.push_str(&format!("\nIn package `{}`.\n", name.package));
+
+        if !decl.comment().is_empty() {
+            value.push('\n');
+
+            for line in decl.comment() {
+                value.push_str(line.trim());
+                value.push('\n');
+            }
+        }
+
+        let offset = offset(&document.text, &params.position);
+
+        Ok(Some(Hover {
+            contents: MarkupContent {
+                kind: "markdown",
+                value: value,
+            },
+            range: range(&document.text, offset, offset),
+        }))
+    }
+
+    fn completion(&self, params: Value) -> Result<Vec<CompletionItem>> {
+        let params: TextDocumentPositionParams = serde_json::from_value(params)?;
+
+        let mut items = Vec::new();
+
+        for keyword in KEYWORDS {
+            items.push(CompletionItem {
+                label: keyword.to_string(),
+                kind: COMPLETION_KEYWORD,
+                detail: None,
+                documentation: None,
+            });
+        }
+
+        for option in OPTIONS {
+            items.push(CompletionItem {
+                label: option.to_string(),
+                kind: COMPLETION_PROPERTY,
+                detail: Some("option".to_string()),
+                documentation: None,
+            });
+        }
+
+        let document = match self.documents.get(&params.text_document.uri) {
+            Some(document) => document,
+            None => return Ok(items),
+        };
+
+        let env = match document.env {
+            Some(ref env) => env,
+            None => return Ok(items),
+        };
+
+        let package = self.prefixed(document.package.package.clone());
+
+        for decl in env.decl_iter() {
+            let name = decl.name();
+
+            let label = if name.package.package == package {
+                name.parts.join("::")
+            } else {
+                let alias = document.prefixes.iter().find(|&(_, p)| *p == name.package.package);
+
+                match alias {
+                    Some((alias, _)) => format!("{}::{}", alias, name.parts.join("::")),
+                    None => continue,
+                }
+            };
+
+            let kind = match *decl.value() {
+                RpDecl::Type(_) => COMPLETION_CLASS,
+                RpDecl::Tuple(_) => COMPLETION_STRUCT,
+                RpDecl::Interface(_) => COMPLETION_INTERFACE,
+                RpDecl::Enum(_) => COMPLETION_ENUM,
+                RpDecl::Service(_) => COMPLETION_MODULE,
+            };
+
+            let documentation = if decl.comment().is_empty() {
+                None
+            } else {
+                Some(decl.comment().join("\n"))
+            };
+
+            items.push(CompletionItem {
+                label: label,
+                kind: kind,
+                detail: Some(format!("{} in {}", decl.kind(), name.package)),
+                documentation: documentation,
+            });
+        }
+
+        Ok(items)
+    }
+
+    fn document_symbol(&self, params: Value) -> Result<Vec<SymbolInformation>> {
+        let params: DocumentSymbolParams = serde_json::from_value(params)?;
+        let uri = params.text_document.uri;
+
+        let document = match self.documents.get(&uri) {
+            Some(document) => document,
+            None => return Ok(Vec::new()),
+        };
+
+        let object: Rc<Box<Object>> = Rc::new(Box::new(document.object(&uri)));
+
+        // symbols are only available for documents which parse.
+        let file = match parser::parse_string(object, &document.text) {
+            Ok(file) => file,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let mut symbols = Vec::new();
+
+        {
+            let mut symbol = |name: &str, kind: u32, pos: &core::Pos, container: Option<&str>| {
+                symbols.push(SymbolInformation {
+                    name: name.to_string(),
+                    kind: kind,
+                    location: Location {
+                        uri: uri.clone(),
+                        range: range(&document.text, pos.start, pos.end),
+                    },
+                    container_name: container.map(ToOwned::to_owned),
+                });
+            };
+
+            for decl in &file.decls {
+                decl_symbols(&mut symbol, decl.value(), None);
+            }
+        }
+
+        return Ok(symbols);
+
+        fn decl_symbols<S>(symbol: &mut S, decl: &ast::Decl, container: Option<&str>)
+        where
+            S: FnMut(&str, u32, &core::Pos, Option<&str>),
+        {
+            let name = match container {
+                Some(container) => format!("{}::{}", container, decl.name()),
+                None => decl.name().to_string(),
+            };
+
+            let inner = Some(name.as_str());
+
+            match *decl {
+                ast::Decl::Type(ref body) => {
+                    symbol(body.name, SYMBOL_CLASS, body.pos(), container);
+                    member_symbols(symbol, &body.members, &name);
+                }
+                ast::Decl::Tuple(ref body) => {
+                    symbol(body.name, SYMBOL_STRUCT, body.pos(), container);
+                    member_symbols(symbol, &body.members, &name);
+                }
+                ast::Decl::Interface(ref body) => {
+                    symbol(body.name, SYMBOL_INTERFACE, body.pos(), container);
+                    member_symbols(symbol, &body.members, &name);
+
+                    for sub_type in &body.sub_types {
+                        symbol(*sub_type.name, SYMBOL_CLASS, sub_type.pos(), inner);
+                        let sub_name = format!("{}::{}", name, *sub_type.name);
+                        member_symbols(symbol, &sub_type.members, &sub_name);
+                    }
+                }
+                ast::Decl::Enum(ref body) => {
+                    symbol(body.name, SYMBOL_ENUM, body.pos(), container);
+
+                    for variant in &body.variants {
+                        symbol(*variant.name, SYMBOL_ENUM_MEMBER, variant.pos(), inner);
+                    }
+
+                    member_symbols(symbol, &body.members, &name);
+                }
+                ast::Decl::Service(ref body) => {
+                    symbol(body.name, SYMBOL_MODULE, body.pos(), container);
+
+                    for endpoint in &body.endpoints {
+                        symbol(*endpoint.id, SYMBOL_METHOD, endpoint.pos(), inner);
+                    }
+                }
+            }
+        }
+
+        fn member_symbols<S>(symbol: &mut S, members: &[Loc<ast::Member>], container: &str)
+        where
+            S: FnMut(&str, u32, &core::Pos, Option<&str>),
+        {
+            for member in members {
+                match *member.value() {
+                    ast::Member::Field(ref field) => {
+                        symbol(field.name, SYMBOL_FIELD, member.pos(), Some(container));
+                    }
+                    ast::Member::InnerDecl(ref decl) => {
+                        decl_symbols(symbol, decl, Some(container));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn publish_diagnostics(uri: String, diagnostics: Vec<Diagnostic>) -> Result<Value> {
+    let params = PublishDiagnosticsParams {
+        uri: uri,
+        diagnostics: diagnostics,
+    };
+
+    Ok(json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": serde_json::to_value(params)?,
+    }))
+}
+
+fn log_message(message: String) -> Result<Value> {
+    let params = LogMessageParams {
+        message_type: MESSAGE_ERROR,
+        message: message,
+    };
+
+    Ok(json!({
+        "jsonrpc": "2.0",
+        "method": "window/logMessage",
+        "params": serde_json::to_value(params)?,
+    }))
+}
+
+/// Convert a byte offset into a position.
+fn position(text: &str, offset: usize) -> Position {
+    let mut line = 0u64;
+    let mut character = 0u64;
+
+    for (i, c) in text.char_indices() {
+        if i >= offset {
+            break;
+        }
+
+        if c == '\n' {
+            line += 1;
+            character = 0;
+        } else {
+            character += c.len_utf16() as u64;
+        }
+    }
+
+    Position {
+        line: line,
+        character: character,
+    }
+}
+
+/// Convert a position into a byte offset.
+///
+/// Positions past the end of a line are clamped to the end of the line.
+fn offset(text: &str, position: &Position) -> usize {
+    let mut line = 0u64;
+    let mut character = 0u64;
+
+    for (i, c) in text.char_indices() {
+        if line == position.line {
+            if character >= position.character || c == '\n' {
+                return i;
+            }
+
+            character += c.len_utf16() as u64;
+        } else if c == '\n' {
+            line += 1;
+        }
+    }
+
+    text.len()
+}
+
+fn range(text: &str, start: usize, end: usize) -> Range {
+    Range {
+        start: position(text, start),
+        end: position(text, end),
+    }
+}
+
+/// Find the name referenced at the given offset.
+///
+/// Only the part of the name up until the component under the offset is included, so that
+/// `Foo` in `Foo::Bar` refers to the outer declaration.
+fn name_at(text: &str, offset: usize) -> Option<NameRef> {
+    fn is_name(c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || c == ':'
+    }
+
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_name(c))
+        .last()
+        .map(|(i, _)| i)
+        .unwrap_or(offset);
+
+    let end = text[offset..]
+        .char_indices()
+        .find(|&(_, c)| !is_name(c))
+        .map(|(i, _)| offset + i)
+        .unwrap_or(text.len());
+
+    let mut name = &text[start..end];
+
+    if let Some(n) = name[offset - start..].find("::") {
+        name = &name[..offset - start + n];
+    }
+
+    let relative = name.starts_with("::");
+
+    let mut parts: Vec<&str> = name.trim_left_matches(':').split("::").collect();
+
+    let prefix = match parts.first() {
+        Some(first) if !relative && first.starts_with(|c: char| c.is_lowercase()) => {
+            Some(first.to_string())
+        }
+        _ => None,
+    };
+
+    if prefix.is_some() {
+        parts.remove(0);
+    }
+
+    let valid = !parts.is_empty() &&
+        parts.iter().all(|p| p.starts_with(|c: char| c.is_uppercase()));
+
+    if !valid {
+        return None;
+    }
+
+    Some(NameRef {
+        prefix: prefix,
+        parts: parts.into_iter().map(ToOwned::to_owned).collect(),
+        relative: relative,
+    })
+}
+
+/// Collects errors and warnings with their positions, by acting as the output of errors.
+struct Collector {
+    errors: RefCell<Vec<(u32, String, Option<ErrorPos>)>>,
+}
+
+impl Collector {
+    fn new() -> Collector {
+        Collector { errors: RefCell::new(Vec::new()) }
+    }
+
+    fn collect(&self, e: &Error) -> Result<()> {
+        if !self.handle_error(e)? {
+            self.print_root_error(e)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Logger which discards everything, since logs are never part of diagnostics.
+struct NoopLogger;
+
+impl log::Log for NoopLogger {
+    fn enabled(&self, _: &log::LogMetadata) -> bool {
+        false
+    }
+
+    fn log(&self, _: &log::LogRecord) {}
+}
+
+impl Output for Collector {
+    fn logger(&self) -> Box<log::Log + 'static> {
+        Box::new(NoopLogger)
+    }
+
+    fn print(&self, m: &str) -> Result<()> {
+        self.errors.borrow_mut().push(
+            (DIAGNOSTIC_ERROR, m.to_string(), None),
+        );
+        Ok(())
+    }
+
+    fn print_error(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        self.errors.borrow_mut().push((
+            DIAGNOSTIC_ERROR,
+            m.to_string(),
+            Some(p.clone_error_pos()),
+        ));
+        Ok(())
+    }
+
+    fn print_warning(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        self.errors.borrow_mut().push((
+            DIAGNOSTIC_WARNING,
+            m.to_string(),
+            Some(p.clone_error_pos()),
+        ));
+        Ok(())
+    }
+
+    fn print_root_error(&self, e: &Error) -> Result<()> {
+        self.errors.borrow_mut().push(
+            (DIAGNOSTIC_ERROR, e.to_string(), None),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use repository::Resolvers;
+    use std::io::Cursor;
+    use std::str;
+
+    const URI: &str = "file:///specs/test.reproto";
+
+    const TEXT: &str = "/// A foo.\ntype Foo {\n  bar: Bar;\n}\n\ntype Bar {\n}\n";
+
+    /// Run a session with the given messages, and return every message sent by the server.
+    fn session<F>(new_env: F, messages: Vec<Value>) -> (Result<()>, Vec<Value>)
+    where
+        F: Fn() -> Result<Environment>,
+    {
+        let mut input = Vec::new();
+
+        for message in &messages {
+            transport::write_message(&mut input, message).unwrap();
+        }
+
+        session_input(new_env, input)
+    }
+
+    /// Run a session with the given raw input, and return every message sent by the server.
+    fn session_input<F>(new_env: F, input: Vec<u8>) -> (Result<()>, Vec<Value>)
+    where
+        F: Fn() -> Result<Environment>,
+    {
+        let mut output = Vec::new();
+
+        let result = run(
+            new_env,
+            None,
+            Vec::new(),
+            BTreeMap::new(),
+            Cursor::new(input),
+            &mut output,
+        );
+
+        let mut sent = Vec::new();
+        let mut rest = output.as_slice();
+
+        while !rest.is_empty() {
+            let end = rest.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+            let header = str::from_utf8(&rest[..end]).unwrap();
+            let length: usize = header.trim_left_matches("Content-Length: ").parse().unwrap();
+            let start = end + 4;
+            sent.push(serde_json::from_slice(&rest[start..start + length]).unwrap());
+            rest = &rest[start + length..];
+        }
+
+        (result, sent)
+    }
+
+    fn empty_env() -> Result<Environment> {
+        Ok(Environment::new(None, Box::new(Resolvers::new(Vec::new()))))
+    }
+
+    fn request(id: u64, method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+    }
+
+    fn notification(method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "method": method, "params": params})
+    }
+
+    fn at(line: u64, character: u64) -> Value {
+        json!({"textDocument": {"uri": URI}, "position": {"line": line, "character": character}})
+    }
+
+    #[test]
+    fn test_session() {
+        let (result, sent) = session(
+            empty_env,
+            vec![
+                request(1, "initialize", json!({})),
+                notification("initialized", json!({})),
+                notification(
+                    "textDocument/didOpen",
+                    json!({"textDocument": {"uri": URI, "text": TEXT}}),
+                ),
+                request(2, "textDocument/definition", at(2, 8)),
+                request(3, "textDocument/hover", at(1, 6)),
+                request(4, "textDocument/documentSymbol", json!({"textDocument": {"uri": URI}})),
+                request(5, "shutdown", Value::Null),
+                notification("exit", Value::Null),
+            ],
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(6, sent.len());
+
+        assert_eq!(json!(1), sent[0]["id"]);
+        assert_eq!(json!(true), sent[0]["result"]["capabilities"]["hoverProvider"]);
+
+        assert_eq!(json!("textDocument/publishDiagnostics"), sent[1]["method"]);
+        assert_eq!(json!([]), sent[1]["params"]["diagnostics"]);
+
+        assert_eq!(json!(2), sent[2]["id"]);
+        assert_eq!(json!(URI), sent[2]["result"]["uri"]);
+        assert_eq!(json!(5), sent[2]["result"]["range"]["start"]["line"]);
+
+        assert_eq!(json!(3), sent[3]["id"]);
+        let hover = sent[3]["result"]["contents"]["value"].as_str().unwrap();
+        assert!(hover.contains("type Foo"));
+        assert!(hover.contains("A foo."));
+
+        assert_eq!(json!(4), sent[4]["id"]);
+
+        let symbols: Vec<&str> = sent[4]["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["name"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(vec!["Foo", "bar", "Bar"], symbols);
+
+        assert_eq!(json!({"jsonrpc": "2.0", "id": 5, "result": null}), sent[5]);
+    }
+
+    #[test]
+    fn test_session_errors() {
+        let failing_env = || -> Result<Environment> { Err("bad manifest".into()) };
+
+        let (result, sent) = session(
+            failing_env,
+            vec![
+                notification("textDocument/didOpen", json!({"textDocument": {}})),
+                notification(
+                    "textDocument/didOpen",
+                    json!({"textDocument": {"uri": URI, "text": TEXT}}),
+                ),
+                request(1, "shutdown", Value::Null),
+                notification("exit", Value::Null),
+            ],
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(3, sent.len());
+
+        assert_eq!(json!("window/logMessage"), sent[0]["method"]);
+        assert_eq!(json!(MESSAGE_ERROR), sent[0]["params"]["type"]);
+
+        assert_eq!(json!("textDocument/publishDiagnostics"), sent[1]["method"]);
+
+        assert_eq!(
+            json!("bad manifest"),
+            sent[1]["params"]["diagnostics"][0]["message"]
+        );
+
+        assert_eq!(json!(1), sent[2]["id"]);
+    }
+
+    #[test]
+    fn test_session_parse_errors() {
+        let mut input = Vec::new();
+
+        for content in &["{\"method\": ", "{\"method\": 42}"] {
+            write!(input, "Content-Length: {}\r\n\r\n{}", content.len(), content).unwrap();
+        }
+
+        let messages = vec![
+            request(1, "shutdown", Value::Null),
+            notification("exit", Value::Null),
+        ];
+
+        for message in &messages {
+            transport::write_message(&mut input, message).unwrap();
+        }
+
+        let (result, sent) = session_input(empty_env, input);
+
+        assert!(result.is_ok());
+        assert_eq!(3, sent.len());
+
+        assert_eq!(Value::Null, sent[0]["id"]);
+        assert_eq!(json!(PARSE_ERROR), sent[0]["error"]["code"]);
+        assert_eq!(Value::Null, sent[1]["id"]);
+        assert_eq!(json!(PARSE_ERROR), sent[1]["error"]["code"]);
+
+        assert_eq!(json!({"jsonrpc": "2.0", "id": 1, "result": null}), sent[2]);
+    }
+
+    #[test]
+    fn test_position_offset() {
+        let text = "type Foo {\n  a: string;\n  \u{e5}: u32;\n}\n";
+
+        for offset in vec![0, 5, 11, 13, 25, 26, 28, text.len()] {
+            assert_eq!(offset, super::offset(text, &position(text, offset)));
+        }
+
+        // `å` is two bytes, but one UTF-16 code unit.
+        assert_eq!(
+            Position {
+                line: 2,
+                character: 3,
+            },
+            position(text, 28)
+        );
+    }
+
+    #[test]
+    fn test_offset_past_end_of_line() {
+        let text = "a\nbc\n";
+
+        let position = Position {
+            line: 0,
+            character: 10,
+        };
+
+        assert_eq!(1, offset(text, &position));
+    }
+
+    #[test]
+    fn test_name_at() {
+        let text = "  foo: c::Foo::Bar;";
+
+        let name = |prefix: Option<&str>, parts: Vec<&str>, relative: bool| {
+            Some(NameRef {
+                prefix: prefix.map(ToOwned::to_owned),
+                parts: parts.into_iter().map(ToOwned::to_owned).collect(),
+                relative: relative,
+            })
+        };
+
+        assert_eq!(name(Some("c"), vec!["Foo"], false), name_at(text, 12));
+        assert_eq!(name(Some("c"), vec!["Foo", "Bar"], false), name_at(text, 16));
+        assert_eq!(name(None, vec!["Bar"], true), name_at("a: ::Bar;", 6));
+        assert_eq!(name(None, vec!["Foo"], false), name_at("a: [Foo];", 5));
+        assert_eq!(None, name_at(text, 3));
+    }
+}
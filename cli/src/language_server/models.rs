@@ -0,0 +1,166 @@
+//! The subset of language server protocol types used by the server.
+
+use serde_json::Value;
+
+pub const TEXT_DOCUMENT_SYNC_FULL: u32 = 1;
+
+pub const DIAGNOSTIC_ERROR: u32 = 1;
+pub const DIAGNOSTIC_WARNING: u32 = 2;
+
+pub const MESSAGE_ERROR: u32 = 1;
+
+pub const COMPLETION_CLASS: u32 = 7;
+pub const COMPLETION_INTERFACE: u32 = 8;
+pub const COMPLETION_MODULE: u32 = 9;
+pub const COMPLETION_PROPERTY: u32 = 10;
+pub const COMPLETION_ENUM: u32 = 13;
+pub const COMPLETION_KEYWORD: u32 = 14;
+pub const COMPLETION_STRUCT: u32 = 22;
+
+pub const SYMBOL_MODULE: u32 = 2;
+pub const SYMBOL_CLASS: u32 = 5;
+pub const SYMBOL_METHOD: u32 = 6;
+pub const SYMBOL_FIELD: u32 = 8;
+pub const SYMBOL_ENUM: u32 = 10;
+pub const SYMBOL_INTERFACE: u32 = 11;
+pub const SYMBOL_ENUM_MEMBER: u32 = 22;
+pub const SYMBOL_STRUCT: u32 = 23;
+
+/// Error code for messages which couldn't be decoded.
+pub const PARSE_ERROR: i64 = -32700;
+/// Error code for requests of unsupported methods.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// Error code for requests which failed.
+pub const INTERNAL_ERROR: i64 = -32603;
+
+/// An incoming request or notification.
+///
+/// Notifications have no id, and responses to requests sent by the server have no method.
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    pub id: Option<Value>,
+    pub method: Option<String>,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    /// Zero-based line.
+    pub line: u64,
+    /// Zero-based character in the line, counted in UTF-16 code units.
+    pub character: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentIdentifier {
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentItem {
+    pub uri: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+    pub text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: u32,
+    pub source: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublishDiagnosticsParams {
+    pub uri: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogMessageParams {
+    #[serde(rename = "type")]
+    pub message_type: u32,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MarkupContent {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Hover {
+    pub contents: MarkupContent,
+    pub range: Range,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInformation {
+    pub name: String,
+    pub kind: u32,
+    pub location: Location,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_name: Option<String>,
+}
@@ -0,0 +1,57 @@
+//! Framing of messages, as described by the base protocol.
+//!
+//! Every message is a JSON document preceded by a `Content-Length` header and an empty line.
+
+use errors::*;
+use serde_json::{self, Value};
+use std::io::{BufRead, Read, Write};
+
+/// Read the content of the next message.
+///
+/// Returns `None` when the input is closed. The content is not decoded, since a message which
+/// isn't valid JSON is answered with an error instead of ending the session.
+pub fn read_content<R: BufRead>(input: &mut R) -> Result<Option<Vec<u8>>> {
+    let mut content_length: Option<usize> = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let header = line.trim_right();
+
+        if header.is_empty() {
+            break;
+        }
+
+        let mut parts = header.splitn(2, ':');
+
+        if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            if name.trim().to_lowercase() == "content-length" {
+                let value = value.trim().parse::<usize>().map_err(|_| {
+                    format!("bad content length: {}", value.trim())
+                })?;
+
+                content_length = Some(value);
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| "missing content length")?;
+
+    let mut content = vec![0u8; content_length];
+    input.read_exact(&mut content)?;
+    Ok(Some(content))
+}
+
+/// Write a single message.
+pub fn write_message<W: Write>(output: &mut W, message: &Value) -> Result<()> {
+    let content = serde_json::to_vec(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n", content.len())?;
+    output.write_all(&content)?;
+    output.flush()?;
+    Ok(())
+}
@@ -6,8 +6,11 @@ extern crate serde_derive;
 extern crate error_chain;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate serde_json;
 
 extern crate ansi_term;
+extern crate serde;
 extern crate clap;
 extern crate reproto_backend as backend;
 extern crate reproto_backend_doc as doc;
@@ -16,6 +19,7 @@ extern crate reproto_backend_js as js;
 extern crate reproto_backend_json as json;
 extern crate reproto_backend_python as python;
 extern crate reproto_backend_rust as rust;
+extern crate reproto_backend_typescript as typescript;
 extern crate reproto_core as core;
 extern crate reproto_semck as semck;
 extern crate reproto_manifest as manifest;
@@ -27,5 +31,7 @@ extern crate relative_path;
 
 pub mod ops;
 pub mod config;
+pub mod import;
+pub mod language_server;
 pub mod errors;
 pub mod output;
@@ -1,5 +1,8 @@
 use super::imports::*;
+use super::watch::watch;
 use manifest::Language;
+use output::Output;
+use std::path::PathBuf;
 
 pub fn options<'a, 'b>() -> App<'a, 'b> {
     let out = SubCommand::with_name("build").about("Build specifications");
@@ -13,27 +16,80 @@ pub fn options<'a, 'b>() -> App<'a, 'b> {
     out
 }
 
-pub fn entry(matches: &ArgMatches) -> Result<()> {
-    use manifest::Language::*;
-
-    let preamble = manifest_preamble(matches)?;
-
-    let language = preamble
-        .language
-        .as_ref()
-        .cloned()
-        .or_else(|| matches.value_of("lang").and_then(Language::parse))
-        .ok_or_else(|| {
-            "no language specified either through manifest or cli (--lang)"
-        })?;
-
-    match language {
-        Java => manifest_compile::<::java::JavaLang, _>(matches, preamble, ::java::compile),
-        Js => manifest_compile::<::js::JsLang, _>(matches, preamble, ::js::compile),
-        Json => manifest_compile::<::json::JsonLang, _>(matches, preamble, ::json::compile),
-        Python => manifest_compile::<::python::PythonLang, _>(matches, preamble, ::python::compile),
-        Rust => manifest_compile::<::rust::RustLang, _>(matches, preamble, ::rust::compile),
-    }?;
-
-    Ok(())
+pub fn entry(matches: &ArgMatches, output: &Output) -> Result<()> {
+    return watch(matches, output, |watched| build(matches, output, watched));
+
+    fn build(matches: &ArgMatches, output: &Output, watched: &mut Vec<PathBuf>) -> Result<()> {
+        use manifest::Language::*;
+
+        let preamble = manifest_preamble(matches)?;
+
+        let language = preamble
+            .language
+            .as_ref()
+            .cloned()
+            .or_else(|| matches.value_of("lang").and_then(Language::parse))
+            .ok_or_else(|| {
+                "no language specified either through manifest or cli (--lang)"
+            })?;
+
+        match language {
+            Java => {
+                manifest_compile::<::java::JavaLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::java::compile,
+                )
+            }
+            Js => {
+                manifest_compile::<::js::JsLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::js::compile,
+                )
+            }
+            Json => {
+                manifest_compile::<::json::JsonLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::json::compile,
+                )
+            }
+            Python => {
+                manifest_compile::<::python::PythonLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::python::compile,
+                )
+            }
+            Rust => {
+                manifest_compile::<::rust::RustLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::rust::compile,
+                )
+            }
+            TypeScript => {
+                manifest_compile::<::typescript::TypeScriptLang, _>(
+                    matches,
+                    preamble,
+                    output,
+                    watched,
+                    ::typescript::compile,
+                )
+            }
+        }?;
+
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
 use super::imports::*;
-use super::setup_environment;
+use super::{lint_environment, setup_environment};
 use core::Version;
 use manifest::{Lang, Manifest};
+use output::Output;
 
 pub fn options<'a, 'b>() -> App<'a, 'b> {
     let out = SubCommand::with_name("check").about("Check specifications");
@@ -18,15 +19,18 @@ pub fn options<'a, 'b>() -> App<'a, 'b> {
     out
 }
 
-pub fn entry(matches: &ArgMatches) -> Result<()> {
+pub fn entry(matches: &ArgMatches, output: &Output) -> Result<()> {
     let preamble = manifest_preamble(matches)?;
-    return do_manifest_use!(matches, preamble, inner);
+    return do_manifest_use!(matches, preamble, |matches, manifest| {
+        inner(matches, manifest, output)
+    });
 
-    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>, output: &Output) -> Result<()>
     where
         L: Lang,
     {
         let mut env = setup_environment(&manifest)?;
+        lint_environment(&manifest, &env, output)?;
 
         let mut manifest_resolver = setup_path_resolver(&manifest)?.ok_or_else(|| {
             "could not setup manifest resolver"
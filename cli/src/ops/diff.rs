@@ -0,0 +1,550 @@
+//! Compare two versions of specifications, and print the differences between them.
+
+use super::imports::*;
+use super::{SemckLookup, setup_environment};
+use core::{BytesObject, Object, PathObject, RpChannel, RpPackage, RpPackageFormat,
+           RpVersionedPackage, Version};
+use manifest::{Lang, Manifest};
+use repository::{Paths, Repository};
+use semck::{self, Change, ChangeKind, Violation};
+use serde_json;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+const EXT: &str = "reproto";
+
+/// Files to compare, and the versions that they are loaded as.
+type Files = BTreeMap<RpPackage, (Option<Version>, Box<Object>)>;
+
+/// Differences of a single package.
+enum PackageDiff {
+    Added(RpPackage),
+    Removed(RpPackage),
+    Changed(RpPackage, Vec<Change>),
+}
+
+impl PackageDiff {
+    fn package(&self) -> &RpPackage {
+        match *self {
+            PackageDiff::Added(ref package) |
+            PackageDiff::Removed(ref package) |
+            PackageDiff::Changed(ref package, _) => package,
+        }
+    }
+}
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("diff")
+        .about("Print the differences between two versions of specifications");
+
+    let out = out.arg(
+        Arg::with_name("package")
+            .long("package")
+            .takes_value(true)
+            .help(
+                "Package of the compared file (or prefix of the compared directory), or the \
+                 published package to compare versions of",
+            ),
+    );
+
+    let out = out.arg(
+        Arg::with_name("git")
+            .long("git")
+            .takes_value(true)
+            .help("Compare a file or directory between two git revisions"),
+    );
+
+    let out = out.arg(
+        Arg::with_name("format")
+            .long("format")
+            .takes_value(true)
+            .possible_values(&["human", "json"])
+            .default_value("human")
+            .help("Format to print differences in"),
+    );
+
+    let out = out.arg(Arg::with_name("from").required(true).help(
+        "File, directory, published version, or git revision (with --git) to compare from",
+    ));
+
+    let out = out.arg(Arg::with_name("to").required(true).help(
+        "File, directory, published version, or git revision (with --git) to compare to",
+    ));
+
+    out
+}
+
+/// Run git, and return what it wrote to standard output.
+fn git(args: &[&str]) -> Result<Vec<u8>> {
+    let output = Command::new("git").args(args).output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git: {}", stderr.trim()).into());
+    }
+
+    Ok(output.stdout)
+}
+
+/// Build the package of a specification, from its path relative to the compared directory.
+///
+/// Versioned files (`<last>-<version>.reproto`) are skipped.
+fn relative_package(prefix: &RpPackage, relative: &Path) -> Option<RpPackage> {
+    if relative.extension() != Some(OsStr::new(EXT)) {
+        return None;
+    }
+
+    let stem = match relative.file_stem().and_then(OsStr::to_str) {
+        Some(stem) if !stem.contains('-') => stem,
+        _ => return None,
+    };
+
+    let mut package = prefix.clone();
+
+    if let Some(parent) = relative.parent() {
+        for part in parent.iter() {
+            package = package.join_part(part.to_str()?);
+        }
+    }
+
+    Some(package.join_part(stem))
+}
+
+/// Collect the files of a file or directory in the given git revision.
+fn git_files(path: &Path, revision: &str, package: Option<&RpPackage>) -> Result<Files> {
+    let mut files = Files::new();
+
+    let path_str = path.to_str().ok_or_else(|| format!("illegal path: {}", path.display()))?;
+    let listing = git(&["ls-tree", "-r", "--name-only", revision, "--", path_str])?;
+    let listing = String::from_utf8_lossy(&listing);
+
+    // paths listed by git are relative to the current directory, without leading `./`.
+    let base: PathBuf = path.components().filter(|c| *c != Component::CurDir).collect();
+
+    for name in listing.lines() {
+        let relative = Path::new(name);
+
+        let package = if relative == base.as_path() {
+            package.cloned().unwrap_or_else(RpPackage::empty)
+        } else {
+            let prefix = package.cloned().unwrap_or_else(RpPackage::empty);
+
+            match relative_package(&prefix, relative.strip_prefix(&base).unwrap_or(relative)) {
+                Some(package) => package,
+                None => continue,
+            }
+        };
+
+        let bytes = git(&["show", &format!("{}:./{}", revision, name)])?;
+        let object = BytesObject::new(format!("{}:{}", revision, name), Arc::new(bytes));
+        files.insert(package, (None, Box::new(object)));
+    }
+
+    if files.is_empty() {
+        return Err(format!("no specifications in {} at {}", path.display(), revision).into());
+    }
+
+    Ok(files)
+}
+
+/// Collect the files of a local file or directory.
+fn path_files(path: &Path, package: Option<&RpPackage>) -> Result<Files> {
+    let mut files = Files::new();
+    let prefix = package.cloned().unwrap_or_else(RpPackage::empty);
+
+    if path.is_file() {
+        files.insert(prefix, (None, Box::new(PathObject::new(None, path))));
+        return Ok(files);
+    }
+
+    if !path.is_dir() {
+        return Err(format!("no such file or directory: {}", path.display()).into());
+    }
+
+    let paths = Paths::new(vec![path.to_owned()], HashMap::new());
+
+    for resolved in paths.find_by_prefix(path, &prefix)? {
+        files.insert(resolved.package, (None, resolved.object));
+    }
+
+    Ok(files)
+}
+
+/// Collect the file of a published version of a package.
+fn published_files(
+    repository: &mut Repository,
+    package: &RpPackage,
+    version: &str,
+) -> Result<Files> {
+    let version = Version::parse(version).map_err(|e| {
+        format!("bad version `{}`: {}", version, e)
+    })?;
+
+    let deployment = repository
+        .all(package)?
+        .into_iter()
+        .find(|d| d.version == version)
+        .ok_or_else(|| {
+            format!("not published: {}", RpPackageFormat(package, Some(&version)))
+        })?;
+
+    let object = repository.get_object(&deployment)?.ok_or_else(|| {
+        format!("No object found for deployment: {:?}", deployment)
+    })?;
+
+    let name = RpPackageFormat(package, Some(&version)).to_string();
+
+    let mut files = Files::new();
+    files.insert(package.clone(), (Some(version), object.with_name(name)));
+    Ok(files)
+}
+
+/// Compare the files of every package.
+fn diff_packages(env: &mut Environment, from: Files, mut to: Files) -> Result<Vec<PackageDiff>> {
+    let mut out = Vec::new();
+
+    for (package, (from_version, from_object)) in from {
+        let (to_version, to_object) = match to.remove(&package) {
+            Some(to) => to,
+            None => {
+                out.push(PackageDiff::Removed(package));
+                continue;
+            }
+        };
+
+        let package_from = RpVersionedPackage::new(package.clone(), from_version);
+        let file_from = env.load_object(from_object, &package_from)?;
+
+        let package_to = RpVersionedPackage::new(package.clone(), to_version);
+        let file_to = env.load_object(to_object, &package_to)?;
+
+        let changes = semck::diff(&file_from, &file_to, &SemckLookup(env))?;
+        out.push(PackageDiff::Changed(package, changes));
+    }
+
+    out.extend(to.into_iter().map(|(package, _)| PackageDiff::Added(package)));
+    out.sort_by(|a, b| a.package().cmp(b.package()));
+    Ok(out)
+}
+
+/// Describe a single difference.
+fn describe(violation: &Violation) -> String {
+    use self::Violation::*;
+
+    fn channel(channel: &Option<RpChannel>) -> String {
+        channel
+            .as_ref()
+            .map(|c| format!("`{}`", c))
+            .unwrap_or_else(|| "nothing".to_string())
+    }
+
+    match *violation {
+        DeclRemoved(..) => "declaration removed".to_string(),
+        DeclAdded(..) => "declaration added".to_string(),
+        RemoveField(..) => "field removed".to_string(),
+        RemoveVariant(..) => "variant removed".to_string(),
+        AddField(..) => "field added".to_string(),
+        AddVariant(..) => "variant added".to_string(),
+        FieldTypeChange(_, ref from, _, ref to, _) => {
+            format!("type changed from `{}` to `{}`", from, to)
+        }
+        FieldNameChange(_, ref from, _, ref to, _) => {
+            format!("name changed from `{}` to `{}`", from, to)
+        }
+        VariantOrdinalChange(_, ref from, _, ref to, _) => {
+            format!("ordinal changed from `{}` to `{}`", from, to)
+        }
+        FieldRequiredChange(..) => "field made required".to_string(),
+        AddRequiredField(..) => "required field added".to_string(),
+        FieldModifierChange(..) => "field modifier changed".to_string(),
+        FieldConstraintChange(_, ref name, _, _) => format!("constraint `{}` changed", name),
+        FieldDefaultChange(..) => "default value changed".to_string(),
+        AddEndpoint(..) => "endpoint added".to_string(),
+        RemoveEndpoint(..) => "endpoint removed".to_string(),
+        EndpointRequestChange(_, ref from, _, ref to, _) => {
+            format!("request changed from {} to {}", channel(from), channel(to))
+        }
+        EndpointResponseChange(_, ref from, _, ref to, _) => {
+            format!("response changed from {} to {}", channel(from), channel(to))
+        }
+        EndpointOptionChange(_, ref name, _, _) => format!("option `{}` changed", name),
+        RemoveSubType(..) => "sub-type removed".to_string(),
+        SubTypeNamesChange(_, ref from, _, ref to, _) => {
+            format!("names changed from {:?} to {:?}", from, to)
+        }
+        TypeInfoChange(_, ref from, _, ref to, _) => {
+            format!("type info changed from `{}` to `{}`", from, to)
+        }
+        EnumTypeChange(_, ref from, _, ref to, _) => {
+            format!("enum type changed from `{}` to `{}`", from, to)
+        }
+        TupleFieldMoved(_, ref name, from, _, to, _) => {
+            format!("field `{}` moved from position {} to {}", name, from, to)
+        }
+        TupleFieldInserted(..) => "field inserted before existing fields".to_string(),
+        TupleArityChange(_, from, _, to, _) => {
+            format!("number of fields changed from {} to {}", from, to)
+        }
+        Transitive(ref chain, ref violation) => {
+            let chain: Vec<String> = chain.iter().map(|&(ref n, _)| format!("`{}`", n)).collect();
+            format!("{} (through {})", describe(violation), chain.join(" -> "))
+        }
+    }
+}
+
+fn print_human<W: Write>(out: &mut W, packages: &[PackageDiff]) -> Result<()> {
+    let mut empty = true;
+
+    for package in packages {
+        match *package {
+            PackageDiff::Added(ref package) => writeln!(out, "+ package {}", package)?,
+            PackageDiff::Removed(ref package) => writeln!(out, "- package {}", package)?,
+            PackageDiff::Changed(ref package, ref changes) => {
+                if changes.is_empty() {
+                    continue;
+                }
+
+                writeln!(out, "~ package {}", package)?;
+
+                for change in changes {
+                    let marker = match change.kind {
+                        ChangeKind::Added => "+",
+                        ChangeKind::Removed => "-",
+                        ChangeKind::Changed => "~",
+                    };
+
+                    writeln!(
+                        out,
+                        "  {} {} {} ({})",
+                        marker,
+                        change.item.describe(),
+                        change.name,
+                        change.bump.describe()
+                    )?;
+
+                    // additions and removals are described by the marker.
+                    if change.kind == ChangeKind::Changed {
+                        for violation in &change.violations {
+                            writeln!(out, "      {}", describe(violation))?;
+                        }
+                    }
+                }
+            }
+        }
+
+        empty = false;
+    }
+
+    if empty {
+        writeln!(out, "no differences")?;
+    }
+
+    Ok(())
+}
+
+fn print_json<W: Write>(out: &mut W, packages: &[PackageDiff]) -> Result<()> {
+    let packages: Vec<serde_json::Value> = packages
+        .iter()
+        .map(|package| match *package {
+            PackageDiff::Added(ref package) => {
+                json!({"package": package.to_string(), "kind": "added"})
+            }
+            PackageDiff::Removed(ref package) => {
+                json!({"package": package.to_string(), "kind": "removed"})
+            }
+            PackageDiff::Changed(ref package, ref changes) => {
+                let changes: Vec<serde_json::Value> = changes
+                    .iter()
+                    .map(|change| {
+                        let details: Vec<String> = change.violations.iter().map(describe).collect();
+
+                        json!({
+                            "kind": change.kind.describe(),
+                            "item": change.item.describe(),
+                            "name": change.name,
+                            "bump": change.bump.describe(),
+                            "details": details,
+                        })
+                    })
+                    .collect();
+
+                json!({"package": package.to_string(), "kind": "changed", "changes": changes})
+            }
+        })
+        .collect();
+
+    serde_json::to_writer_pretty(&mut *out, &json!({ "packages": packages }))?;
+    writeln!(out, "")?;
+    Ok(())
+}
+
+pub fn entry(matches: &ArgMatches) -> Result<()> {
+    let preamble = manifest_preamble(matches)?;
+    return do_manifest_use!(matches, preamble, inner);
+
+    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    where
+        L: Lang,
+    {
+        let from = matches.value_of("from").ok_or_else(|| "missing <from>")?;
+        let to = matches.value_of("to").ok_or_else(|| "missing <to>")?;
+        let package = matches.value_of("package").map(RpPackage::parse);
+
+        let mut env = setup_environment(&manifest)?;
+
+        let (from_files, to_files) = if let Some(path) = matches.value_of("git") {
+            let path = Path::new(path);
+            (
+                git_files(path, from, package.as_ref())?,
+                git_files(path, to, package.as_ref())?,
+            )
+        } else if Path::new(from).exists() || Path::new(to).exists() {
+            (
+                path_files(Path::new(from), package.as_ref())?,
+                path_files(Path::new(to), package.as_ref())?,
+            )
+        } else {
+            let package = package.ok_or_else(|| {
+                "--package is required to compare published versions"
+            })?;
+
+            let mut repository = setup_repository(&manifest)?;
+
+            (
+                published_files(&mut repository, &package, from)?,
+                published_files(&mut repository, &package, to)?,
+            )
+        };
+
+        let packages = diff_packages(&mut env, from_files, to_files)?;
+
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+
+        match matches.value_of("format") {
+            Some("json") => print_json(&mut out, &packages),
+            _ => print_human(&mut out, &packages),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{ErrorPos, RpType};
+    use semck::{Bump, Component, Item};
+
+    fn pos() -> ErrorPos {
+        let object = BytesObject::new("test".to_string(), Arc::new(vec![]));
+
+        ErrorPos {
+            object: Box::new(object),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    fn packages() -> Vec<PackageDiff> {
+        let changes = vec![
+            Change {
+                kind: ChangeKind::Changed,
+                item: Item::Field,
+                name: "Foo.a".to_string(),
+                bump: Bump::Major,
+                violations: vec![
+                    Violation::FieldTypeChange(
+                        Component::Minor,
+                        RpType::String,
+                        pos(),
+                        RpType::Bytes,
+                        pos(),
+                    ),
+                    Violation::FieldModifierChange(Component::Patch, pos(), pos()),
+                ],
+            },
+            Change {
+                kind: ChangeKind::Added,
+                item: Item::Decl,
+                name: "Bar".to_string(),
+                bump: Bump::Minor,
+                violations: vec![Violation::DeclAdded(Component::Patch, pos())],
+            },
+        ];
+
+        vec![
+            PackageDiff::Added(RpPackage::parse("added")),
+            PackageDiff::Changed(RpPackage::parse("changed"), changes),
+            PackageDiff::Changed(RpPackage::parse("same"), vec![]),
+            PackageDiff::Removed(RpPackage::parse("removed")),
+        ]
+    }
+
+    #[test]
+    fn test_print_human() {
+        let mut out = Vec::new();
+        print_human(&mut out, &packages()).unwrap();
+
+        let expected = "+ package added
+~ package changed
+  ~ field Foo.a (major)
+      type changed from `string` to `bytes`
+      field modifier changed
+  + declaration Bar (minor)
+- package removed
+";
+
+        assert_eq!(expected, String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_print_human_empty() {
+        let mut out = Vec::new();
+        print_human(&mut out, &[PackageDiff::Changed(RpPackage::parse("same"), vec![])]).unwrap();
+        assert_eq!("no differences\n", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_print_json() {
+        let mut out = Vec::new();
+        print_json(&mut out, &packages()).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        let expected = json!({
+            "packages": [
+                {"package": "added", "kind": "added"},
+                {
+                    "package": "changed",
+                    "kind": "changed",
+                    "changes": [
+                        {
+                            "kind": "changed",
+                            "item": "field",
+                            "name": "Foo.a",
+                            "bump": "major",
+                            "details": [
+                                "type changed from `string` to `bytes`",
+                                "field modifier changed",
+                            ],
+                        },
+                        {
+                            "kind": "added",
+                            "item": "declaration",
+                            "name": "Bar",
+                            "bump": "minor",
+                            "details": ["declaration added"],
+                        },
+                    ],
+                },
+                {"package": "same", "kind": "changed", "changes": []},
+                {"package": "removed", "kind": "removed"},
+            ],
+        });
+
+        assert_eq!(expected, value);
+    }
+}
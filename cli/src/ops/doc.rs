@@ -2,21 +2,34 @@
 
 use super::{setup_compiler_options, setup_environment};
 use super::imports::*;
+use super::watch::{manifest_paths, watch};
 use manifest::{Lang, Manifest};
+use output::Output;
+use std::path::PathBuf;
 
 pub fn options<'a, 'b>() -> App<'a, 'b> {
     ::doc::shared_options(SubCommand::with_name("doc").about("Generate documentation"))
 }
 
-pub fn entry(matches: &ArgMatches) -> Result<()> {
-    let preamble = manifest_preamble(matches)?;
+pub fn entry(matches: &ArgMatches, output: &Output) -> Result<()> {
+    return watch(matches, output, |watched| {
+        let preamble = manifest_preamble(matches)?;
 
-    return do_manifest_use!(matches, preamble, inner);
+        do_manifest_use!(matches, preamble, |matches, manifest| {
+            inner(matches, manifest, watched)
+        })
+    });
 
-    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    fn inner<L>(
+        matches: &ArgMatches,
+        manifest: Manifest<L>,
+        watched: &mut Vec<PathBuf>,
+    ) -> Result<()>
     where
         L: Lang,
     {
+        watched.extend(manifest_paths(&manifest));
+
         let env = setup_environment(&manifest)?;
         let options = setup_options(&manifest)?;
         let compiler_options = setup_compiler_options(&manifest, matches)?;
@@ -0,0 +1,116 @@
+//! Formatting of specifications into the canonical layout.
+
+use super::imports::*;
+use core::{Object, PathObject};
+use manifest::{Lang, Manifest};
+use parser;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+const EXT: &str = "reproto";
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("fmt").about("Format specifications");
+
+    let out = out.arg(Arg::with_name("check").long("check").help(
+        "Check that specifications are formatted instead of rewriting them",
+    ));
+
+    let out = out.arg(Arg::with_name("file").multiple(true).help(
+        "Files or directories to format, defaults to the paths of the manifest",
+    ));
+
+    out
+}
+
+pub fn entry(matches: &ArgMatches) -> Result<()> {
+    let preamble = manifest_preamble(matches)?;
+    return do_manifest_use!(matches, preamble, inner);
+
+    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    where
+        L: Lang,
+    {
+        let paths: Vec<PathBuf> = match matches.values_of("file") {
+            Some(files) => files.map(PathBuf::from).collect(),
+            None => manifest.paths.clone(),
+        };
+
+        let mut files = Vec::new();
+
+        for path in &paths {
+            collect_files(&mut files, path)?;
+        }
+
+        let check = matches.is_present("check");
+        let mut errors: Vec<Error> = Vec::new();
+
+        for file in files {
+            let mut content = String::new();
+            File::open(&file)?.read_to_string(&mut content)?;
+
+            let object: Rc<Box<Object>> = Rc::new(Box::new(PathObject::new(None, &file)));
+
+            let formatted = match parser::format_string(object, &content) {
+                Ok(formatted) => formatted,
+                Err(e) => {
+                    errors.push(e.into());
+                    continue;
+                }
+            };
+
+            if formatted == content {
+                continue;
+            }
+
+            if check {
+                errors.push(ErrorKind::File("not formatted".to_string(), file).into());
+                continue;
+            }
+
+            File::create(&file)?.write_all(formatted.as_bytes())?;
+            info!("Formatted: {}", file.display());
+        }
+
+        if !errors.is_empty() {
+            return Err(ErrorKind::Errors(errors).into());
+        }
+
+        Ok(())
+    }
+
+    /// Collect all specifications in the given path, recursing into directories.
+    fn collect_files(files: &mut Vec<PathBuf>, path: &Path) -> Result<()> {
+        if path.is_file() {
+            files.push(path.to_owned());
+            return Ok(());
+        }
+
+        if !path.is_dir() {
+            let message = "no such file or directory".to_string();
+            return Err(ErrorKind::File(message, path.to_owned()).into());
+        }
+
+        let mut entries = fs::read_dir(path)?
+            .map(|e| e.map(|e| e.path()))
+            .collect::<::std::result::Result<Vec<_>, _>>()?;
+
+        // stable order, so that errors are reported consistently.
+        entries.sort();
+
+        for entry in entries {
+            if entry.is_dir() {
+                collect_files(files, &entry)?;
+                continue;
+            }
+
+            if entry.extension().map(|ext| ext == EXT).unwrap_or(false) {
+                files.push(entry);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Import action that converts JSON Schema and OpenAPI documents into specifications.
+
+use core::RpPackage;
+use ops::imports::*;
+use serde_json;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("import")
+        .about("Import a JSON Schema or OpenAPI document as a specification");
+
+    let out = out.arg(
+        Arg::with_name("package")
+            .long("package")
+            .short("p")
+            .takes_value(true)
+            .required(true)
+            .help("Package to import the document into"),
+    );
+
+    let out = out.arg(
+        Arg::with_name("out")
+            .long("out")
+            .short("o")
+            .takes_value(true)
+            .help("Directory to write the specification to, defaults to standard output"),
+    );
+
+    let out = out.arg(
+        Arg::with_name("file")
+            .required(true)
+            .help("JSON Schema or OpenAPI document to import (JSON)"),
+    );
+
+    out
+}
+
+pub fn entry(matches: &ArgMatches) -> Result<()> {
+    let file = Path::new(matches.value_of("file").ok_or_else(|| "missing <file>")?);
+    let package = matches.value_of("package").ok_or_else(|| "missing --package")?;
+    let package = RpPackage::parse(package);
+
+    let document: serde_json::Value = serde_json::from_reader(File::open(file)?)
+        .map_err(|e| ErrorKind::File(e.to_string(), file.to_owned()))?;
+
+    let out = ::import::import(&document)?;
+
+    let path = match matches.value_of("out") {
+        Some(path) => package.parts.iter().fold(
+            Path::new(path).to_owned(),
+            |path, part| path.join(part),
+        ),
+        None => {
+            io::stdout().write_all(out.as_bytes())?;
+            return Ok(());
+        }
+    };
+
+    let path = path.with_extension("reproto");
+
+    if let Some(parent) = path.parent() {
+        if !parent.is_dir() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    File::create(&path)?.write_all(out.as_bytes())?;
+    info!("Wrote: {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Language server, speaking the language server protocol over stdio.
+
+use super::imports::*;
+use super::setup_resolvers;
+use language_server;
+use manifest::{Lang, Manifest};
+use std::io;
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("language-server").about("Run a language server over stdio")
+}
+
+pub fn entry(matches: &ArgMatches) -> Result<()> {
+    let preamble = manifest_preamble(matches)?;
+    return do_manifest_use!(matches, preamble, inner);
+
+    fn inner<L>(_: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    where
+        L: Lang,
+    {
+        let new_env = || -> Result<Environment> {
+            let resolvers = setup_resolvers(&manifest)?;
+            Ok(Environment::new(manifest.package_prefix.clone(), resolvers))
+        };
+
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+
+        language_server::run(
+            new_env,
+            manifest.package_prefix.clone(),
+            manifest.paths.clone(),
+            manifest.lints.clone(),
+            stdin.lock(),
+            stdout.lock(),
+        )
+    }
+}
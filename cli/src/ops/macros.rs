@@ -26,6 +26,9 @@ macro_rules! do_manifest_use {
             Some(::manifest::Language::Rust) => {
                 ::ops::manifest_use::<::rust::RustLang, _>($matches, $preamble, $fn)
             }
+            Some(::manifest::Language::TypeScript) => {
+                ::ops::manifest_use::<::typescript::TypeScriptLang, _>($matches, $preamble, $fn)
+            }
             None => ::ops::manifest_use::<::manifest::NoLang, _>($matches, $preamble, $fn),
         }
     }};
@@ -3,24 +3,32 @@ mod macros;
 mod build;
 mod doc;
 mod config_env;
+mod diff;
+mod fmt;
+mod import;
 mod imports;
+mod language_server;
 mod publish;
 mod repo;
+mod semck;
 mod update;
 mod check;
+mod watch;
 
 use self::config_env::ConfigEnv;
 use self::imports::*;
 use backend;
 use backend::{CamelCase, FromNaming, Naming, SnakeCase};
-use core::{Object, RpPackage, RpPackageFormat, RpVersionedPackage, Version};
+use backend::lint;
+use core::{LintLevel, Object, Pos, RpFile, RpName, RpPackage, RpPackageFormat, RpReg,
+           RpVersionedPackage, Version};
 use manifest::{Lang, Manifest, ManifestFile, ManifestPreamble, Publish, TryFromToml,
                read_manifest, read_manifest_preamble, self as m};
+use output::Output;
 use relative_path::RelativePath;
 use repository::{Index, IndexConfig, NoIndex, NoObjects, Objects, ObjectsConfig, Paths,
                  Repository, Resolved, ResolvedByPrefix, Resolver, Resolvers, index_from_path,
                  index_from_url, objects_from_path, objects_from_url};
-use semck;
 use std::collections::HashMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
@@ -122,6 +130,8 @@ pub fn base_args<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
 pub fn build_args<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
     let out = base_args(out);
 
+    let out = out.arg(watch::watch_arg());
+
     let out = out.arg(
         Arg::with_name("package")
             .long("package")
@@ -428,6 +438,42 @@ where
     Ok(env)
 }
 
+/// Lint the specifications of the manifest, and report the lints to the given output.
+///
+/// Only specifications which are part of the manifest are linted, not its dependencies.
+pub fn lint_environment<L>(manifest: &Manifest<L>, env: &Environment, output: &Output) -> Result<()>
+where
+    L: Lang,
+{
+    let include = |pos: &Pos| {
+        let path = match pos.object.path() {
+            Some(path) => path,
+            None => return false,
+        };
+
+        manifest.paths.iter().any(|p| path.starts_with(p)) ||
+            manifest.files.iter().any(|f| path == f.path.as_path())
+    };
+
+    let lints = lint::lint(env, &manifest.lints, include)?;
+
+    let mut denied = 0;
+
+    for l in &lints {
+        if l.level == LintLevel::Deny {
+            denied += 1;
+        }
+
+        output.lint(l)?;
+    }
+
+    if denied > 0 {
+        return Err(ErrorKind::DeniedLints(denied).into());
+    }
+
+    Ok(())
+}
+
 pub fn options<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
     let out = out.subcommand(build_args(build::options()));
     let out = out.subcommand(build_args(doc::options()));
@@ -435,6 +481,11 @@ pub fn options<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
     let out = out.subcommand(base_args(publish::options()));
     let out = out.subcommand(base_args(update::options()));
     let out = out.subcommand(base_args(repo::options()));
+    let out = out.subcommand(base_args(semck::options()));
+    let out = out.subcommand(base_args(diff::options()));
+    let out = out.subcommand(base_args(fmt::options()));
+    let out = out.subcommand(import::options());
+    let out = out.subcommand(base_args(language_server::options()));
     out
 }
 
@@ -588,37 +639,82 @@ where
     Ok(results)
 }
 
-pub fn semck_check(
-    errors: &mut Vec<Error>,
+/// Find the item with the latest version which is not a prerelease, and if `max` is specified,
+/// is not newer than it.
+fn semck_baseline<T, F>(items: Vec<T>, version: F, max: Option<&Version>) -> Option<T>
+where
+    F: Fn(&T) -> &Version,
+{
+    items
+        .into_iter()
+        .filter(|item| {
+            let v = version(item);
+            !v.is_prerelease() && max.map(|max| v <= max).unwrap_or(true)
+        })
+        .max_by(|a, b| version(a).cmp(version(b)))
+}
+
+/// Load the latest published version of a matched package, and the matched package itself.
+///
+/// If `max` is specified, only published versions which are not newer than it are considered.
+/// Returns `None` if no such version has been published.
+pub fn semck_files(
     repository: &mut Repository,
     env: &mut Environment,
     m: &Match,
-) -> Result<()> {
+    max: Option<&Version>,
+) -> Result<Option<(Version, RpFile, RpFile)>> {
     let Match(ref version, ref object, ref package) = *m;
 
-    // perform semck verification
-    if let Some(d) = repository
-        .all(package)?
-        .into_iter()
-        .filter(|d| d.version <= *version && !d.version.is_prerelease())
-        .last()
-    {
-        debug!("Checking semantics of {} -> {}", d.version, version);
+    let d = match semck_baseline(repository.all(package)?, |d| &d.version, max) {
+        Some(d) => d,
+        None => return Ok(None),
+    };
 
-        let previous = repository.get_object(&d)?.ok_or_else(|| {
-            format!("No object found for deployment: {:?}", d)
-        })?;
+    debug!("Checking semantics of {} -> {}", d.version, version);
+
+    let previous = repository.get_object(&d)?.ok_or_else(|| {
+        format!("No object found for deployment: {:?}", d)
+    })?;
 
-        let name = RpPackageFormat(package, Some(&d.version)).to_string();
-        let previous = previous.with_name(name);
+    let name = RpPackageFormat(package, Some(&d.version)).to_string();
+    let previous = previous.with_name(name);
 
-        let package_from = RpVersionedPackage::new(package.clone(), Some(d.version.clone()));
-        let file_from = env.load_object(previous, &package_from)?;
+    let package_from = RpVersionedPackage::new(package.clone(), Some(d.version.clone()));
+    let file_from = env.load_object(previous, &package_from)?;
 
-        let package_to = RpVersionedPackage::new(package.clone(), Some(version.clone()));
-        let file_to = env.load_object(object.clone_object(), &package_to)?;
+    let package_to = RpVersionedPackage::new(package.clone(), Some(version.clone()));
+    let file_to = env.load_object(object.clone_object(), &package_to)?;
 
-        let violations = semck::check((&d.version, &file_from), (&version, &file_to))?;
+    Ok(Some((d.version, file_from, file_to)))
+}
+
+/// Resolves declarations of other packages for semck through the environment.
+pub struct SemckLookup<'a>(pub &'a Environment);
+
+impl<'a> ::semck::Lookup for SemckLookup<'a> {
+    fn lookup(&self, name: &RpName) -> Option<&RpReg> {
+        self.0.lookup(name).ok()
+    }
+}
+
+pub fn semck_check(
+    errors: &mut Vec<Error>,
+    repository: &mut Repository,
+    env: &mut Environment,
+    m: &Match,
+) -> Result<()> {
+    let Match(ref version, _, _) = *m;
+
+    // perform semck verification
+    let files = semck_files(repository, env, m, Some(version))?;
+
+    if let Some((from_version, file_from, file_to)) = files {
+        let violations = ::semck::check(
+            (&from_version, &file_from),
+            (&version, &file_to),
+            &SemckLookup(env),
+        )?;
 
         if !violations.is_empty() {
             for (i, v) in violations.into_iter().enumerate() {
@@ -634,6 +730,8 @@ pub fn semck_check(
 pub fn manifest_compile<'a, L, F>(
     matches: &'a ArgMatches,
     preamble: ManifestPreamble,
+    output: &Output,
+    watched: &mut Vec<PathBuf>,
     compile: F,
 ) -> Result<()>
 where
@@ -646,7 +744,9 @@ where
            -> backend::errors::Result<()>,
 {
     let manifest = manifest::<L>(matches, preamble)?;
+    watched.extend(watch::manifest_paths(&manifest));
     let env = setup_environment(&manifest)?;
+    lint_environment(&manifest, &env, output)?;
     let options = setup_options(&manifest)?;
     let compiler_options = setup_compiler_options(&manifest, matches)?;
 
@@ -673,19 +773,49 @@ where
     Ok(())
 }
 
-pub fn entry(matches: &ArgMatches) -> Result<()> {
+pub fn entry(matches: &ArgMatches, output: &Output) -> Result<()> {
     let (name, matches) = matches.subcommand();
     let matches = matches.ok_or_else(|| "no subcommand")?;
 
     match name {
-        "build" => return build::entry(matches),
-        "check" => return check::entry(matches),
-        "doc" => return doc::entry(matches),
+        "build" => return build::entry(matches, output),
+        "check" => return check::entry(matches, output),
+        "doc" => return doc::entry(matches, output),
         "update" => return update::entry(matches),
         "publish" => return publish::entry(matches),
         "repo" => return repo::entry(matches),
+        "semck" => return semck::entry(matches),
+        "diff" => return diff::entry(matches),
+        "fmt" => return fmt::entry(matches),
+        "import" => return import::entry(matches),
+        "language-server" => return language_server::entry(matches),
         _ => {}
     }
 
     Err(format!("No such command: {}", name).into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    #[test]
+    fn test_semck_baseline() {
+        let published = vec![v("1.0.0"), v("1.2.0"), v("1.1.0"), v("1.3.0-alpha.1")];
+
+        // the latest release, even if it is newer than the current version.
+        assert_eq!(Some(v("1.2.0")), semck_baseline(published.clone(), |v| v, None));
+
+        // the latest release which is not newer than the checked version.
+        assert_eq!(
+            Some(v("1.1.0")),
+            semck_baseline(published.clone(), |v| v, Some(&v("1.1.5")))
+        );
+
+        assert_eq!(None, semck_baseline(published, |v| v, Some(&v("0.9.0"))));
+    }
+}
@@ -0,0 +1,134 @@
+//! Semantic version checking of the packages in a manifest.
+
+use super::imports::*;
+use super::{SemckLookup, semck_files, setup_environment};
+use core::{RpPackage, Version};
+use manifest::{Lang, Manifest, update_publish_versions};
+use semck;
+use std::fs::File;
+use std::io::{Read, Write};
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("semck").about("Check semantic versioning of specifications");
+
+    let out = out.arg(Arg::with_name("suggest").long("suggest").help(
+        "Print the smallest version that each package in [publish] can be published as",
+    ));
+
+    let out = out.arg(Arg::with_name("write").long("write").requires("suggest").help(
+        "Write suggested versions to the manifest",
+    ));
+
+    out
+}
+
+pub fn entry(matches: &ArgMatches) -> Result<()> {
+    let preamble = manifest_preamble(matches)?;
+    return do_manifest_use!(matches, preamble, inner);
+
+    fn inner<L>(matches: &ArgMatches, manifest: Manifest<L>) -> Result<()>
+    where
+        L: Lang,
+    {
+        let mut env = setup_environment(&manifest)?;
+
+        let mut manifest_resolver = setup_path_resolver(&manifest)?.ok_or_else(|| {
+            "could not setup manifest resolver"
+        })?;
+
+        let mut repository = setup_repository(&manifest)?;
+
+        if !matches.is_present("suggest") {
+            let mut errors = Vec::new();
+
+            for m in setup_publish_matches(manifest_resolver.as_mut(), None, &manifest.publish)? {
+                semck_check(&mut errors, &mut repository, &mut env, &m)?;
+            }
+
+            if errors.len() > 0 {
+                return Err(ErrorKind::Errors(errors).into());
+            }
+
+            return Ok(());
+        }
+
+        let mut updates: Vec<(RpPackage, Version)> = Vec::new();
+
+        for publish in &manifest.publish {
+            let mut suggested: Option<Version> = None;
+
+            // every package covered by the entry is published with the same version.
+            for m in setup_publish_matches(manifest_resolver.as_mut(), None, Some(publish))? {
+                // suggestions are relative to the latest release, even if it is newer than the
+                // version currently in the manifest.
+                let version = match semck_files(&mut repository, &mut env, &m, None)? {
+                    Some((from_version, file_from, file_to)) => {
+                        let lookup = SemckLookup(&env);
+                        semck::suggest((&from_version, &file_from), &file_to, &lookup)?
+                    }
+                    // nothing published yet, any version is permitted.
+                    None => publish.version.clone(),
+                };
+
+                if suggested.as_ref().map(|s| version > *s).unwrap_or(true) {
+                    suggested = Some(version);
+                }
+            }
+
+            let suggested = match suggested {
+                Some(suggested) => suggested,
+                None => continue,
+            };
+
+            let suggested = match suggested_update(&publish.version, suggested) {
+                Some(suggested) => suggested,
+                None => {
+                    info!("{}: {}", publish.package, publish.version);
+                    continue;
+                }
+            };
+
+            info!("{}: {} (currently {})", publish.package, suggested, publish.version);
+            updates.push((publish.package.clone(), suggested));
+        }
+
+        if matches.is_present("write") && !updates.is_empty() {
+            let mut content = String::new();
+            File::open(&manifest.path)?.read_to_string(&mut content)?;
+
+            let content = update_publish_versions(&content, &updates)?;
+            File::create(&manifest.path)?.write_all(content.as_bytes())?;
+
+            info!("Updated: {}", manifest.path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// The version to update to, if the suggested version is newer than the current one.
+///
+/// Suggesting a version that is older than the current one would downgrade the manifest.
+fn suggested_update(current: &Version, suggested: Version) -> Option<Version> {
+    if suggested > *current {
+        Some(suggested)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    #[test]
+    fn test_suggested_update() {
+        assert_eq!(Some(v("1.1.0")), suggested_update(&v("1.0.0"), v("1.1.0")));
+        assert_eq!(None, suggested_update(&v("1.0.0"), v("1.0.0")));
+        assert_eq!(None, suggested_update(&v("2.0.0"), v("1.1.0")));
+    }
+}
@@ -0,0 +1,186 @@
+//! Watching of specifications, to run a task again every time they change.
+
+use super::MANIFEST_NAME;
+use super::imports::*;
+use manifest::{Lang, Manifest};
+use output::Output;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const EXT: &str = "reproto";
+
+/// How often, in milliseconds, watched paths are checked for changes.
+const POLL_INTERVAL: u64 = 500;
+
+/// Modification time and length of a file, or `None` if it does not exist.
+///
+/// The length catches changes made within the resolution of modification times.
+type Stamp = Option<(SystemTime, u64)>;
+
+pub fn watch_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("watch").long("watch").help(
+        "Run again every time the manifest or a specification changes",
+    )
+}
+
+/// Paths that the given manifest depends on.
+pub fn manifest_paths<L>(manifest: &Manifest<L>) -> Vec<PathBuf>
+where
+    L: Lang,
+{
+    let mut paths = Vec::new();
+    paths.push(manifest.path.clone());
+    paths.extend(manifest.paths.iter().cloned());
+    paths.extend(manifest.files.iter().map(|f| f.path.clone()));
+    paths
+}
+
+/// Run the given task once, or if `--watch` is present, every time a path it depends on changes.
+///
+/// The task registers the paths it depends on as they become known, so that a task which fails
+/// is still run again once the problem has been fixed.
+/// While watching, errors are reported to the output instead of stopping the watch.
+pub fn watch<F>(matches: &ArgMatches, output: &Output, mut task: F) -> Result<()>
+where
+    F: FnMut(&mut Vec<PathBuf>) -> Result<()>,
+{
+    if !matches.is_present("watch") {
+        return task(&mut Vec::new());
+    }
+
+    let manifest_path = matches
+        .value_of("manifest-path")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(MANIFEST_NAME));
+
+    let mut paths = vec![manifest_path.clone()];
+
+    loop {
+        // taken before the task runs, so that changes made while it is running are not missed.
+        let before = snapshot(&paths);
+
+        paths = vec![manifest_path.clone()];
+
+        if let Err(e) = task(&mut paths) {
+            if !output.handle_error(&e)? {
+                output.print_root_error(&e)?;
+            }
+        }
+
+        // paths which were first registered by the task can only be compared from now on.
+        let last: BTreeMap<_, _> = snapshot(&paths)
+            .into_iter()
+            .map(|(path, stamp)| {
+                let stamp = before.get(&path).cloned().unwrap_or(stamp);
+                (path, stamp)
+            })
+            .collect();
+
+        info!("Watching for changes...");
+
+        while snapshot(&paths) == last {
+            thread::sleep(Duration::from_millis(POLL_INTERVAL));
+        }
+    }
+}
+
+/// Stamps of all relevant files in the given paths.
+///
+/// Files which do not exist are included without a stamp, so that creating them is detected as a
+/// change.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, Stamp> {
+    let mut out = BTreeMap::new();
+
+    for path in paths {
+        if path.is_dir() {
+            walk(&mut out, path);
+        } else {
+            out.insert(path.clone(), stamp(path));
+        }
+    }
+
+    return out;
+
+    /// Collect all specifications in a directory, recursively.
+    fn walk(out: &mut BTreeMap<PathBuf, Stamp>, path: &Path) {
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for entry in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if entry.is_dir() {
+                walk(out, &entry);
+                continue;
+            }
+
+            if entry.extension().map(|ext| ext == EXT).unwrap_or(false) {
+                let stamp = stamp(&entry);
+                out.insert(entry, stamp);
+            }
+        }
+    }
+
+    fn stamp(path: &Path) -> Stamp {
+        fs::metadata(path)
+            .and_then(|m| Ok((m.modified()?, m.len())))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::File;
+    use std::io::Write;
+    use std::process;
+
+    fn write(path: &Path, content: &str) {
+        File::create(path)
+            .and_then(|mut f| f.write_all(content.as_bytes()))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("reproto-test-snapshot-{}", process::id()));
+
+        if dir.is_dir() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+
+        let specs = dir.join("foo");
+        fs::create_dir_all(&specs).unwrap();
+
+        let spec = specs.join("bar.reproto");
+        let other = specs.join("bar.txt");
+
+        write(&spec, "type Bar {}");
+        write(&other, "");
+
+        let paths = vec![dir.clone()];
+        let first = snapshot(&paths);
+
+        assert_eq!(vec![spec.clone()], first.keys().cloned().collect::<Vec<_>>());
+
+        write(&other, "ignored");
+        assert_eq!(first, snapshot(&paths));
+
+        // changes the length, since modification times might only have a resolution of a second.
+        write(&spec, "type Bar { a: string; }");
+        let second = snapshot(&paths);
+        assert_ne!(first, second);
+
+        let new = specs.join("baz.reproto");
+        write(&new, "type Baz {}");
+        let third = snapshot(&paths);
+        assert_ne!(second, third);
+        assert!(third.contains_key(&new));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,5 @@
 use super::{LockableWrite, Output, find_line};
-use ansi_term::Colour::{Blue, Red};
+use ansi_term::Colour::{self, Blue, Red, Yellow};
 use core::ErrorPos;
 use errors::*;
 use log;
@@ -14,21 +14,12 @@ impl<T> Colored<T> {
     }
 }
 
-impl<T> Output for Colored<T>
+impl<T> Colored<T>
 where
-    T: 'static + LockableWrite,
+    T: LockableWrite,
 {
-    fn logger(&self) -> Box<log::Log + 'static> {
-        Box::new(ColoredLogger { out: self.out.open_new() })
-    }
-
-    fn print(&self, m: &str) -> Result<()> {
-        let mut o = self.out.lock();
-        writeln!(o, "ERROR: {}", Red.paint(m.as_ref()))?;
-        Ok(())
-    }
-
-    fn print_error(&self, m: &str, p: &ErrorPos) -> Result<()> {
+    /// Print a message pointing to the given position, highlighted with the given colour.
+    fn print_positioned(&self, m: &str, p: &ErrorPos, colour: Colour) -> Result<()> {
         use std::iter::repeat;
         use std::cmp::max;
 
@@ -48,13 +39,36 @@ where
         writeln!(
             o,
             "{}{}{}",
-            Red.paint(indicator),
-            Red.paint(" - "),
-            Red.paint(m.as_ref())
+            colour.paint(indicator),
+            colour.paint(" - "),
+            colour.paint(m.as_ref())
         )?;
 
         Ok(())
     }
+}
+
+impl<T> Output for Colored<T>
+where
+    T: 'static + LockableWrite,
+{
+    fn logger(&self) -> Box<log::Log + 'static> {
+        Box::new(ColoredLogger { out: self.out.open_new() })
+    }
+
+    fn print(&self, m: &str) -> Result<()> {
+        let mut o = self.out.lock();
+        writeln!(o, "ERROR: {}", Red.paint(m.as_ref()))?;
+        Ok(())
+    }
+
+    fn print_error(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        self.print_positioned(m, p, Red)
+    }
+
+    fn print_warning(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        self.print_positioned(m, p, Yellow)
+    }
 
     fn print_root_error(&self, e: &Error) -> Result<()> {
         use ansi_term::Colour::Red;
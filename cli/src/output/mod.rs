@@ -4,6 +4,7 @@ mod non_colored;
 pub use self::colored::Colored;
 pub use self::non_colored::NonColored;
 use backend;
+use backend::lint::Lint;
 use core;
 use errors::*;
 use log;
@@ -32,6 +33,16 @@ impl LockableWrite for io::Stdout {
     }
 }
 
+impl LockableWrite for io::Stderr {
+    fn open_new(&self) -> Self {
+        io::stderr()
+    }
+
+    fn lock<'a>(&'a self) -> Box<Write + 'a> {
+        Box::new(self.lock())
+    }
+}
+
 const NL: u8 = '\n' as u8;
 
 fn find_line<'a, R: AsMut<Read + 'a>>(
@@ -175,6 +186,15 @@ pub trait Output {
                 self.print_error("previous name here", old)?;
                 true
             }
+            Errors(ref errors) => {
+                for e in errors {
+                    if !self.handle_backend_error(e.kind())? {
+                        self.print(&e.to_string())?;
+                    }
+                }
+
+                true
+            }
             _ => false,
         };
 
@@ -211,6 +231,15 @@ pub trait Output {
                 self.print_error(message, pos)?;
                 true
             }
+            Errors(ref errors) => {
+                for e in errors {
+                    if !self.handle_parser_error(e.kind())? {
+                        self.print(&e.to_string())?;
+                    }
+                }
+
+                true
+            }
             _ => false,
         };
 
@@ -269,7 +298,7 @@ pub trait Output {
         Ok(out)
     }
 
-    fn semck_violation(&self, _index: usize, violation: &Violation) -> Result<()> {
+    fn semck_violation(&self, index: usize, violation: &Violation) -> Result<()> {
         use self::Violation::*;
 
         match *violation {
@@ -386,6 +415,29 @@ pub trait Output {
 
                 self.print_error("from here", from)?;
             }
+            FieldDefaultChange(ref c, ref from, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: field default value changed",
+                        c.describe(),
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error("from here", from)?;
+            }
+            FieldConstraintChange(ref c, ref name, ref from, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: field constraint `{}` changed",
+                        c.describe(),
+                        name,
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error("from here", from)?;
+            }
             AddEndpoint(ref c, ref pos) => {
                 self.print_error(
                     format!("{}: endpoint added", c.describe()).as_str(),
@@ -430,17 +482,132 @@ pub trait Output {
                     from,
                 )?;
             }
+            EndpointOptionChange(ref c, ref name, ref from, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: endpoint option `{}` changed",
+                        c.describe(),
+                        name
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error("from here", from)?;
+            }
+            RemoveSubType(ref c, ref pos) => {
+                self.print_error(
+                    format!("{}: sub-type removed", c.describe()).as_str(),
+                    pos,
+                )?;
+            }
+            SubTypeNamesChange(ref c, ref from_names, ref from, ref to_names, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: sub-type names changed to `{}`",
+                        c.describe(),
+                        to_names.join(", ")
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error(
+                    format!("from `{}`", from_names.join(", ")).as_str(),
+                    from,
+                )?;
+            }
+            TypeInfoChange(ref c, ref from_type_info, ref from, ref to_type_info, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: sub-type information changed to `{}`",
+                        c.describe(),
+                        to_type_info
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error(
+                    format!("from `{}`", from_type_info).as_str(),
+                    from,
+                )?;
+            }
+            EnumTypeChange(ref c, ref from_type, ref from, ref to_type, ref to) => {
+                self.print_error(
+                    format!("{}: enum type changed to `{}`", c.describe(), to_type).as_str(),
+                    to,
+                )?;
+
+                self.print_error(format!("from `{}`", from_type).as_str(), from)?;
+            }
+            TupleFieldMoved(ref c, ref name, from_index, ref from, to_index, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: tuple field `{}` moved to position {}",
+                        c.describe(),
+                        name,
+                        to_index
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error(
+                    format!("from position {}", from_index).as_str(),
+                    from,
+                )?;
+            }
+            TupleFieldInserted(ref c, ref pos) => {
+                self.print_error(
+                    format!(
+                        "{}: tuple field inserted before existing fields",
+                        c.describe()
+                    ).as_str(),
+                    pos,
+                )?;
+            }
+            TupleArityChange(ref c, from_len, ref from, to_len, ref to) => {
+                self.print_error(
+                    format!(
+                        "{}: number of tuple fields changed to {}",
+                        c.describe(),
+                        to_len
+                    ).as_str(),
+                    to,
+                )?;
+
+                self.print_error(format!("from {}", from_len).as_str(), from)?;
+            }
+            Transitive(ref chain, ref violation) => {
+                self.semck_violation(index, violation)?;
+
+                for &(ref name, ref pos) in chain.iter().rev() {
+                    self.print_error(
+                        format!("through reference to `{}`", name).as_str(),
+                        pos,
+                    )?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Report a lint, denied lints are reported as errors.
+    fn lint(&self, lint: &Lint) -> Result<()> {
+        let m = format!("{} [{}]", lint.message, lint.rule);
+
+        match lint.level {
+            core::LintLevel::Deny => self.print_error(&m, &lint.pos),
+            _ => self.print_warning(&m, &lint.pos),
+        }
+    }
+
     fn logger(&self) -> Box<log::Log + 'static>;
 
     fn print(&self, m: &str) -> Result<()>;
 
     fn print_error(&self, m: &str, p: &core::ErrorPos) -> Result<()>;
 
+    fn print_warning(&self, m: &str, p: &core::ErrorPos) -> Result<()>;
+
     fn print_root_error(&self, e: &Error) -> Result<()>;
 }
 
@@ -17,6 +17,34 @@ impl<T> NonColored<T> {
     }
 }
 
+impl<T> NonColored<T>
+where
+    T: LockableWrite,
+{
+    /// Print a message pointing to the given position.
+    fn print_positioned(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        use std::iter::repeat;
+        use std::cmp::max;
+
+        let mut o = self.out.lock();
+
+        let (line_str, line, (s, e)) = find_line(p.object.read()?, (p.start, p.end))?;
+
+        let line_no = format!("{:>3}:", line + 1);
+
+        let mut indicator = String::new();
+
+        indicator.extend(repeat(' ').take(line_no.len() + s + 1));
+        indicator.extend(repeat('^').take(max(1, e - s)));
+
+        writeln!(o, "{}:{}:{}-{}:", p.object, line + 1, s + 1, e + 1)?;
+        writeln!(o, "{} {}", line_no, line_str)?;
+        writeln!(o, "{}{}{}", indicator, " - ", m)?;
+
+        Ok(())
+    }
+}
+
 impl<T> log::Log for NonColoredLogger<T>
 where
     T: LockableWrite,
@@ -49,25 +77,11 @@ where
     }
 
     fn print_error(&self, m: &str, p: &ErrorPos) -> Result<()> {
-        use std::iter::repeat;
-        use std::cmp::max;
-
-        let mut o = self.out.lock();
-
-        let (line_str, line, (s, e)) = find_line(p.object.read()?, (p.start, p.end))?;
-
-        let line_no = format!("{:>3}:", line + 1);
-
-        let mut indicator = String::new();
-
-        indicator.extend(repeat(' ').take(line_no.len() + s + 1));
-        indicator.extend(repeat('^').take(max(1, e - s)));
-
-        writeln!(o, "{}:{}:{}-{}:", p.object, line + 1, s + 1, e + 1)?;
-        writeln!(o, "{} {}", line_no, line_str)?;
-        writeln!(o, "{}{}{}", indicator, " - ", m)?;
+        self.print_positioned(m, p)
+    }
 
-        Ok(())
+    fn print_warning(&self, m: &str, p: &ErrorPos) -> Result<()> {
+        self.print_positioned(&format!("warning: {}", m), p)
     }
 
     fn print_root_error(&self, e: &Error) -> Result<()> {
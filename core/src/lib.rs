@@ -15,8 +15,10 @@ pub extern crate semver;
 mod macros;
 mod as_loc;
 mod rp_code;
+mod rp_constraints;
 mod error_pos;
 mod for_each_loc;
+mod lint_level;
 mod loc;
 mod mime;
 mod object;
@@ -26,12 +28,14 @@ mod pos;
 mod rp_channel;
 mod rp_decl;
 mod rp_endpoint;
+mod rp_endpoint_http;
 mod rp_enum_body;
 mod rp_enum_type;
 mod rp_path_spec;
 mod rp_path_segment;
 mod rp_field;
 mod rp_file;
+mod rp_http_method;
 mod rp_interface_body;
 mod rp_modifier;
 mod rp_name;
@@ -46,6 +50,7 @@ mod rp_sub_type;
 mod rp_tuple_body;
 mod rp_type;
 mod rp_type_body;
+mod rp_type_info;
 mod rp_value;
 mod rp_variant;
 mod rp_versioned_package;
@@ -55,6 +60,7 @@ pub mod errors;
 
 pub use self::error_pos::ErrorPos;
 pub use self::for_each_loc::ForEachLoc;
+pub use self::lint_level::LintLevel;
 pub use self::loc::Loc;
 pub use self::mime::Mime;
 pub use self::object::{BytesObject, Object, PathObject};
@@ -63,13 +69,16 @@ pub use self::options::Options;
 pub use self::pos::Pos;
 pub use self::rp_channel::RpChannel;
 pub use self::rp_code::RpCode;
+pub use self::rp_constraints::RpConstraints;
 pub use self::rp_decl::RpDecl;
 pub use self::rp_endpoint::RpEndpoint;
+pub use self::rp_endpoint_http::RpEndpointHttp;
 pub use self::rp_enum_body::RpEnumBody;
 pub use self::rp_enum_ordinal::RpEnumOrdinal;
 pub use self::rp_enum_type::RpEnumType;
 pub use self::rp_field::RpField;
 pub use self::rp_file::RpFile;
+pub use self::rp_http_method::RpHttpMethod;
 pub use self::rp_interface_body::RpInterfaceBody;
 pub use self::rp_modifier::RpModifier;
 pub use self::rp_name::RpName;
@@ -86,8 +95,9 @@ pub use self::rp_sub_type::RpSubType;
 pub use self::rp_tuple_body::RpTupleBody;
 pub use self::rp_type::RpType;
 pub use self::rp_type_body::RpTypeBody;
+pub use self::rp_type_info::RpTypeInfo;
 pub use self::rp_value::RpValue;
-pub use self::rp_variant::RpVariant;
+pub use self::rp_variant::{RpVariant, RpVariantValue};
 pub use self::rp_versioned_package::RpVersionedPackage;
 pub use self::with_pos::WithPos;
 pub use semver::{Version, VersionReq};
@@ -0,0 +1,70 @@
+//! Model for constraints on the values of fields
+
+use super::RpNumber;
+
+/// Constraints on the value of a field, which are enforced when it is decoded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RpConstraints {
+    /// Smallest permitted value of a number.
+    pub min: Option<RpNumber>,
+    /// Largest permitted value of a number.
+    pub max: Option<RpNumber>,
+    /// Smallest permitted length of a string.
+    pub min_length: Option<usize>,
+    /// Largest permitted length of a string.
+    pub max_length: Option<usize>,
+    /// Regular expression that a string must match.
+    pub pattern: Option<String>,
+    /// Smallest permitted number of items in an array.
+    pub min_items: Option<usize>,
+    /// Largest permitted number of items in an array.
+    pub max_items: Option<usize>,
+    /// If a map must have at least one entry.
+    pub non_empty: bool,
+}
+
+impl RpConstraints {
+    /// Check if no constraints are present.
+    pub fn is_empty(&self) -> bool {
+        *self == RpConstraints::default()
+    }
+
+    /// Describe every present constraint, in the same form that it is declared.
+    pub fn describe(&self) -> Vec<String> {
+        let mut out = Vec::new();
+
+        if let Some(ref min) = self.min {
+            out.push(format!("min = {}", min));
+        }
+
+        if let Some(ref max) = self.max {
+            out.push(format!("max = {}", max));
+        }
+
+        if let Some(min_length) = self.min_length {
+            out.push(format!("min_length = {}", min_length));
+        }
+
+        if let Some(max_length) = self.max_length {
+            out.push(format!("max_length = {}", max_length));
+        }
+
+        if let Some(ref pattern) = self.pattern {
+            out.push(format!("pattern = {:?}", pattern));
+        }
+
+        if let Some(min_items) = self.min_items {
+            out.push(format!("min_items = {}", min_items));
+        }
+
+        if let Some(max_items) = self.max_items {
+            out.push(format!("max_items = {}", max_items));
+        }
+
+        if self.non_empty {
+            out.push("non_empty = true".to_string());
+        }
+
+        out
+    }
+}
@@ -1,6 +1,7 @@
 //! Model for endpoints
 
-use super::{Loc, RpChannel};
+use super::{Loc, Options, RpChannel, RpEndpointHttp, RpOptionDecl};
+use errors::*;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct RpEndpoint {
@@ -10,10 +11,14 @@ pub struct RpEndpoint {
     pub name: String,
     /// Comments for documentation.
     pub comment: Vec<String>,
+    /// Options specified for the endpoint.
+    pub options: Vec<Loc<RpOptionDecl>>,
     /// Request type that this endpoint expects.
     pub request: Option<Loc<RpChannel>>,
     /// Response type that this endpoint responds with.
     pub response: Option<Loc<RpChannel>>,
+    /// HTTP binding of the endpoint, if specified.
+    pub http: Option<RpEndpointHttp>,
 }
 
 impl RpEndpoint {
@@ -28,4 +33,10 @@ impl RpEndpoint {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Get the HTTP status that the endpoint responds with, if specified.
+    pub fn http_status(&self) -> Result<Option<u32>> {
+        let status = self.options.find_one_u32("http_status")?;
+        Ok(status.and_then(|s| s.value().to_u32()))
+    }
 }
@@ -0,0 +1,11 @@
+//! Model for HTTP bindings of endpoints
+
+use super::{Loc, RpHttpMethod, RpPathSpec};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RpEndpointHttp {
+    /// HTTP method used to invoke the endpoint.
+    pub method: Loc<RpHttpMethod>,
+    /// Path template, where variables are bound to fields in the request.
+    pub path: Loc<RpPathSpec>,
+}
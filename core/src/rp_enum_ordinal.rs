@@ -1,9 +1,13 @@
 //! Data Models for the final model stage stage.
 
+use super::RpNumber;
+
 #[derive(Debug, Clone, Serialize)]
 pub enum RpEnumOrdinal {
     /// Value is specified expliticly.
     String(String),
+    /// Numeric value, specified explicitly.
+    Number(RpNumber),
     /// Value is automatically derived from the name of the variant.
     Generated,
 }
@@ -1,11 +1,13 @@
 //! Model for enum types
 
-use super::{RpField, RpModifier, RpType, RpValue};
+use super::{RpConstraints, RpField, RpModifier, RpType, RpValue};
 use std::fmt;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum RpEnumType {
     String,
+    Signed { size: usize },
+    Unsigned { size: usize },
     Generated,
 }
 
@@ -15,6 +17,8 @@ impl RpEnumType {
 
         match (self, value) {
             (&String, &RpValue::String(_)) => true,
+            (&Signed { .. }, &RpValue::Number(_)) |
+            (&Unsigned { .. }, &RpValue::Number(_)) => self.as_type().is_assignable_from(value),
             _ => false,
         }
     }
@@ -24,6 +28,8 @@ impl RpEnumType {
 
         match *self {
             String => RpType::String,
+            Signed { size } => RpType::Signed { size: size },
+            Unsigned { size } => RpType::Unsigned { size: size },
             Generated => RpType::String,
         }
     }
@@ -35,6 +41,8 @@ impl RpEnumType {
             comment: vec![],
             ty: self.as_type(),
             field_as: None,
+            constraints: RpConstraints::default(),
+            default: None,
         }
     }
 }
@@ -45,6 +53,8 @@ impl fmt::Display for RpEnumType {
 
         match *self {
             String => write!(f, "string"),
+            Signed { size } => write!(f, "i{}", size),
+            Unsigned { size } => write!(f, "u{}", size),
             Generated => write!(f, "generated"),
         }
     }
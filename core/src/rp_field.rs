@@ -1,6 +1,6 @@
 //! Data Models for fields
 
-use super::{RpModifier, RpType};
+use super::{Loc, RpConstraints, RpModifier, RpType, RpValue};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct RpField {
@@ -11,6 +11,10 @@ pub struct RpField {
     pub ty: RpType,
     /// Alias of field in JSON.
     pub field_as: Option<String>,
+    /// Constraints on the value of the field.
+    pub constraints: RpConstraints,
+    /// Value of the field when it is absent.
+    pub default: Option<Loc<RpValue>>,
 }
 
 impl RpField {
@@ -0,0 +1,63 @@
+//! Model for HTTP methods
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RpHttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+}
+
+impl RpHttpMethod {
+    /// All supported methods, in the order they are presented to users.
+    pub const ALL: &'static [RpHttpMethod] = &[
+        RpHttpMethod::Get,
+        RpHttpMethod::Post,
+        RpHttpMethod::Put,
+        RpHttpMethod::Patch,
+        RpHttpMethod::Delete,
+        RpHttpMethod::Head,
+    ];
+
+    /// Parse the method from its upper-case name, as written in the specification.
+    pub fn parse(input: &str) -> Option<RpHttpMethod> {
+        RpHttpMethod::ALL.iter().find(|m| m.as_str() == input).map(
+            Clone::clone,
+        )
+    }
+
+    /// Get the upper-case name of the method, as sent in a request.
+    pub fn as_str(&self) -> &'static str {
+        use self::RpHttpMethod::*;
+
+        match *self {
+            Get => "GET",
+            Post => "POST",
+            Put => "PUT",
+            Patch => "PATCH",
+            Delete => "DELETE",
+            Head => "HEAD",
+        }
+    }
+
+    /// Check if requests using this method carry a body.
+    pub fn has_body(&self) -> bool {
+        use self::RpHttpMethod::*;
+
+        match *self {
+            Post | Put | Patch => true,
+            Get | Delete | Head => false,
+        }
+    }
+}
+
+impl fmt::Display for RpHttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
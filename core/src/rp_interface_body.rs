@@ -1,6 +1,6 @@
 //! Model for tuples.
 
-use super::{Loc, RpCode, RpField, RpSubType};
+use super::{Loc, RpCode, RpField, RpSubType, RpTypeInfo};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::slice;
@@ -9,6 +9,8 @@ decl_body!(pub struct RpInterfaceBody {
     pub fields: Vec<Loc<RpField>>,
     pub codes: Vec<Loc<RpCode>>,
     pub sub_types: BTreeMap<String, Rc<Loc<RpSubType>>>,
+    /// How sub-type information is encoded.
+    pub type_info: RpTypeInfo,
 });
 
 /// Iterator over fields.
@@ -3,6 +3,7 @@ use num::integer::Integer;
 use num::traits::Signed;
 use num::traits::cast::ToPrimitive;
 use serde;
+use std::cmp::Ordering;
 use std::fmt;
 use std::result;
 
@@ -32,6 +33,12 @@ impl RpNumber {
         self.digits.checked_div(&m).and_then(|r| r.to_u64())
     }
 
+    pub fn to_i64(&self) -> Option<i64> {
+        let m = self.multiple();
+
+        self.digits.checked_div(&m).and_then(|r| r.to_i64())
+    }
+
     pub fn to_u32(&self) -> Option<u32> {
         self.to_u64().map(|v| v as u32)
     }
@@ -40,6 +47,13 @@ impl RpNumber {
         self.to_u64().map(|v| v as usize)
     }
 
+    /// Compare the values of two numbers, regardless of their number of decimals.
+    pub fn cmp_value(&self, other: &RpNumber) -> Ordering {
+        let a = &self.digits * &other.multiple();
+        let b = &other.digits * &self.multiple();
+        a.cmp(&b)
+    }
+
     pub fn to_f64(&self) -> Option<f64> {
         let multiple = self.multiple();
         let (base, decimal) = self.digits.div_mod_floor(&multiple);
@@ -136,6 +150,23 @@ mod test_numbers {
         assert_eq!(Some(1043.21), n.to_f64());
     }
 
+    #[test]
+    fn test_cmp_value() {
+        let a = RpNumber {
+            digits: 15.into(),
+            decimal: 1,
+        };
+
+        let b = RpNumber {
+            digits: 2.into(),
+            decimal: 0,
+        };
+
+        assert_eq!(Ordering::Less, a.cmp_value(&b));
+        assert_eq!(Ordering::Greater, b.cmp_value(&a));
+        assert_eq!(Ordering::Equal, b.cmp_value(&RpNumber { digits: 200.into(), decimal: 2 }));
+    }
+
     #[test]
     fn test_negative() {
         let n = RpNumber {
@@ -144,6 +175,7 @@ mod test_numbers {
         };
 
         assert_eq!(None, n.to_u64());
+        assert_eq!(Some(-1043), n.to_i64());
         assert_eq!(Some(-1043.21), n.to_f64());
     }
 
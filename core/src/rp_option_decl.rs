@@ -6,7 +6,7 @@ use rp_number::RpNumber;
 use rp_value::RpValue;
 use std::result;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct RpOptionDecl {
     pub name: String,
     pub value: Loc<RpValue>,
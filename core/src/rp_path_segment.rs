@@ -1,12 +1,13 @@
 //! Path specifications
 
-use super::{Loc, RpType};
+use super::Loc;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RpPathSegment {
     Literal { value: Loc<String> },
-    Variable { name: Loc<String>, ty: Loc<RpType> },
+    /// A variable, bound to the request field with the same name.
+    Variable { name: Loc<String> },
 }
 
 impl RpPathSegment {
@@ -15,7 +16,7 @@ impl RpPathSegment {
 
         match *self {
             Literal { ref value } => value.value().to_owned(),
-            Variable { ref name, .. } => format!("{{{}}}", name.value()),
+            Variable { ref name } => format!("{{{}}}", name.value()),
         }
     }
 
@@ -24,7 +25,7 @@ impl RpPathSegment {
 
         match *self {
             Literal { ref value } => value.value().as_str(),
-            Variable { ref name, .. } => name.value().as_str(),
+            Variable { ref name } => name.value().as_str(),
         }
     }
 }
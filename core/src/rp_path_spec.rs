@@ -1,8 +1,8 @@
 //! Path specifications
 
-use super::RpPathSegment;
+use super::{Loc, RpPathSegment};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RpPathSpec {
     pub segments: Vec<RpPathSegment>,
 }
@@ -16,4 +16,15 @@ impl RpPathSpec {
     pub fn id_fragments(&self) -> Vec<&str> {
         self.segments.iter().map(RpPathSegment::id).collect()
     }
+
+    /// Iterate over all variables in the path, in the order they appear.
+    pub fn variables(&self) -> Vec<&Loc<String>> {
+        self.segments
+            .iter()
+            .filter_map(|s| match *s {
+                RpPathSegment::Variable { ref name } => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
 }
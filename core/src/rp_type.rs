@@ -1,6 +1,6 @@
 //! Type of a model.
 
-use super::{RpEnumType, RpName};
+use super::{RpEnumType, RpName, RpValue};
 use std::fmt;
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -14,6 +14,13 @@ pub enum RpType {
     String,
     /// ISO-8601 datetime
     DateTime,
+    /// ISO-8601 date, without time
+    Date,
+    /// ISO-8601 duration
+    Duration,
+    Uuid,
+    /// Arbitrary-precision decimal number
+    Decimal,
     Bytes,
     Any,
     Name { name: RpName },
@@ -31,10 +38,70 @@ impl RpType {
 
         match *self {
             String => Some(RpEnumType::String),
+            Signed { size } => Some(RpEnumType::Signed { size: size }),
+            Unsigned { size } => Some(RpEnumType::Unsigned { size: size }),
             _ => None,
         }
     }
 
+    /// Check if the given value is a valid value of this type.
+    ///
+    /// Only built-in types and arrays of them have values.
+    pub fn is_assignable_from(&self, value: &RpValue) -> bool {
+        use self::RpType::*;
+
+        match (self, value) {
+            (&Signed { size }, &RpValue::Number(ref number)) if number.decimal == 0 => {
+                number.to_i64().map_or(false, |v| match size {
+                    8 => v >= i8::min_value() as i64 && v <= i8::max_value() as i64,
+                    16 => v >= i16::min_value() as i64 && v <= i16::max_value() as i64,
+                    32 => v >= i32::min_value() as i64 && v <= i32::max_value() as i64,
+                    _ => true,
+                })
+            }
+            (&Unsigned { size }, &RpValue::Number(ref number)) if number.decimal == 0 => {
+                number.to_u64().map_or(false, |v| match size {
+                    8 => v <= u8::max_value() as u64,
+                    16 => v <= u16::max_value() as u64,
+                    32 => v <= u32::max_value() as u64,
+                    _ => true,
+                })
+            }
+            (&Float, &RpValue::Number(_)) |
+            (&Double, &RpValue::Number(_)) |
+            (&String, &RpValue::String(_)) |
+            (&Boolean, &RpValue::Boolean(_)) => true,
+            (&Array { ref inner }, &RpValue::Array(ref values)) => {
+                values.iter().all(|v| inner.is_assignable_from(v.value()))
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if this type can represent every value of the other type, with the same encoding.
+    ///
+    /// Integers widen to integers of a larger size, and `float` widens to `double`.
+    pub fn is_widening_of(&self, other: &RpType) -> bool {
+        use self::RpType::*;
+
+        match (self, other) {
+            (&Signed { size }, &Signed { size: from }) => size > from,
+            (&Unsigned { size }, &Unsigned { size: from }) => size > from,
+            (&Signed { size }, &Unsigned { size: from }) => size > from,
+            (&Double, &Float) => true,
+            (&Array { ref inner }, &Array { inner: ref other }) => inner.is_widening_of(other),
+            (&Map { ref key, ref value },
+             &Map {
+                 key: ref other_key,
+                 value: ref other_value,
+             }) => {
+                self != other && (key == other_key || key.is_widening_of(other_key)) &&
+                    (value == other_value || value.is_widening_of(other_value))
+            }
+            _ => false,
+        }
+    }
+
     /// Localize type.
     ///
     /// Strips version of any type which is _not_ imported.
@@ -78,6 +145,10 @@ impl fmt::Display for RpType {
             Boolean => write!(f, "boolean"),
             String => write!(f, "string"),
             DateTime => write!(f, "datetime"),
+            Date => write!(f, "date"),
+            Duration => write!(f, "duration"),
+            Uuid => write!(f, "uuid"),
+            Decimal => write!(f, "decimal"),
             Name { ref name } => write!(f, "{}", name),
             Array { ref inner } => write!(f, "[{}]", inner),
             Map { ref key, ref value } => write!(f, "{{{}: {}}}", key, value),
@@ -86,3 +157,39 @@ impl fmt::Display for RpType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_widening_of() {
+        use self::RpType::*;
+
+        assert!(Signed { size: 16 }.is_widening_of(&Signed { size: 8 }));
+        assert!(Signed { size: 16 }.is_widening_of(&Unsigned { size: 8 }));
+        assert!(Unsigned { size: 64 }.is_widening_of(&Unsigned { size: 32 }));
+        assert!(Double.is_widening_of(&Float));
+
+        assert!(!Signed { size: 8 }.is_widening_of(&Signed { size: 16 }));
+        assert!(!Unsigned { size: 16 }.is_widening_of(&Signed { size: 8 }));
+        assert!(!Signed { size: 32 }.is_widening_of(&Signed { size: 32 }));
+        assert!(!Decimal.is_widening_of(&Signed { size: 32 }));
+
+        let from = Array { inner: Box::new(Signed { size: 8 }) };
+        let to = Array { inner: Box::new(Signed { size: 32 }) };
+        assert!(to.is_widening_of(&from));
+        assert!(!from.is_widening_of(&to));
+
+        let from = Map {
+            key: Box::new(String),
+            value: Box::new(Float),
+        };
+        let to = Map {
+            key: Box::new(String),
+            value: Box::new(Double),
+        };
+        assert!(to.is_widening_of(&from));
+        assert!(!from.is_widening_of(&from));
+    }
+}
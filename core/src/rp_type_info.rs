@@ -0,0 +1,37 @@
+//! Model for how sub-type information is carried in interfaces.
+
+use std::fmt;
+
+/// Default name of the field carrying sub-type information.
+const DEFAULT_TYPE_FIELD: &'static str = "type";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RpTypeInfo {
+    /// Sub-types are objects, with a special field containing the name of the sub-type.
+    TypeField { name: String },
+    /// Sub-types are two-element arrays, where the first element is the name of the sub-type and
+    /// the second is an object containing its fields.
+    Array,
+    /// Sub-types are objects with a single key, where the key is the name of the sub-type and the
+    /// value is an object containing its fields.
+    ObjectKeys,
+}
+
+impl Default for RpTypeInfo {
+    fn default() -> RpTypeInfo {
+        RpTypeInfo::TypeField { name: DEFAULT_TYPE_FIELD.to_string() }
+    }
+}
+
+impl fmt::Display for RpTypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::RpTypeInfo::*;
+
+        match *self {
+            TypeField { ref name } => write!(f, "type_field({:?})", name),
+            Array => write!(f, "array"),
+            ObjectKeys => write!(f, "object_keys"),
+        }
+    }
+}
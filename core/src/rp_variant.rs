@@ -1,6 +1,7 @@
 //! Variant in an enum
 
-use super::{Loc, RpEnumOrdinal, RpName};
+use super::{Loc, RpEnumOrdinal, RpName, RpNumber};
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct RpVariant {
@@ -10,13 +11,31 @@ pub struct RpVariant {
     pub ordinal: RpEnumOrdinal,
 }
 
+/// The value that a variant is encoded as.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RpVariantValue<'a> {
+    String(&'a str),
+    Number(&'a RpNumber),
+}
+
+impl<'a> fmt::Display for RpVariantValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpVariantValue::String(string) => write!(f, "{}", string),
+            RpVariantValue::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
 impl RpVariant {
-    pub fn ordinal(&self) -> &str {
+    pub fn ordinal(&self) -> RpVariantValue {
         use self::RpEnumOrdinal::*;
 
         match self.ordinal {
-            String(ref string) => string.as_str(),
-            Generated => self.local_name.as_str(),
+            String(ref string) => RpVariantValue::String(string.as_str()),
+            Number(ref number) => RpVariantValue::Number(number),
+            Generated => RpVariantValue::String(self.local_name.as_str()),
         }
     }
 }
@@ -2,12 +2,16 @@ package test;
 
 import com.fasterxml.jackson.annotation.JsonCreator;
 import com.fasterxml.jackson.annotation.JsonProperty;
+import java.math.BigDecimal;
 import java.nio.ByteBuffer;
+import java.time.Duration;
 import java.time.Instant;
+import java.time.LocalDate;
 import java.util.List;
 import java.util.Map;
 import java.util.Objects;
 import java.util.Optional;
+import java.util.UUID;
 
 public class Entry {
   @JsonProperty("boolean_type")
@@ -19,7 +23,7 @@ public class Entry {
   @JsonProperty("unsigned_32")
   private final Optional<Integer> unsigned32;
   @JsonProperty("unsigned_64")
-  private final Optional<Integer> unsigned64;
+  private final Optional<Long> unsigned64;
   @JsonProperty("signed_32")
   private final Optional<Integer> signed32;
   @JsonProperty("signed_64")
@@ -36,6 +40,22 @@ public class Entry {
   private final Optional<List<Entry>> arrayType;
   @JsonProperty("map_type")
   private final Optional<Map<String, Entry>> mapType;
+  @JsonProperty("unsigned_8")
+  private final Optional<Short> unsigned8;
+  @JsonProperty("unsigned_16")
+  private final Optional<Integer> unsigned16;
+  @JsonProperty("signed_8")
+  private final Optional<Byte> signed8;
+  @JsonProperty("signed_16")
+  private final Optional<Short> signed16;
+  @JsonProperty("date_type")
+  private final Optional<LocalDate> dateType;
+  @JsonProperty("duration_type")
+  private final Optional<Duration> durationType;
+  @JsonProperty("uuid_type")
+  private final Optional<UUID> uuidType;
+  @JsonProperty("decimal_type")
+  private final Optional<BigDecimal> decimalType;
 
   @JsonCreator
   public Entry(
@@ -43,7 +63,7 @@ public class Entry {
     @JsonProperty("string_type") final Optional<String> stringType,
     @JsonProperty("datetime_type") final Optional<Instant> datetimeType,
     @JsonProperty("unsigned_32") final Optional<Integer> unsigned32,
-    @JsonProperty("unsigned_64") final Optional<Integer> unsigned64,
+    @JsonProperty("unsigned_64") final Optional<Long> unsigned64,
     @JsonProperty("signed_32") final Optional<Integer> signed32,
     @JsonProperty("signed_64") final Optional<Long> signed64,
     @JsonProperty("float_type") final Optional<Float> floatType,
@@ -51,7 +71,15 @@ public class Entry {
     @JsonProperty("bytes_type") final Optional<ByteBuffer> bytesType,
     @JsonProperty("any_type") final Optional<Object> anyType,
     @JsonProperty("array_type") final Optional<List<Entry>> arrayType,
-    @JsonProperty("map_type") final Optional<Map<String, Entry>> mapType
+    @JsonProperty("map_type") final Optional<Map<String, Entry>> mapType,
+    @JsonProperty("unsigned_8") final Optional<Short> unsigned8,
+    @JsonProperty("unsigned_16") final Optional<Integer> unsigned16,
+    @JsonProperty("signed_8") final Optional<Byte> signed8,
+    @JsonProperty("signed_16") final Optional<Short> signed16,
+    @JsonProperty("date_type") final Optional<LocalDate> dateType,
+    @JsonProperty("duration_type") final Optional<Duration> durationType,
+    @JsonProperty("uuid_type") final Optional<UUID> uuidType,
+    @JsonProperty("decimal_type") final Optional<BigDecimal> decimalType
   ) {
     Objects.requireNonNull(booleanType, "booleanType");
     this.booleanType = booleanType;
@@ -79,6 +107,22 @@ public class Entry {
     this.arrayType = arrayType;
     Objects.requireNonNull(mapType, "mapType");
     this.mapType = mapType;
+    Objects.requireNonNull(unsigned8, "unsigned8");
+    this.unsigned8 = unsigned8;
+    Objects.requireNonNull(unsigned16, "unsigned16");
+    this.unsigned16 = unsigned16;
+    Objects.requireNonNull(signed8, "signed8");
+    this.signed8 = signed8;
+    Objects.requireNonNull(signed16, "signed16");
+    this.signed16 = signed16;
+    Objects.requireNonNull(dateType, "dateType");
+    this.dateType = dateType;
+    Objects.requireNonNull(durationType, "durationType");
+    this.durationType = durationType;
+    Objects.requireNonNull(uuidType, "uuidType");
+    this.uuidType = uuidType;
+    Objects.requireNonNull(decimalType, "decimalType");
+    this.decimalType = decimalType;
   }
 
   public Optional<Boolean> getBooleanType() {
@@ -97,7 +141,7 @@ public class Entry {
     return this.unsigned32;
   }
 
-  public Optional<Integer> getUnsigned64() {
+  public Optional<Long> getUnsigned64() {
     return this.unsigned64;
   }
 
@@ -133,6 +177,38 @@ public class Entry {
     return this.mapType;
   }
 
+  public Optional<Short> getUnsigned8() {
+    return this.unsigned8;
+  }
+
+  public Optional<Integer> getUnsigned16() {
+    return this.unsigned16;
+  }
+
+  public Optional<Byte> getSigned8() {
+    return this.signed8;
+  }
+
+  public Optional<Short> getSigned16() {
+    return this.signed16;
+  }
+
+  public Optional<LocalDate> getDateType() {
+    return this.dateType;
+  }
+
+  public Optional<Duration> getDurationType() {
+    return this.durationType;
+  }
+
+  public Optional<UUID> getUuidType() {
+    return this.uuidType;
+  }
+
+  public Optional<BigDecimal> getDecimalType() {
+    return this.decimalType;
+  }
+
   @Override
   public int hashCode() {
     int result = 1;
@@ -149,6 +225,14 @@ public class Entry {
     result = result * 31 + this.anyType.hashCode();
     result = result * 31 + this.arrayType.hashCode();
     result = result * 31 + this.mapType.hashCode();
+    result = result * 31 + this.unsigned8.hashCode();
+    result = result * 31 + this.unsigned16.hashCode();
+    result = result * 31 + this.signed8.hashCode();
+    result = result * 31 + this.signed16.hashCode();
+    result = result * 31 + this.dateType.hashCode();
+    result = result * 31 + this.durationType.hashCode();
+    result = result * 31 + this.uuidType.hashCode();
+    result = result * 31 + this.decimalType.hashCode();
     return result;
   }
 
@@ -217,6 +301,38 @@ public class Entry {
       return false;
     }
 
+    if (!this.unsigned8.equals(o.unsigned8)) {
+      return false;
+    }
+
+    if (!this.unsigned16.equals(o.unsigned16)) {
+      return false;
+    }
+
+    if (!this.signed8.equals(o.signed8)) {
+      return false;
+    }
+
+    if (!this.signed16.equals(o.signed16)) {
+      return false;
+    }
+
+    if (!this.dateType.equals(o.dateType)) {
+      return false;
+    }
+
+    if (!this.durationType.equals(o.durationType)) {
+      return false;
+    }
+
+    if (!this.uuidType.equals(o.uuidType)) {
+      return false;
+    }
+
+    if (!this.decimalType.equals(o.decimalType)) {
+      return false;
+    }
+
     return true;
   }
 
@@ -264,6 +380,30 @@ public class Entry {
     b.append(", ");
     b.append("mapType=");
     b.append(this.mapType.toString());
+    b.append(", ");
+    b.append("unsigned8=");
+    b.append(this.unsigned8.toString());
+    b.append(", ");
+    b.append("unsigned16=");
+    b.append(this.unsigned16.toString());
+    b.append(", ");
+    b.append("signed8=");
+    b.append(this.signed8.toString());
+    b.append(", ");
+    b.append("signed16=");
+    b.append(this.signed16.toString());
+    b.append(", ");
+    b.append("dateType=");
+    b.append(this.dateType.toString());
+    b.append(", ");
+    b.append("durationType=");
+    b.append(this.durationType.toString());
+    b.append(", ");
+    b.append("uuidType=");
+    b.append(this.uuidType.toString());
+    b.append(", ");
+    b.append("decimalType=");
+    b.append(this.decimalType.toString());
     b.append(")");
 
     return b.toString();
@@ -274,7 +414,7 @@ public class Entry {
     private Optional<String> stringType = Optional.empty();
     private Optional<Instant> datetimeType = Optional.empty();
     private Optional<Integer> unsigned32 = Optional.empty();
-    private Optional<Integer> unsigned64 = Optional.empty();
+    private Optional<Long> unsigned64 = Optional.empty();
     private Optional<Integer> signed32 = Optional.empty();
     private Optional<Long> signed64 = Optional.empty();
     private Optional<Float> floatType = Optional.empty();
@@ -283,6 +423,14 @@ public class Entry {
     private Optional<Object> anyType = Optional.empty();
     private Optional<List<Entry>> arrayType = Optional.empty();
     private Optional<Map<String, Entry>> mapType = Optional.empty();
+    private Optional<Short> unsigned8 = Optional.empty();
+    private Optional<Integer> unsigned16 = Optional.empty();
+    private Optional<Byte> signed8 = Optional.empty();
+    private Optional<Short> signed16 = Optional.empty();
+    private Optional<LocalDate> dateType = Optional.empty();
+    private Optional<Duration> durationType = Optional.empty();
+    private Optional<UUID> uuidType = Optional.empty();
+    private Optional<BigDecimal> decimalType = Optional.empty();
 
     public Builder booleanType(final boolean booleanType) {
       this.booleanType = Optional.of(booleanType);
@@ -304,7 +452,7 @@ public class Entry {
       return this;
     }
 
-    public Builder unsigned64(final int unsigned64) {
+    public Builder unsigned64(final long unsigned64) {
       this.unsigned64 = Optional.of(unsigned64);
       return this;
     }
@@ -349,12 +497,52 @@ public class Entry {
       return this;
     }
 
+    public Builder unsigned8(final short unsigned8) {
+      this.unsigned8 = Optional.of(unsigned8);
+      return this;
+    }
+
+    public Builder unsigned16(final int unsigned16) {
+      this.unsigned16 = Optional.of(unsigned16);
+      return this;
+    }
+
+    public Builder signed8(final byte signed8) {
+      this.signed8 = Optional.of(signed8);
+      return this;
+    }
+
+    public Builder signed16(final short signed16) {
+      this.signed16 = Optional.of(signed16);
+      return this;
+    }
+
+    public Builder dateType(final LocalDate dateType) {
+      this.dateType = Optional.of(dateType);
+      return this;
+    }
+
+    public Builder durationType(final Duration durationType) {
+      this.durationType = Optional.of(durationType);
+      return this;
+    }
+
+    public Builder uuidType(final UUID uuidType) {
+      this.uuidType = Optional.of(uuidType);
+      return this;
+    }
+
+    public Builder decimalType(final BigDecimal decimalType) {
+      this.decimalType = Optional.of(decimalType);
+      return this;
+    }
+
     public Entry build() {
       final Optional<Boolean> booleanType = this.booleanType;
       final Optional<String> stringType = this.stringType;
       final Optional<Instant> datetimeType = this.datetimeType;
       final Optional<Integer> unsigned32 = this.unsigned32;
-      final Optional<Integer> unsigned64 = this.unsigned64;
+      final Optional<Long> unsigned64 = this.unsigned64;
       final Optional<Integer> signed32 = this.signed32;
       final Optional<Long> signed64 = this.signed64;
       final Optional<Float> floatType = this.floatType;
@@ -363,8 +551,16 @@ public class Entry {
       final Optional<Object> anyType = this.anyType;
       final Optional<List<Entry>> arrayType = this.arrayType;
       final Optional<Map<String, Entry>> mapType = this.mapType;
-
-      return new Entry(booleanType, stringType, datetimeType, unsigned32, unsigned64, signed32, signed64, floatType, doubleType, bytesType, anyType, arrayType, mapType);
+      final Optional<Short> unsigned8 = this.unsigned8;
+      final Optional<Integer> unsigned16 = this.unsigned16;
+      final Optional<Byte> signed8 = this.signed8;
+      final Optional<Short> signed16 = this.signed16;
+      final Optional<LocalDate> dateType = this.dateType;
+      final Optional<Duration> durationType = this.durationType;
+      final Optional<UUID> uuidType = this.uuidType;
+      final Optional<BigDecimal> decimalType = this.decimalType;
+
+      return new Entry(booleanType, stringType, datetimeType, unsigned32, unsigned64, signed32, signed64, floatType, doubleType, bytesType, anyType, arrayType, mapType, unsigned8, unsigned16, signed8, signed16, dateType, durationType, uuidType, decimalType);
     }
   }
 }
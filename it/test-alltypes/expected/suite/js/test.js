@@ -1,6 +1,6 @@
 
 export class Entry {
-  constructor(boolean_type, string_type, datetime_type, unsigned_32, unsigned_64, signed_32, signed_64, float_type, double_type, bytes_type, any_type, array_type, map_type) {
+  constructor(boolean_type, string_type, datetime_type, unsigned_32, unsigned_64, signed_32, signed_64, float_type, double_type, bytes_type, any_type, array_type, map_type, unsigned_8, unsigned_16, signed_8, signed_16, date_type, duration_type, uuid_type, decimal_type) {
     this.boolean_type = boolean_type;
     this.string_type = string_type;
     this.datetime_type = datetime_type;
@@ -14,6 +14,14 @@ export class Entry {
     this.any_type = any_type;
     this.array_type = array_type;
     this.map_type = map_type;
+    this.unsigned_8 = unsigned_8;
+    this.unsigned_16 = unsigned_16;
+    this.signed_8 = signed_8;
+    this.signed_16 = signed_16;
+    this.date_type = date_type;
+    this.duration_type = duration_type;
+    this.uuid_type = uuid_type;
+    this.decimal_type = decimal_type;
   }
 
   static decode(data) {
@@ -121,7 +129,71 @@ export class Entry {
       v_map_type = null;
     }
 
-    return new Entry(v_boolean_type, v_string_type, v_datetime_type, v_unsigned_32, v_unsigned_64, v_signed_32, v_signed_64, v_float_type, v_double_type, v_bytes_type, v_any_type, v_array_type, v_map_type);
+    let v_unsigned_8 = data["unsigned_8"];
+
+    if (v_unsigned_8 !== null && v_unsigned_8 !== undefined) {
+      v_unsigned_8 = v_unsigned_8;
+    } else {
+      v_unsigned_8 = null;
+    }
+
+    let v_unsigned_16 = data["unsigned_16"];
+
+    if (v_unsigned_16 !== null && v_unsigned_16 !== undefined) {
+      v_unsigned_16 = v_unsigned_16;
+    } else {
+      v_unsigned_16 = null;
+    }
+
+    let v_signed_8 = data["signed_8"];
+
+    if (v_signed_8 !== null && v_signed_8 !== undefined) {
+      v_signed_8 = v_signed_8;
+    } else {
+      v_signed_8 = null;
+    }
+
+    let v_signed_16 = data["signed_16"];
+
+    if (v_signed_16 !== null && v_signed_16 !== undefined) {
+      v_signed_16 = v_signed_16;
+    } else {
+      v_signed_16 = null;
+    }
+
+    let v_date_type = data["date_type"];
+
+    if (v_date_type !== null && v_date_type !== undefined) {
+      v_date_type = v_date_type;
+    } else {
+      v_date_type = null;
+    }
+
+    let v_duration_type = data["duration_type"];
+
+    if (v_duration_type !== null && v_duration_type !== undefined) {
+      v_duration_type = v_duration_type;
+    } else {
+      v_duration_type = null;
+    }
+
+    let v_uuid_type = data["uuid_type"];
+
+    if (v_uuid_type !== null && v_uuid_type !== undefined) {
+      v_uuid_type = v_uuid_type;
+    } else {
+      v_uuid_type = null;
+    }
+
+    let v_decimal_type = data["decimal_type"];
+
+    if (v_decimal_type !== null && v_decimal_type !== undefined) {
+      v_decimal_type = v_decimal_type;
+    } else {
+      v_decimal_type = null;
+    }
+
+    return new Entry(v_boolean_type, v_string_type, v_datetime_type, v_unsigned_32, v_unsigned_64, v_signed_32, v_signed_64, v_float_type, v_double_type, v_bytes_type, v_any_type, v_array_type, v_map_type, v_unsigned_8, v_unsigned_16, v_signed_8, v_signed_16, v_date_type, v_duration_type, v_uuid_type, v_decimal_type);
   }
 
   encode() {
@@ -179,6 +251,38 @@ export class Entry {
       data["map_type"] = (function(data) { let o = {}; for (let k in data) { o[k] = data[k].encode(); }; return o; })(this.map_type);
     }
 
+    if (this.unsigned_8 !== null && this.unsigned_8 !== undefined) {
+      data["unsigned_8"] = this.unsigned_8;
+    }
+
+    if (this.unsigned_16 !== null && this.unsigned_16 !== undefined) {
+      data["unsigned_16"] = this.unsigned_16;
+    }
+
+    if (this.signed_8 !== null && this.signed_8 !== undefined) {
+      data["signed_8"] = this.signed_8;
+    }
+
+    if (this.signed_16 !== null && this.signed_16 !== undefined) {
+      data["signed_16"] = this.signed_16;
+    }
+
+    if (this.date_type !== null && this.date_type !== undefined) {
+      data["date_type"] = this.date_type;
+    }
+
+    if (this.duration_type !== null && this.duration_type !== undefined) {
+      data["duration_type"] = this.duration_type;
+    }
+
+    if (this.uuid_type !== null && this.uuid_type !== undefined) {
+      data["uuid_type"] = this.uuid_type;
+    }
+
+    if (this.decimal_type !== null && this.decimal_type !== undefined) {
+      data["decimal_type"] = this.decimal_type;
+    }
+
     return data;
   }
 }
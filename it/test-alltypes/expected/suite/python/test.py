@@ -1,5 +1,5 @@
 class Entry:
-  def __init__(self, boolean_type, string_type, datetime_type, unsigned_32, unsigned_64, signed_32, signed_64, float_type, double_type, bytes_type, any_type, array_type, map_type):
+  def __init__(self, boolean_type, string_type, datetime_type, unsigned_32, unsigned_64, signed_32, signed_64, float_type, double_type, bytes_type, any_type, array_type, map_type, unsigned_8, unsigned_16, signed_8, signed_16, date_type, duration_type, uuid_type, decimal_type):
     self.boolean_type = boolean_type
     self.string_type = string_type
     self.datetime_type = datetime_type
@@ -13,6 +13,14 @@ class Entry:
     self.any_type = any_type
     self.array_type = array_type
     self.map_type = map_type
+    self.unsigned_8 = unsigned_8
+    self.unsigned_16 = unsigned_16
+    self.signed_8 = signed_8
+    self.signed_16 = signed_16
+    self.date_type = date_type
+    self.duration_type = duration_type
+    self.uuid_type = uuid_type
+    self.decimal_type = decimal_type
 
   @staticmethod
   def decode(data):
@@ -120,7 +128,71 @@ class Entry:
     else:
       f_map_type = None
 
-    return Entry(f_boolean_type, f_string_type, f_datetime_type, f_unsigned_32, f_unsigned_64, f_signed_32, f_signed_64, f_float_type, f_double_type, f_bytes_type, f_any_type, f_array_type, f_map_type)
+    if "unsigned_8" in data:
+      f_unsigned_8 = data["unsigned_8"]
+
+      if f_unsigned_8 is not None:
+        f_unsigned_8 = f_unsigned_8
+    else:
+      f_unsigned_8 = None
+
+    if "unsigned_16" in data:
+      f_unsigned_16 = data["unsigned_16"]
+
+      if f_unsigned_16 is not None:
+        f_unsigned_16 = f_unsigned_16
+    else:
+      f_unsigned_16 = None
+
+    if "signed_8" in data:
+      f_signed_8 = data["signed_8"]
+
+      if f_signed_8 is not None:
+        f_signed_8 = f_signed_8
+    else:
+      f_signed_8 = None
+
+    if "signed_16" in data:
+      f_signed_16 = data["signed_16"]
+
+      if f_signed_16 is not None:
+        f_signed_16 = f_signed_16
+    else:
+      f_signed_16 = None
+
+    if "date_type" in data:
+      f_date_type = data["date_type"]
+
+      if f_date_type is not None:
+        f_date_type = f_date_type
+    else:
+      f_date_type = None
+
+    if "duration_type" in data:
+      f_duration_type = data["duration_type"]
+
+      if f_duration_type is not None:
+        f_duration_type = f_duration_type
+    else:
+      f_duration_type = None
+
+    if "uuid_type" in data:
+      f_uuid_type = data["uuid_type"]
+
+      if f_uuid_type is not None:
+        f_uuid_type = f_uuid_type
+    else:
+      f_uuid_type = None
+
+    if "decimal_type" in data:
+      f_decimal_type = data["decimal_type"]
+
+      if f_decimal_type is not None:
+        f_decimal_type = f_decimal_type
+    else:
+      f_decimal_type = None
+
+    return Entry(f_boolean_type, f_string_type, f_datetime_type, f_unsigned_32, f_unsigned_64, f_signed_32, f_signed_64, f_float_type, f_double_type, f_bytes_type, f_any_type, f_array_type, f_map_type, f_unsigned_8, f_unsigned_16, f_signed_8, f_signed_16, f_date_type, f_duration_type, f_uuid_type, f_decimal_type)
 
   def encode(self):
     data = dict()
@@ -164,7 +236,31 @@ class Entry:
     if self.map_type is not None:
       data["map_type"] = dict((k, v.encode()) for (k, v) in self.map_type.items())
 
+    if self.unsigned_8 is not None:
+      data["unsigned_8"] = self.unsigned_8
+
+    if self.unsigned_16 is not None:
+      data["unsigned_16"] = self.unsigned_16
+
+    if self.signed_8 is not None:
+      data["signed_8"] = self.signed_8
+
+    if self.signed_16 is not None:
+      data["signed_16"] = self.signed_16
+
+    if self.date_type is not None:
+      data["date_type"] = self.date_type
+
+    if self.duration_type is not None:
+      data["duration_type"] = self.duration_type
+
+    if self.uuid_type is not None:
+      data["uuid_type"] = self.uuid_type
+
+    if self.decimal_type is not None:
+      data["decimal_type"] = self.decimal_type
+
     return data
 
   def __repr__(self):
-    return "<Entry boolean_type: {!r}, string_type: {!r}, datetime_type: {!r}, unsigned_32: {!r}, unsigned_64: {!r}, signed_32: {!r}, signed_64: {!r}, float_type: {!r}, double_type: {!r}, bytes_type: {!r}, any_type: {!r}, array_type: {!r}, map_type: {!r}>".format(self.boolean_type, self.string_type, self.datetime_type, self.unsigned_32, self.unsigned_64, self.signed_32, self.signed_64, self.float_type, self.double_type, self.bytes_type, self.any_type, self.array_type, self.map_type)
+    return "<Entry boolean_type: {!r}, string_type: {!r}, datetime_type: {!r}, unsigned_32: {!r}, unsigned_64: {!r}, signed_32: {!r}, signed_64: {!r}, float_type: {!r}, double_type: {!r}, bytes_type: {!r}, any_type: {!r}, array_type: {!r}, map_type: {!r}, unsigned_8: {!r}, unsigned_16: {!r}, signed_8: {!r}, signed_16: {!r}, date_type: {!r}, duration_type: {!r}, uuid_type: {!r}, decimal_type: {!r}>".format(self.boolean_type, self.string_type, self.datetime_type, self.unsigned_32, self.unsigned_64, self.signed_32, self.signed_64, self.float_type, self.double_type, self.bytes_type, self.any_type, self.array_type, self.map_type, self.unsigned_8, self.unsigned_16, self.signed_8, self.signed_16, self.date_type, self.duration_type, self.uuid_type, self.decimal_type)
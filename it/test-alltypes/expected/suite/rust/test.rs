@@ -1,7 +1,9 @@
+use bigdecimal;
 use chrono;
 use chrono::offset;
 use serde_json as json;
 use std::collections;
+use uuid;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Entry {
@@ -12,9 +14,9 @@ pub struct Entry {
   #[serde(skip_serializing_if="Option::is_none")]
   datetime_type: Option<chrono::DateTime<offset::Utc>>,
   #[serde(skip_serializing_if="Option::is_none")]
-  unsigned_32: Option<i32>,
+  unsigned_32: Option<u32>,
   #[serde(skip_serializing_if="Option::is_none")]
-  unsigned_64: Option<i32>,
+  unsigned_64: Option<u64>,
   #[serde(skip_serializing_if="Option::is_none")]
   signed_32: Option<i32>,
   #[serde(skip_serializing_if="Option::is_none")]
@@ -24,11 +26,289 @@ pub struct Entry {
   #[serde(skip_serializing_if="Option::is_none")]
   double_type: Option<f64>,
   #[serde(skip_serializing_if="Option::is_none")]
-  bytes_type: Option<String>,
+  #[serde(default)]
+  #[serde(with = "reproto_bytes")]
+  bytes_type: Option<Vec<u8>>,
   #[serde(skip_serializing_if="Option::is_none")]
   any_type: Option<json::Value>,
   #[serde(skip_serializing_if="Option::is_none")]
   array_type: Option<Vec<Entry>>,
   #[serde(skip_serializing_if="Option::is_none")]
   map_type: Option<collections::HashMap<String, Entry>>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  unsigned_8: Option<u8>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  unsigned_16: Option<u16>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  signed_8: Option<i8>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  signed_16: Option<i16>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  date_type: Option<chrono::NaiveDate>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default)]
+  #[serde(with = "reproto_duration")]
+  duration_type: Option<chrono::Duration>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  uuid_type: Option<uuid::Uuid>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  decimal_type: Option<bigdecimal::BigDecimal>,
+}
+
+mod reproto_bytes {
+  pub trait Encode: Sized {
+    type Repr: ::serde::Serialize + ::serde::de::DeserializeOwned;
+
+    fn encode(&self) -> Self::Repr;
+
+    fn decode(repr: Self::Repr) -> Result<Self, String>;
+  }
+
+  impl Encode for Vec<u8> {
+    type Repr = String;
+
+    fn encode(&self) -> String {
+      ::base64::encode(self)
+    }
+
+    fn decode(repr: String) -> Result<Self, String> {
+      ::base64::decode(&repr).map_err(|e| e.to_string())
+    }
+  }
+
+  impl<T: Encode> Encode for Option<T> {
+    type Repr = Option<T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.as_ref().map(Encode::encode)
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      match repr {
+        Some(repr) => T::decode(repr).map(Some),
+        None => Ok(None),
+      }
+    }
+  }
+
+  impl<T: Encode> Encode for Vec<T> {
+    type Repr = Vec<T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.iter().map(Encode::encode).collect()
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      repr.into_iter().map(T::decode).collect()
+    }
+  }
+
+  impl<K, T: Encode> Encode for ::std::collections::HashMap<K, T>
+  where
+    K: ::std::hash::Hash + Eq + Clone + ::serde::Serialize + ::serde::de::DeserializeOwned,
+  {
+    type Repr = ::std::collections::HashMap<K, T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.iter().map(|(k, v)| (k.clone(), v.encode())).collect()
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      repr.into_iter().map(|(k, v)| T::decode(v).map(|v| (k, v))).collect()
+    }
+  }
+
+  pub fn serialize<T: Encode, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ::serde::Serializer,
+  {
+    ::serde::Serialize::serialize(&value.encode(), serializer)
+  }
+
+  pub fn deserialize<'de, T: Encode, D>(deserializer: D) -> Result<T, D::Error>
+  where
+    D: ::serde::Deserializer<'de>,
+  {
+    use ::serde::de::Error;
+
+    let repr = ::serde::Deserialize::deserialize(deserializer)?;
+    T::decode(repr).map_err(D::Error::custom)
+  }
+}
+
+mod reproto_duration {
+  pub trait Encode: Sized {
+    type Repr: ::serde::Serialize + ::serde::de::DeserializeOwned;
+
+    fn encode(&self) -> Self::Repr;
+
+    fn decode(repr: Self::Repr) -> Result<Self, String>;
+  }
+
+  impl Encode for ::chrono::Duration {
+    type Repr = String;
+
+    fn encode(&self) -> String {
+      let (sign, duration) = if *self < ::chrono::Duration::zero() {
+        ("-", -*self)
+      } else {
+        ("", *self)
+      };
+
+      let seconds = duration.num_seconds();
+      let nanos = (duration - ::chrono::Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0);
+      let mut out = format!("{}PT", sign);
+
+      if seconds >= 3600 {
+        out.push_str(&format!("{}H", seconds / 3600));
+      }
+
+      if seconds % 3600 >= 60 {
+        out.push_str(&format!("{}M", seconds % 3600 / 60));
+      }
+
+      if seconds % 60 != 0 || nanos != 0 || seconds == 0 {
+        out.push_str(&(seconds % 60).to_string());
+
+        if nanos != 0 {
+          let mut fraction = format!(".{:09}", nanos);
+
+          while fraction.ends_with('0') {
+            fraction.pop();
+          }
+
+          out.push_str(&fraction);
+        }
+
+        out.push('S');
+      }
+
+      out
+    }
+
+    fn decode(repr: String) -> Result<Self, String> {
+      let (negative, rest) = if repr.starts_with('-') {
+        (true, &repr[1..])
+      } else {
+        (false, &repr[..])
+      };
+
+      if !rest.starts_with('P') {
+        return Err(format!("bad duration: {}", repr));
+      }
+
+      let mut duration = ::chrono::Duration::zero();
+      let mut time = false;
+      let mut number = String::new();
+
+      for c in rest[1..].chars() {
+        let unit = match c {
+          'T' if !time && number.is_empty() => {
+            time = true;
+            continue;
+          }
+          'D' if !time => 86_400,
+          'H' if time => 3_600,
+          'M' if time => 60,
+          'S' if time => 1,
+          c => {
+            number.push(c);
+            continue;
+          }
+        };
+
+        let mut parts = number.splitn(2, '.');
+        let whole = parts.next().unwrap_or("");
+        let seconds: i64 = whole.parse().map_err(|_| format!("bad duration: {}", repr))?;
+        duration = duration + ::chrono::Duration::seconds(seconds * unit);
+
+        if let Some(fraction) = parts.next() {
+          if unit != 1 || fraction.is_empty() || fraction.len() > 9 {
+            return Err(format!("bad duration: {}", repr));
+          }
+
+          let nanos: i64 = format!("{:0<9}", fraction)
+            .parse()
+            .map_err(|_| format!("bad duration: {}", repr))?;
+
+          if whole.starts_with('-') {
+            duration = duration - ::chrono::Duration::nanoseconds(nanos);
+          } else {
+            duration = duration + ::chrono::Duration::nanoseconds(nanos);
+          }
+        }
+
+        number.clear();
+      }
+
+      if !number.is_empty() {
+        return Err(format!("bad duration: {}", repr));
+      }
+
+      if negative {
+        return Ok(-duration);
+      }
+
+      Ok(duration)
+    }
+  }
+
+  impl<T: Encode> Encode for Option<T> {
+    type Repr = Option<T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.as_ref().map(Encode::encode)
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      match repr {
+        Some(repr) => T::decode(repr).map(Some),
+        None => Ok(None),
+      }
+    }
+  }
+
+  impl<T: Encode> Encode for Vec<T> {
+    type Repr = Vec<T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.iter().map(Encode::encode).collect()
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      repr.into_iter().map(T::decode).collect()
+    }
+  }
+
+  impl<K, T: Encode> Encode for ::std::collections::HashMap<K, T>
+  where
+    K: ::std::hash::Hash + Eq + Clone + ::serde::Serialize + ::serde::de::DeserializeOwned,
+  {
+    type Repr = ::std::collections::HashMap<K, T::Repr>;
+
+    fn encode(&self) -> Self::Repr {
+      self.iter().map(|(k, v)| (k.clone(), v.encode())).collect()
+    }
+
+    fn decode(repr: Self::Repr) -> Result<Self, String> {
+      repr.into_iter().map(|(k, v)| T::decode(v).map(|v| (k, v))).collect()
+    }
+  }
+
+  pub fn serialize<T: Encode, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ::serde::Serializer,
+  {
+    ::serde::Serialize::serialize(&value.encode(), serializer)
+  }
+
+  pub fn deserialize<'de, T: Encode, D>(deserializer: D) -> Result<T, D::Error>
+  where
+    D: ::serde::Deserializer<'de>,
+  {
+    use ::serde::de::Error;
+
+    let repr = ::serde::Deserialize::deserialize(deserializer)?;
+    T::decode(repr).map_err(D::Error::custom)
+  }
 }
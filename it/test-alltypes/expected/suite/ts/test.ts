@@ -0,0 +1,246 @@
+export class Entry {
+  boolean_type: boolean | null;
+  string_type: string | null;
+  datetime_type: string | null;
+  unsigned_32: number | null;
+  unsigned_64: number | null;
+  signed_32: number | null;
+  signed_64: number | null;
+  float_type: number | null;
+  double_type: number | null;
+  bytes_type: string | null;
+  any_type: any | null;
+  array_type: Entry[] | null;
+  map_type: {[key: string]: Entry} | null;
+  unsigned_8: number | null;
+  unsigned_16: number | null;
+  signed_8: number | null;
+  signed_16: number | null;
+  date_type: string | null;
+  duration_type: string | null;
+  uuid_type: string | null;
+  decimal_type: string | null;
+
+  constructor(boolean_type: boolean | null, string_type: string | null, datetime_type: string | null, unsigned_32: number | null, unsigned_64: number | null, signed_32: number | null, signed_64: number | null, float_type: number | null, double_type: number | null, bytes_type: string | null, any_type: any | null, array_type: Entry[] | null, map_type: {[key: string]: Entry} | null, unsigned_8: number | null, unsigned_16: number | null, signed_8: number | null, signed_16: number | null, date_type: string | null, duration_type: string | null, uuid_type: string | null, decimal_type: string | null) {
+    this.boolean_type = boolean_type;
+    this.string_type = string_type;
+    this.datetime_type = datetime_type;
+    this.unsigned_32 = unsigned_32;
+    this.unsigned_64 = unsigned_64;
+    this.signed_32 = signed_32;
+    this.signed_64 = signed_64;
+    this.float_type = float_type;
+    this.double_type = double_type;
+    this.bytes_type = bytes_type;
+    this.any_type = any_type;
+    this.array_type = array_type;
+    this.map_type = map_type;
+    this.unsigned_8 = unsigned_8;
+    this.unsigned_16 = unsigned_16;
+    this.signed_8 = signed_8;
+    this.signed_16 = signed_16;
+    this.date_type = date_type;
+    this.duration_type = duration_type;
+    this.uuid_type = uuid_type;
+    this.decimal_type = decimal_type;
+  }
+
+  static decode(data: any): Entry {
+    let v_boolean_type: boolean | null = null;
+    if (data["boolean_type"] !== null && data["boolean_type"] !== undefined) {
+      v_boolean_type = data["boolean_type"];
+    }
+
+    let v_string_type: string | null = null;
+    if (data["string_type"] !== null && data["string_type"] !== undefined) {
+      v_string_type = data["string_type"];
+    }
+
+    let v_datetime_type: string | null = null;
+    if (data["datetime_type"] !== null && data["datetime_type"] !== undefined) {
+      v_datetime_type = data["datetime_type"];
+    }
+
+    let v_unsigned_32: number | null = null;
+    if (data["unsigned_32"] !== null && data["unsigned_32"] !== undefined) {
+      v_unsigned_32 = data["unsigned_32"];
+    }
+
+    let v_unsigned_64: number | null = null;
+    if (data["unsigned_64"] !== null && data["unsigned_64"] !== undefined) {
+      v_unsigned_64 = data["unsigned_64"];
+    }
+
+    let v_signed_32: number | null = null;
+    if (data["signed_32"] !== null && data["signed_32"] !== undefined) {
+      v_signed_32 = data["signed_32"];
+    }
+
+    let v_signed_64: number | null = null;
+    if (data["signed_64"] !== null && data["signed_64"] !== undefined) {
+      v_signed_64 = data["signed_64"];
+    }
+
+    let v_float_type: number | null = null;
+    if (data["float_type"] !== null && data["float_type"] !== undefined) {
+      v_float_type = data["float_type"];
+    }
+
+    let v_double_type: number | null = null;
+    if (data["double_type"] !== null && data["double_type"] !== undefined) {
+      v_double_type = data["double_type"];
+    }
+
+    let v_bytes_type: string | null = null;
+    if (data["bytes_type"] !== null && data["bytes_type"] !== undefined) {
+      v_bytes_type = data["bytes_type"];
+    }
+
+    let v_any_type: any | null = null;
+    if (data["any_type"] !== null && data["any_type"] !== undefined) {
+      v_any_type = data["any_type"];
+    }
+
+    let v_array_type: Entry[] | null = null;
+    if (data["array_type"] !== null && data["array_type"] !== undefined) {
+      v_array_type = data["array_type"].map((v: any) => Entry.decode(v));
+    }
+
+    let v_map_type: {[key: string]: Entry} | null = null;
+    if (data["map_type"] !== null && data["map_type"] !== undefined) {
+      v_map_type = ((data: any) => { const o: any = {}; for (let k in data) { o[k] = Entry.decode(data[k]); } return o; })(data["map_type"]);
+    }
+
+    let v_unsigned_8: number | null = null;
+    if (data["unsigned_8"] !== null && data["unsigned_8"] !== undefined) {
+      v_unsigned_8 = data["unsigned_8"];
+    }
+
+    let v_unsigned_16: number | null = null;
+    if (data["unsigned_16"] !== null && data["unsigned_16"] !== undefined) {
+      v_unsigned_16 = data["unsigned_16"];
+    }
+
+    let v_signed_8: number | null = null;
+    if (data["signed_8"] !== null && data["signed_8"] !== undefined) {
+      v_signed_8 = data["signed_8"];
+    }
+
+    let v_signed_16: number | null = null;
+    if (data["signed_16"] !== null && data["signed_16"] !== undefined) {
+      v_signed_16 = data["signed_16"];
+    }
+
+    let v_date_type: string | null = null;
+    if (data["date_type"] !== null && data["date_type"] !== undefined) {
+      v_date_type = data["date_type"];
+    }
+
+    let v_duration_type: string | null = null;
+    if (data["duration_type"] !== null && data["duration_type"] !== undefined) {
+      v_duration_type = data["duration_type"];
+    }
+
+    let v_uuid_type: string | null = null;
+    if (data["uuid_type"] !== null && data["uuid_type"] !== undefined) {
+      v_uuid_type = data["uuid_type"];
+    }
+
+    let v_decimal_type: string | null = null;
+    if (data["decimal_type"] !== null && data["decimal_type"] !== undefined) {
+      v_decimal_type = data["decimal_type"];
+    }
+
+    return new Entry(v_boolean_type, v_string_type, v_datetime_type, v_unsigned_32, v_unsigned_64, v_signed_32, v_signed_64, v_float_type, v_double_type, v_bytes_type, v_any_type, v_array_type, v_map_type, v_unsigned_8, v_unsigned_16, v_signed_8, v_signed_16, v_date_type, v_duration_type, v_uuid_type, v_decimal_type);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.boolean_type !== null && this.boolean_type !== undefined) {
+      data["boolean_type"] = this.boolean_type;
+    }
+
+    if (this.string_type !== null && this.string_type !== undefined) {
+      data["string_type"] = this.string_type;
+    }
+
+    if (this.datetime_type !== null && this.datetime_type !== undefined) {
+      data["datetime_type"] = this.datetime_type;
+    }
+
+    if (this.unsigned_32 !== null && this.unsigned_32 !== undefined) {
+      data["unsigned_32"] = this.unsigned_32;
+    }
+
+    if (this.unsigned_64 !== null && this.unsigned_64 !== undefined) {
+      data["unsigned_64"] = this.unsigned_64;
+    }
+
+    if (this.signed_32 !== null && this.signed_32 !== undefined) {
+      data["signed_32"] = this.signed_32;
+    }
+
+    if (this.signed_64 !== null && this.signed_64 !== undefined) {
+      data["signed_64"] = this.signed_64;
+    }
+
+    if (this.float_type !== null && this.float_type !== undefined) {
+      data["float_type"] = this.float_type;
+    }
+
+    if (this.double_type !== null && this.double_type !== undefined) {
+      data["double_type"] = this.double_type;
+    }
+
+    if (this.bytes_type !== null && this.bytes_type !== undefined) {
+      data["bytes_type"] = this.bytes_type;
+    }
+
+    if (this.any_type !== null && this.any_type !== undefined) {
+      data["any_type"] = this.any_type;
+    }
+
+    if (this.array_type !== null && this.array_type !== undefined) {
+      data["array_type"] = this.array_type.map((v: any) => v.encode());
+    }
+
+    if (this.map_type !== null && this.map_type !== undefined) {
+      data["map_type"] = ((data: any) => { const o: any = {}; for (let k in data) { o[k] = data[k].encode(); } return o; })(this.map_type);
+    }
+
+    if (this.unsigned_8 !== null && this.unsigned_8 !== undefined) {
+      data["unsigned_8"] = this.unsigned_8;
+    }
+
+    if (this.unsigned_16 !== null && this.unsigned_16 !== undefined) {
+      data["unsigned_16"] = this.unsigned_16;
+    }
+
+    if (this.signed_8 !== null && this.signed_8 !== undefined) {
+      data["signed_8"] = this.signed_8;
+    }
+
+    if (this.signed_16 !== null && this.signed_16 !== undefined) {
+      data["signed_16"] = this.signed_16;
+    }
+
+    if (this.date_type !== null && this.date_type !== undefined) {
+      data["date_type"] = this.date_type;
+    }
+
+    if (this.duration_type !== null && this.duration_type !== undefined) {
+      data["duration_type"] = this.duration_type;
+    }
+
+    if (this.uuid_type !== null && this.uuid_type !== undefined) {
+      data["uuid_type"] = this.uuid_type;
+    }
+
+    if (this.decimal_type !== null && this.decimal_type !== undefined) {
+      data["decimal_type"] = this.decimal_type;
+    }
+
+    return data;
+  }
+}
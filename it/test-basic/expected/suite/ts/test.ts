@@ -0,0 +1,113 @@
+export class Entry {
+  foo: Foo | null;
+
+  constructor(foo: Foo | null) {
+    this.foo = foo;
+  }
+
+  static decode(data: any): Entry {
+    let v_foo: Foo | null = null;
+    if (data["foo"] !== null && data["foo"] !== undefined) {
+      v_foo = Foo.decode(data["foo"]);
+    }
+
+    return new Entry(v_foo);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.foo !== null && this.foo !== undefined) {
+      data["foo"] = this.foo.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Foo {
+  field: string;
+
+  constructor(field: string) {
+    this.field = field;
+  }
+
+  static decode(data: any): Foo {
+    if (data["field"] === null || data["field"] === undefined) {
+      throw new Error("field: required field");
+    }
+    const v_field: string = data["field"];
+
+    return new Foo(v_field);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.field === null || this.field === undefined) {
+      throw new Error("field: is a required field");
+    }
+
+    data["field"] = this.field;
+
+    return data;
+  }
+}
+
+export class Bar {
+  field: Bar_Inner;
+
+  constructor(field: Bar_Inner) {
+    this.field = field;
+  }
+
+  static decode(data: any): Bar {
+    if (data["field"] === null || data["field"] === undefined) {
+      throw new Error("field: required field");
+    }
+    const v_field: Bar_Inner = Bar_Inner.decode(data["field"]);
+
+    return new Bar(v_field);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.field === null || this.field === undefined) {
+      throw new Error("field: is a required field");
+    }
+
+    data["field"] = this.field.encode();
+
+    return data;
+  }
+}
+
+export class Bar_Inner {
+  field: string;
+
+  constructor(field: string) {
+    this.field = field;
+  }
+
+  static decode(data: any): Bar_Inner {
+    if (data["field"] === null || data["field"] === undefined) {
+      throw new Error("field: required field");
+    }
+    const v_field: string = data["field"];
+
+    return new Bar_Inner(v_field);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.field === null || this.field === undefined) {
+      throw new Error("field: is a required field");
+    }
+
+    data["field"] = this.field;
+
+    return data;
+  }
+}
@@ -0,0 +1,17 @@
+export class Type {
+  constructor() {
+  }
+
+  static decode(data: any): Type {
+    return new Type();
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    return data;
+  }
+
+  foo(): void {
+  }
+}
@@ -0,0 +1,175 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.regex.Pattern;
+
+public class Entry {
+  @JsonProperty("name")
+  private final Optional<String> name;
+  @JsonProperty("age")
+  private final Optional<Integer> age;
+  @JsonProperty("tags")
+  private final Optional<List<String>> tags;
+  @JsonProperty("code")
+  private final Optional<String> code;
+
+  @JsonCreator
+  public Entry(
+    @JsonProperty("name") final Optional<String> name,
+    @JsonProperty("age") final Optional<Integer> age,
+    @JsonProperty("tags") final Optional<List<String>> tags,
+    @JsonProperty("code") final Optional<String> code
+  ) {
+    Objects.requireNonNull(name, "name");
+    if (name.isPresent()) {
+      if (name.get().length() < 1) {
+        throw new IllegalArgumentException("name: length must be at least 1");
+      }
+      if (name.get().length() > 8) {
+        throw new IllegalArgumentException("name: length must be at most 8");
+      }
+    }
+    this.name = name;
+    Objects.requireNonNull(age, "age");
+    if (age.isPresent()) {
+      if (age.get() > 150) {
+        throw new IllegalArgumentException("age: must be at most 150");
+      }
+    }
+    this.age = age;
+    Objects.requireNonNull(tags, "tags");
+    if (tags.isPresent()) {
+      if (tags.get().size() > 2) {
+        throw new IllegalArgumentException("tags: must have at most 2 item(s)");
+      }
+    }
+    this.tags = tags;
+    Objects.requireNonNull(code, "code");
+    if (code.isPresent()) {
+      if (!Pattern.compile("^[a-z]+$").matcher(code.get()).find()) {
+        throw new IllegalArgumentException("code: must match the pattern ^[a-z]+$");
+      }
+    }
+    this.code = code;
+  }
+
+  public Optional<String> getName() {
+    return this.name;
+  }
+
+  public Optional<Integer> getAge() {
+    return this.age;
+  }
+
+  public Optional<List<String>> getTags() {
+    return this.tags;
+  }
+
+  public Optional<String> getCode() {
+    return this.code;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.name.hashCode();
+    result = result * 31 + this.age.hashCode();
+    result = result * 31 + this.tags.hashCode();
+    result = result * 31 + this.code.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Entry)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Entry o = (Entry) other;
+
+    if (!this.name.equals(o.name)) {
+      return false;
+    }
+
+    if (!this.age.equals(o.age)) {
+      return false;
+    }
+
+    if (!this.tags.equals(o.tags)) {
+      return false;
+    }
+
+    if (!this.code.equals(o.code)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Entry");
+    b.append("(");
+    b.append("name=");
+    b.append(this.name.toString());
+    b.append(", ");
+    b.append("age=");
+    b.append(this.age.toString());
+    b.append(", ");
+    b.append("tags=");
+    b.append(this.tags.toString());
+    b.append(", ");
+    b.append("code=");
+    b.append(this.code.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<String> name = Optional.empty();
+    private Optional<Integer> age = Optional.empty();
+    private Optional<List<String>> tags = Optional.empty();
+    private Optional<String> code = Optional.empty();
+
+    public Builder name(final String name) {
+      this.name = Optional.of(name);
+      return this;
+    }
+
+    public Builder age(final Integer age) {
+      this.age = Optional.of(age);
+      return this;
+    }
+
+    public Builder tags(final List<String> tags) {
+      this.tags = Optional.of(tags);
+      return this;
+    }
+
+    public Builder code(final String code) {
+      this.code = Optional.of(code);
+      return this;
+    }
+
+    public Entry build() {
+      final Optional<String> name = this.name;
+      final Optional<Integer> age = this.age;
+      final Optional<List<String>> tags = this.tags;
+      final Optional<String> code = this.code;
+
+      return new Entry(name, age, tags, code);
+    }
+  }
+}
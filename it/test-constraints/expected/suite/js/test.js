@@ -0,0 +1,93 @@
+export class Entry {
+  constructor(name, age, tags, code) {
+    this.name = name;
+    this.age = age;
+    this.tags = tags;
+    this.code = code;
+  }
+
+  static decode(data) {
+    let v_name = data["name"];
+
+    if (v_name !== null && v_name !== undefined) {
+      v_name = v_name;
+    } else {
+      v_name = null;
+    }
+
+    if (v_name !== null) {
+      if (v_name.length < 1) {
+        throw new Error("name: length must be at least 1");
+      }
+      if (v_name.length > 8) {
+        throw new Error("name: length must be at most 8");
+      }
+    }
+
+    let v_age = data["age"];
+
+    if (v_age !== null && v_age !== undefined) {
+      v_age = v_age;
+    } else {
+      v_age = null;
+    }
+
+    if (v_age !== null) {
+      if (v_age > 150) {
+        throw new Error("age: must be at most 150");
+      }
+    }
+
+    let v_tags = data["tags"];
+
+    if (v_tags !== null && v_tags !== undefined) {
+      v_tags = v_tags;
+    } else {
+      v_tags = null;
+    }
+
+    if (v_tags !== null) {
+      if (v_tags.length > 2) {
+        throw new Error("tags: must have at most 2 item(s)");
+      }
+    }
+
+    let v_code = data["code"];
+
+    if (v_code !== null && v_code !== undefined) {
+      v_code = v_code;
+    } else {
+      v_code = null;
+    }
+
+    if (v_code !== null) {
+      if (!new RegExp("^[a-z]+$").test(v_code)) {
+        throw new Error("code: must match the pattern ^[a-z]+$");
+      }
+    }
+
+    return new Entry(v_name, v_age, v_tags, v_code);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name !== null && this.name !== undefined) {
+      data["name"] = this.name;
+    }
+
+    if (this.age !== null && this.age !== undefined) {
+      data["age"] = this.age;
+    }
+
+    if (this.tags !== null && this.tags !== undefined) {
+      data["tags"] = this.tags;
+    }
+
+    if (this.code !== null && this.code !== undefined) {
+      data["code"] = this.code;
+    }
+
+    return data;
+  }
+}
@@ -0,0 +1,82 @@
+import re
+
+class Entry:
+  def __init__(self, name, age, tags, code):
+    self.name = name
+    self.age = age
+    self.tags = tags
+    self.code = code
+
+  @staticmethod
+  def decode(data):
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        f_name = f_name
+    else:
+      f_name = None
+
+    if f_name is not None:
+      if len(f_name) < 1:
+        raise Exception("name: length must be at least 1")
+      if len(f_name) > 8:
+        raise Exception("name: length must be at most 8")
+
+    if "age" in data:
+      f_age = data["age"]
+
+      if f_age is not None:
+        f_age = f_age
+    else:
+      f_age = None
+
+    if f_age is not None:
+      if f_age > 150:
+        raise Exception("age: must be at most 150")
+
+    if "tags" in data:
+      f_tags = data["tags"]
+
+      if f_tags is not None:
+        f_tags = f_tags
+    else:
+      f_tags = None
+
+    if f_tags is not None:
+      if len(f_tags) > 2:
+        raise Exception("tags: must have at most 2 item(s)")
+
+    if "code" in data:
+      f_code = data["code"]
+
+      if f_code is not None:
+        f_code = f_code
+    else:
+      f_code = None
+
+    if f_code is not None:
+      if re.search("^[a-z]+$", f_code) is None:
+        raise Exception("code: must match the pattern ^[a-z]+$")
+
+    return Entry(f_name, f_age, f_tags, f_code)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    if self.age is not None:
+      data["age"] = self.age
+
+    if self.tags is not None:
+      data["tags"] = self.tags
+
+    if self.code is not None:
+      data["code"] = self.code
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name: {!r}, age: {!r}, tags: {!r}, code: {!r}>".format(self.name, self.age, self.tags, self.code)
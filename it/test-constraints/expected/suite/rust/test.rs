@@ -0,0 +1,91 @@
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default)]
+  #[serde(deserialize_with = "deserialize_entry_name")]
+  name: Option<String>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default)]
+  #[serde(deserialize_with = "deserialize_entry_age")]
+  age: Option<u32>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default)]
+  #[serde(deserialize_with = "deserialize_entry_tags")]
+  tags: Option<Vec<String>>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default)]
+  #[serde(deserialize_with = "deserialize_entry_code")]
+  code: Option<String>,
+}
+
+fn deserialize_entry_name<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: ::serde::Deserializer<'de>,
+{
+  use ::serde::de::Error;
+
+  let out: Option<String> = ::serde::Deserialize::deserialize(deserializer)?;
+
+  if let Some(value) = out.as_ref() {
+    if value.chars().count() < 1 {
+      return Err(D::Error::custom("name: length must be at least 1"));
+    }
+
+    if value.chars().count() > 8 {
+      return Err(D::Error::custom("name: length must be at most 8"));
+    }
+  }
+
+  Ok(out)
+}
+
+fn deserialize_entry_age<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+  D: ::serde::Deserializer<'de>,
+{
+  use ::serde::de::Error;
+
+  let out: Option<u32> = ::serde::Deserialize::deserialize(deserializer)?;
+
+  if let Some(value) = out.as_ref() {
+    if *value > 150 {
+      return Err(D::Error::custom("age: must be at most 150"));
+    }
+  }
+
+  Ok(out)
+}
+
+fn deserialize_entry_tags<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+  D: ::serde::Deserializer<'de>,
+{
+  use ::serde::de::Error;
+
+  let out: Option<Vec<String>> = ::serde::Deserialize::deserialize(deserializer)?;
+
+  if let Some(value) = out.as_ref() {
+    if value.len() > 2 {
+      return Err(D::Error::custom("tags: must have at most 2 item(s)"));
+    }
+  }
+
+  Ok(out)
+}
+
+fn deserialize_entry_code<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: ::serde::Deserializer<'de>,
+{
+  use ::serde::de::Error;
+
+  let out: Option<String> = ::serde::Deserialize::deserialize(deserializer)?;
+
+  if let Some(value) = out.as_ref() {
+    if !::regex::Regex::new("^[a-z]+$").map_err(D::Error::custom)?.is_match(value) {
+      return Err(D::Error::custom("code: must match the pattern ^[a-z]+$"));
+    }
+  }
+
+  Ok(out)
+}
@@ -0,0 +1,150 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Entry {
+  @JsonProperty("size")
+  private final Optional<Size> size;
+  @JsonProperty("sort")
+  private final Optional<Sort> sort;
+  @JsonProperty("tags")
+  private final Optional<Tags> tags;
+  @JsonProperty("ratio")
+  private final Optional<Ratio> ratio;
+
+  @JsonCreator
+  public Entry(
+    @JsonProperty("size") final Optional<Size> size,
+    @JsonProperty("sort") final Optional<Sort> sort,
+    @JsonProperty("tags") final Optional<Tags> tags,
+    @JsonProperty("ratio") final Optional<Ratio> ratio
+  ) {
+    Objects.requireNonNull(size, "size");
+    this.size = size;
+    Objects.requireNonNull(sort, "sort");
+    this.sort = sort;
+    Objects.requireNonNull(tags, "tags");
+    this.tags = tags;
+    Objects.requireNonNull(ratio, "ratio");
+    this.ratio = ratio;
+  }
+
+  public Optional<Size> getSize() {
+    return this.size;
+  }
+
+  public Optional<Sort> getSort() {
+    return this.sort;
+  }
+
+  public Optional<Tags> getTags() {
+    return this.tags;
+  }
+
+  public Optional<Ratio> getRatio() {
+    return this.ratio;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.size.hashCode();
+    result = result * 31 + this.sort.hashCode();
+    result = result * 31 + this.tags.hashCode();
+    result = result * 31 + this.ratio.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Entry)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Entry o = (Entry) other;
+
+    if (!this.size.equals(o.size)) {
+      return false;
+    }
+
+    if (!this.sort.equals(o.sort)) {
+      return false;
+    }
+
+    if (!this.tags.equals(o.tags)) {
+      return false;
+    }
+
+    if (!this.ratio.equals(o.ratio)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Entry");
+    b.append("(");
+    b.append("size=");
+    b.append(this.size.toString());
+    b.append(", ");
+    b.append("sort=");
+    b.append(this.sort.toString());
+    b.append(", ");
+    b.append("tags=");
+    b.append(this.tags.toString());
+    b.append(", ");
+    b.append("ratio=");
+    b.append(this.ratio.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<Size> size = Optional.empty();
+    private Optional<Sort> sort = Optional.empty();
+    private Optional<Tags> tags = Optional.empty();
+    private Optional<Ratio> ratio = Optional.empty();
+
+    public Builder size(final Size size) {
+      this.size = Optional.of(size);
+      return this;
+    }
+
+    public Builder sort(final Sort sort) {
+      this.sort = Optional.of(sort);
+      return this;
+    }
+
+    public Builder tags(final Tags tags) {
+      this.tags = Optional.of(tags);
+      return this;
+    }
+
+    public Builder ratio(final Ratio ratio) {
+      this.ratio = Optional.of(ratio);
+      return this;
+    }
+
+    public Entry build() {
+      final Optional<Size> size = this.size;
+      final Optional<Sort> sort = this.sort;
+      final Optional<Tags> tags = this.tags;
+      final Optional<Ratio> ratio = this.ratio;
+
+      return new Entry(size, sort, tags, ratio);
+    }
+  }
+}
@@ -0,0 +1,78 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Ratio {
+  @JsonProperty("value")
+  private final Optional<Double> value;
+
+  @JsonCreator
+  public Ratio(
+    @JsonProperty("value") final Optional<Double> value
+  ) {
+    Objects.requireNonNull(value, "value");
+    this.value = value.isPresent() ? value : Optional.of(0.5d);
+  }
+
+  public Optional<Double> getValue() {
+    return this.value;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.value.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Ratio)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Ratio o = (Ratio) other;
+
+    if (!this.value.equals(o.value)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Ratio");
+    b.append("(");
+    b.append("value=");
+    b.append(this.value.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<Double> value = Optional.empty();
+
+    public Builder value(final Double value) {
+      this.value = Optional.of(value);
+      return this;
+    }
+
+    public Ratio build() {
+      final Optional<Double> value = this.value;
+
+      return new Ratio(value);
+    }
+  }
+}
@@ -0,0 +1,78 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Size {
+  @JsonProperty("value")
+  private final Optional<Integer> value;
+
+  @JsonCreator
+  public Size(
+    @JsonProperty("value") final Optional<Integer> value
+  ) {
+    Objects.requireNonNull(value, "value");
+    this.value = value.isPresent() ? value : Optional.of(100);
+  }
+
+  public Optional<Integer> getValue() {
+    return this.value;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.value.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Size)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Size o = (Size) other;
+
+    if (!this.value.equals(o.value)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Size");
+    b.append("(");
+    b.append("value=");
+    b.append(this.value.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<Integer> value = Optional.empty();
+
+    public Builder value(final Integer value) {
+      this.value = Optional.of(value);
+      return this;
+    }
+
+    public Size build() {
+      final Optional<Integer> value = this.value;
+
+      return new Size(value);
+    }
+  }
+}
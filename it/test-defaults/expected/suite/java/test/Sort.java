@@ -0,0 +1,78 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Sort {
+  @JsonProperty("value")
+  private final Optional<String> value;
+
+  @JsonCreator
+  public Sort(
+    @JsonProperty("value") final Optional<String> value
+  ) {
+    Objects.requireNonNull(value, "value");
+    this.value = value.isPresent() ? value : Optional.of("name");
+  }
+
+  public Optional<String> getValue() {
+    return this.value;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.value.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Sort)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Sort o = (Sort) other;
+
+    if (!this.value.equals(o.value)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Sort");
+    b.append("(");
+    b.append("value=");
+    b.append(this.value.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<String> value = Optional.empty();
+
+    public Builder value(final String value) {
+      this.value = Optional.of(value);
+      return this;
+    }
+
+    public Sort build() {
+      final Optional<String> value = this.value;
+
+      return new Sort(value);
+    }
+  }
+}
@@ -0,0 +1,80 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Arrays;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Tags {
+  @JsonProperty("value")
+  private final Optional<List<String>> value;
+
+  @JsonCreator
+  public Tags(
+    @JsonProperty("value") final Optional<List<String>> value
+  ) {
+    Objects.requireNonNull(value, "value");
+    this.value = value.isPresent() ? value : Optional.of(Arrays.asList("all"));
+  }
+
+  public Optional<List<String>> getValue() {
+    return this.value;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.value.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Tags)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Tags o = (Tags) other;
+
+    if (!this.value.equals(o.value)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Tags");
+    b.append("(");
+    b.append("value=");
+    b.append(this.value.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<List<String>> value = Optional.empty();
+
+    public Builder value(final List<String> value) {
+      this.value = Optional.of(value);
+      return this;
+    }
+
+    public Tags build() {
+      final Optional<List<String>> value = this.value;
+
+      return new Tags(value);
+    }
+  }
+}
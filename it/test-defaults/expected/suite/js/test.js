@@ -0,0 +1,178 @@
+export class Entry {
+  constructor(size, sort, tags, ratio) {
+    this.size = size;
+    this.sort = sort;
+    this.tags = tags;
+    this.ratio = ratio;
+  }
+
+  static decode(data) {
+    let v_size = data["size"];
+
+    if (v_size !== null && v_size !== undefined) {
+      v_size = Size.decode(v_size);
+    } else {
+      v_size = null;
+    }
+
+    let v_sort = data["sort"];
+
+    if (v_sort !== null && v_sort !== undefined) {
+      v_sort = Sort.decode(v_sort);
+    } else {
+      v_sort = null;
+    }
+
+    let v_tags = data["tags"];
+
+    if (v_tags !== null && v_tags !== undefined) {
+      v_tags = Tags.decode(v_tags);
+    } else {
+      v_tags = null;
+    }
+
+    let v_ratio = data["ratio"];
+
+    if (v_ratio !== null && v_ratio !== undefined) {
+      v_ratio = Ratio.decode(v_ratio);
+    } else {
+      v_ratio = null;
+    }
+
+    return new Entry(v_size, v_sort, v_tags, v_ratio);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.size !== null && this.size !== undefined) {
+      data["size"] = this.size.encode();
+    }
+
+    if (this.sort !== null && this.sort !== undefined) {
+      data["sort"] = this.sort.encode();
+    }
+
+    if (this.tags !== null && this.tags !== undefined) {
+      data["tags"] = this.tags.encode();
+    }
+
+    if (this.ratio !== null && this.ratio !== undefined) {
+      data["ratio"] = this.ratio.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Size {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value !== null && v_value !== undefined) {
+      v_value = v_value;
+    } else {
+      v_value = 100;
+    }
+
+    return new Size(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value !== null && this.value !== undefined) {
+      data["value"] = this.value;
+    }
+
+    return data;
+  }
+}
+
+export class Sort {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value !== null && v_value !== undefined) {
+      v_value = v_value;
+    } else {
+      v_value = "name";
+    }
+
+    return new Sort(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value !== null && this.value !== undefined) {
+      data["value"] = this.value;
+    }
+
+    return data;
+  }
+}
+
+export class Tags {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value !== null && v_value !== undefined) {
+      v_value = v_value;
+    } else {
+      v_value = ["all"];
+    }
+
+    return new Tags(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value !== null && this.value !== undefined) {
+      data["value"] = this.value;
+    }
+
+    return data;
+  }
+}
+
+export class Ratio {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value !== null && v_value !== undefined) {
+      v_value = v_value;
+    } else {
+      v_value = 0.5;
+    }
+
+    return new Ratio(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value !== null && this.value !== undefined) {
+      data["value"] = this.value;
+    }
+
+    return data;
+  }
+}
@@ -0,0 +1,182 @@
+class Entry:
+  def __init__(self, size, sort, tags, ratio):
+    self.size = size
+    self.sort = sort
+    self.tags = tags
+    self.ratio = ratio
+
+  @staticmethod
+  def decode(data):
+    if "size" in data:
+      f_size = data["size"]
+
+      if f_size is not None:
+        f_size = Size.decode(f_size)
+    else:
+      f_size = None
+
+    if "sort" in data:
+      f_sort = data["sort"]
+
+      if f_sort is not None:
+        f_sort = Sort.decode(f_sort)
+    else:
+      f_sort = None
+
+    if "tags" in data:
+      f_tags = data["tags"]
+
+      if f_tags is not None:
+        f_tags = Tags.decode(f_tags)
+    else:
+      f_tags = None
+
+    if "ratio" in data:
+      f_ratio = data["ratio"]
+
+      if f_ratio is not None:
+        f_ratio = Ratio.decode(f_ratio)
+    else:
+      f_ratio = None
+
+    return Entry(f_size, f_sort, f_tags, f_ratio)
+
+  def encode(self):
+    data = dict()
+
+    if self.size is not None:
+      data["size"] = self.size.encode()
+
+    if self.sort is not None:
+      data["sort"] = self.sort.encode()
+
+    if self.tags is not None:
+      data["tags"] = self.tags.encode()
+
+    if self.ratio is not None:
+      data["ratio"] = self.ratio.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry size: {!r}, sort: {!r}, tags: {!r}, ratio: {!r}>".format(self.size, self.sort, self.tags, self.ratio)
+
+class Size:
+  def __init__(self, value):
+    self.value = value
+
+  @staticmethod
+  def decode(data):
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        f_value = f_value
+    else:
+      f_value = None
+
+    if f_value is None:
+      f_value = 100
+
+    return Size(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Size value: {!r}>".format(self.value)
+
+class Sort:
+  def __init__(self, value):
+    self.value = value
+
+  @staticmethod
+  def decode(data):
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        f_value = f_value
+    else:
+      f_value = None
+
+    if f_value is None:
+      f_value = "name"
+
+    return Sort(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Sort value: {!r}>".format(self.value)
+
+class Tags:
+  def __init__(self, value):
+    self.value = value
+
+  @staticmethod
+  def decode(data):
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        f_value = f_value
+    else:
+      f_value = None
+
+    if f_value is None:
+      f_value = ["all"]
+
+    return Tags(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Tags value: {!r}>".format(self.value)
+
+class Ratio:
+  def __init__(self, value):
+    self.value = value
+
+  @staticmethod
+  def decode(data):
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        f_value = f_value
+    else:
+      f_value = None
+
+    if f_value is None:
+      f_value = 0.5
+
+    return Ratio(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Ratio value: {!r}>".format(self.value)
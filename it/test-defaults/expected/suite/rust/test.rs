@@ -0,0 +1,55 @@
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  size: Option<Size>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  sort: Option<Sort>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  tags: Option<Tags>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  ratio: Option<Ratio>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Size {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "default_size_value")]
+  value: Option<u32>,
+}
+
+fn default_size_value() -> Option<u32> {
+  Some(100)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Sort {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "default_sort_value")]
+  value: Option<String>,
+}
+
+fn default_sort_value() -> Option<String> {
+  Some("name".to_string())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Tags {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "default_tags_value")]
+  value: Option<Vec<String>>,
+}
+
+fn default_tags_value() -> Option<Vec<String>> {
+  Some(vec!["all".to_string()])
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Ratio {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "default_ratio_value")]
+  value: Option<f64>,
+}
+
+fn default_ratio_value() -> Option<f64> {
+  Some(0.5f64)
+}
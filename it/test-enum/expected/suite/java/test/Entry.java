@@ -10,16 +10,21 @@ public class Entry {
   private final Optional<EnumExplicit> explicit;
   @JsonProperty("implicit")
   private final Optional<EnumImplicit> implicit;
+  @JsonProperty("numeric")
+  private final Optional<EnumNumeric> numeric;
 
   @JsonCreator
   public Entry(
     @JsonProperty("explicit") final Optional<EnumExplicit> explicit,
-    @JsonProperty("implicit") final Optional<EnumImplicit> implicit
+    @JsonProperty("implicit") final Optional<EnumImplicit> implicit,
+    @JsonProperty("numeric") final Optional<EnumNumeric> numeric
   ) {
     Objects.requireNonNull(explicit, "explicit");
     this.explicit = explicit;
     Objects.requireNonNull(implicit, "implicit");
     this.implicit = implicit;
+    Objects.requireNonNull(numeric, "numeric");
+    this.numeric = numeric;
   }
 
   public Optional<EnumExplicit> getExplicit() {
@@ -30,11 +35,16 @@ public class Entry {
     return this.implicit;
   }
 
+  public Optional<EnumNumeric> getNumeric() {
+    return this.numeric;
+  }
+
   @Override
   public int hashCode() {
     int result = 1;
     result = result * 31 + this.explicit.hashCode();
     result = result * 31 + this.implicit.hashCode();
+    result = result * 31 + this.numeric.hashCode();
     return result;
   }
 
@@ -59,6 +69,10 @@ public class Entry {
       return false;
     }
 
+    if (!this.numeric.equals(o.numeric)) {
+      return false;
+    }
+
     return true;
   }
 
@@ -73,6 +87,9 @@ public class Entry {
     b.append(", ");
     b.append("implicit=");
     b.append(this.implicit.toString());
+    b.append(", ");
+    b.append("numeric=");
+    b.append(this.numeric.toString());
     b.append(")");
 
     return b.toString();
@@ -81,6 +98,7 @@ public class Entry {
   public static class Builder {
     private Optional<EnumExplicit> explicit = Optional.empty();
     private Optional<EnumImplicit> implicit = Optional.empty();
+    private Optional<EnumNumeric> numeric = Optional.empty();
 
     public Builder explicit(final EnumExplicit explicit) {
       this.explicit = Optional.of(explicit);
@@ -92,11 +110,17 @@ public class Entry {
       return this;
     }
 
+    public Builder numeric(final EnumNumeric numeric) {
+      this.numeric = Optional.of(numeric);
+      return this;
+    }
+
     public Entry build() {
       final Optional<EnumExplicit> explicit = this.explicit;
       final Optional<EnumImplicit> implicit = this.implicit;
+      final Optional<EnumNumeric> numeric = this.numeric;
 
-      return new Entry(explicit, implicit);
+      return new Entry(explicit, implicit, numeric);
     }
   }
 }
@@ -0,0 +1,33 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum EnumNumeric {
+  A(1),
+  B(2);
+
+  private final int value;
+
+  private EnumNumeric(
+    final int value
+  ) {
+    this.value = value;
+  }
+
+  @JsonCreator
+  public static EnumNumeric fromValue(final int value) {
+    for (final EnumNumeric v_value : values()) {
+      if (v_value.value == value) {
+        return v_value;
+      }
+    }
+
+    throw new IllegalArgumentException("value");
+  }
+
+  @JsonValue
+  public int toValue() {
+    return this.value;
+  }
+}
@@ -1,8 +1,9 @@
 
 export class Entry {
-  constructor(explicit, implicit) {
+  constructor(explicit, implicit, numeric) {
     this.explicit = explicit;
     this.implicit = implicit;
+    this.numeric = numeric;
   }
 
   static decode(data) {
@@ -22,7 +23,15 @@ export class Entry {
       v_implicit = null;
     }
 
-    return new Entry(v_explicit, v_implicit);
+    let v_numeric = data["numeric"];
+
+    if (v_numeric !== null && v_numeric !== undefined) {
+      v_numeric = EnumNumeric.decode(v_numeric);
+    } else {
+      v_numeric = null;
+    }
+
+    return new Entry(v_explicit, v_implicit, v_numeric);
   }
 
   encode() {
@@ -36,6 +45,10 @@ export class Entry {
       data["implicit"] = this.implicit.encode();
     }
 
+    if (this.numeric !== null && this.numeric !== undefined) {
+      data["numeric"] = this.numeric.encode();
+    }
+
     return data;
   }
 }
@@ -97,3 +110,32 @@ EnumImplicit.A = new EnumImplicit("A", "A");
 EnumImplicit.B = new EnumImplicit("B", "B");
 
 EnumImplicit.values = [EnumImplicit.A, EnumImplicit.B];
+
+export class EnumNumeric {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+  static decode(data) {
+    for (let i = 0, l = EnumNumeric.values.length; i < l; i++) {
+      const member = EnumNumeric.values[i]
+
+
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+}
+
+EnumNumeric.A = new EnumNumeric("A", 1);
+EnumNumeric.B = new EnumNumeric("B", 2);
+
+EnumNumeric.values = [EnumNumeric.A, EnumNumeric.B];
@@ -1,9 +1,10 @@
 import enum
 
 class Entry:
-  def __init__(self, explicit, implicit):
+  def __init__(self, explicit, implicit, numeric):
     self.explicit = explicit
     self.implicit = implicit
+    self.numeric = numeric
 
   @staticmethod
   def decode(data):
@@ -23,7 +24,15 @@ class Entry:
     else:
       f_implicit = None
 
-    return Entry(f_explicit, f_implicit)
+    if "numeric" in data:
+      f_numeric = data["numeric"]
+
+      if f_numeric is not None:
+        f_numeric = EnumNumeric.decode(f_numeric)
+    else:
+      f_numeric = None
+
+    return Entry(f_explicit, f_implicit, f_numeric)
 
   def encode(self):
     data = dict()
@@ -34,10 +43,13 @@ class Entry:
     if self.implicit is not None:
       data["implicit"] = self.implicit.encode()
 
+    if self.numeric is not None:
+      data["numeric"] = self.numeric.encode()
+
     return data
 
   def __repr__(self):
-    return "<Entry explicit: {!r}, implicit: {!r}>".format(self.explicit, self.implicit)
+    return "<Entry explicit: {!r}, implicit: {!r}, numeric: {!r}>".format(self.explicit, self.implicit, self.numeric)
 
 class EnumExplicit:
   def __init__(self, _value):
@@ -75,6 +87,26 @@ class EnumImplicit:
   def __repr__(self):
     return "<EnumImplicit _value: {!r}>".format(self._value)
 
+class EnumNumeric:
+  def __init__(self, _value):
+    self._value = _value
+
+  def encode(self):
+    return self._value
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._value == data:
+        return value
+
+    raise Exception("data does not match enum")
+
+  def __repr__(self):
+    return "<EnumNumeric _value: {!r}>".format(self._value)
+
 EnumExplicit = enum.Enum("EnumExplicit", [("A", "foo"), ("B", "bar")], type=EnumExplicit)
 
 EnumImplicit = enum.Enum("EnumImplicit", [("A", "A"), ("B", "B")], type=EnumImplicit)
+
+EnumNumeric = enum.Enum("EnumNumeric", [("A", 1), ("B", 2)], type=EnumNumeric)
@@ -4,6 +4,8 @@ pub struct Entry {
   explicit: Option<EnumExplicit>,
   #[serde(skip_serializing_if="Option::is_none")]
   implicit: Option<EnumImplicit>,
+  #[serde(skip_serializing_if="Option::is_none")]
+  numeric: Option<EnumNumeric>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -39,3 +41,45 @@ impl EnumImplicit {
     }
   }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumNumeric {
+  A,
+  B,
+}
+
+impl EnumNumeric {
+  pub fn value(&self) -> u32 {
+    use self::EnumNumeric::*;
+    match *self {
+      A => 1,
+      B => 2,
+    }
+  }
+}
+
+impl ::serde::Serialize for EnumNumeric {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ::serde::Serializer,
+  {
+    ::serde::Serialize::serialize(&self.value(), serializer)
+  }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnumNumeric {
+  fn deserialize<D>(deserializer: D) -> Result<EnumNumeric, D::Error>
+  where
+    D: ::serde::Deserializer<'de>,
+  {
+    use ::serde::de::Error;
+
+    let value: u32 = ::serde::Deserialize::deserialize(deserializer)?;
+
+    match value {
+      1 => Ok(EnumNumeric::A),
+      2 => Ok(EnumNumeric::B),
+      _ => Err(D::Error::custom(format!("unknown value: {}", value))),
+    }
+  }
+}
@@ -0,0 +1,135 @@
+export class Entry {
+  explicit: EnumExplicit | null;
+  implicit: EnumImplicit | null;
+  numeric: EnumNumeric | null;
+
+  constructor(explicit: EnumExplicit | null, implicit: EnumImplicit | null, numeric: EnumNumeric | null) {
+    this.explicit = explicit;
+    this.implicit = implicit;
+    this.numeric = numeric;
+  }
+
+  static decode(data: any): Entry {
+    let v_explicit: EnumExplicit | null = null;
+    if (data["explicit"] !== null && data["explicit"] !== undefined) {
+      v_explicit = EnumExplicit.decode(data["explicit"]);
+    }
+
+    let v_implicit: EnumImplicit | null = null;
+    if (data["implicit"] !== null && data["implicit"] !== undefined) {
+      v_implicit = EnumImplicit.decode(data["implicit"]);
+    }
+
+    let v_numeric: EnumNumeric | null = null;
+    if (data["numeric"] !== null && data["numeric"] !== undefined) {
+      v_numeric = EnumNumeric.decode(data["numeric"]);
+    }
+
+    return new Entry(v_explicit, v_implicit, v_numeric);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.explicit !== null && this.explicit !== undefined) {
+      data["explicit"] = this.explicit.encode();
+    }
+
+    if (this.implicit !== null && this.implicit !== undefined) {
+      data["implicit"] = this.implicit.encode();
+    }
+
+    if (this.numeric !== null && this.numeric !== undefined) {
+      data["numeric"] = this.numeric.encode();
+    }
+
+    return data;
+  }
+}
+
+export class EnumExplicit {
+  static A: EnumExplicit = new EnumExplicit("A", "foo");
+  static B: EnumExplicit = new EnumExplicit("B", "bar");
+  static values: EnumExplicit[] = [EnumExplicit.A, EnumExplicit.B];
+
+  name: string;
+  value: string;
+
+  constructor(name: string, value: string) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode(): string {
+    return this.value;
+  }
+
+  static decode(data: any): EnumExplicit {
+    for (let i = 0, l = EnumExplicit.values.length; i < l; i++) {
+      const member = EnumExplicit.values[i];
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+}
+
+export class EnumImplicit {
+  static A: EnumImplicit = new EnumImplicit("A", "A");
+  static B: EnumImplicit = new EnumImplicit("B", "B");
+  static values: EnumImplicit[] = [EnumImplicit.A, EnumImplicit.B];
+
+  name: string;
+  value: string;
+
+  constructor(name: string, value: string) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode(): string {
+    return this.value;
+  }
+
+  static decode(data: any): EnumImplicit {
+    for (let i = 0, l = EnumImplicit.values.length; i < l; i++) {
+      const member = EnumImplicit.values[i];
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+}
+
+export class EnumNumeric {
+  static A: EnumNumeric = new EnumNumeric("A", 1);
+  static B: EnumNumeric = new EnumNumeric("B", 2);
+  static values: EnumNumeric[] = [EnumNumeric.A, EnumNumeric.B];
+
+  name: string;
+  value: number;
+
+  constructor(name: string, value: number) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode(): number {
+    return this.value;
+  }
+
+  static decode(data: any): EnumNumeric {
+    for (let i = 0, l = EnumNumeric.values.length; i < l; i++) {
+      const member = EnumNumeric.values[i];
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+}
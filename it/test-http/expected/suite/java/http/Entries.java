@@ -0,0 +1,134 @@
+package http;
+
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import java.io.IOException;
+import java.util.concurrent.CompletableFuture;
+import okhttp3.Call;
+import okhttp3.Callback;
+import okhttp3.HttpUrl;
+import okhttp3.MediaType;
+import okhttp3.OkHttpClient;
+import okhttp3.Request;
+import okhttp3.RequestBody;
+import okhttp3.Response;
+import okhttp3.ResponseBody;
+
+public interface Entries {
+  CompletableFuture<Entry> getEntry(final Entry request);
+
+  CompletableFuture<Entry> createEntry(final Entry request);
+
+  CompletableFuture<Void> deleteEntry(final Entry request);
+
+  public static class OkHttp implements Entries {
+    private static final MediaType JSON = MediaType.parse("application/json; charset=utf-8");
+    private final OkHttpClient client;
+    private final HttpUrl baseUrl;
+    private final ObjectMapper mapper;
+
+    public OkHttp(
+      final OkHttpClient client,
+      final HttpUrl baseUrl,
+      final ObjectMapper mapper
+    ) {
+      this.client = client;
+      this.baseUrl = baseUrl;
+      this.mapper = mapper;
+    }
+
+    public CompletableFuture<Entry> getEntry(final Entry request) {
+      final HttpUrl url = this.baseUrl.newBuilder()
+        .addPathSegment("entries")
+        .addPathSegment(String.valueOf(request.getName()))
+        .build();
+      final Request req = new Request.Builder()
+        .url(url)
+        .method("GET", null)
+        .build();
+      final CompletableFuture<Entry> future = new CompletableFuture<Entry>();
+      this.client.newCall(req).enqueue(new Callback() {
+        public void onFailure(final Call call, final IOException e) {
+          future.completeExceptionally(e);
+        }
+
+        public void onResponse(final Call call, final Response response) {
+          try (final ResponseBody body = response.body()) {
+            if (!response.isSuccessful()) {
+              throw new IOException("bad response: " + response);
+            }
+            future.complete(OkHttp.this.mapper.readValue(body.bytes(), new TypeReference<Entry>(){}));
+          } catch (final Exception e) {
+            future.completeExceptionally(e);
+          }
+        }
+      });
+      return future;
+    }
+
+    public CompletableFuture<Entry> createEntry(final Entry request) {
+      final HttpUrl url = this.baseUrl.newBuilder()
+        .addPathSegment("entries")
+        .build();
+      final RequestBody requestBody;
+      try {
+        requestBody = RequestBody.create(JSON, this.mapper.writeValueAsBytes(request));
+      } catch (final Exception e) {
+        final CompletableFuture<Entry> future = new CompletableFuture<Entry>();
+        future.completeExceptionally(e);
+        return future;
+      }
+      final Request req = new Request.Builder()
+        .url(url)
+        .method("POST", requestBody)
+        .build();
+      final CompletableFuture<Entry> future = new CompletableFuture<Entry>();
+      this.client.newCall(req).enqueue(new Callback() {
+        public void onFailure(final Call call, final IOException e) {
+          future.completeExceptionally(e);
+        }
+
+        public void onResponse(final Call call, final Response response) {
+          try (final ResponseBody body = response.body()) {
+            if (response.code() != 201) {
+              throw new IOException("bad response: " + response);
+            }
+            future.complete(OkHttp.this.mapper.readValue(body.bytes(), new TypeReference<Entry>(){}));
+          } catch (final Exception e) {
+            future.completeExceptionally(e);
+          }
+        }
+      });
+      return future;
+    }
+
+    public CompletableFuture<Void> deleteEntry(final Entry request) {
+      final HttpUrl url = this.baseUrl.newBuilder()
+        .addPathSegment("entries")
+        .addPathSegment(String.valueOf(request.getName()))
+        .build();
+      final Request req = new Request.Builder()
+        .url(url)
+        .method("DELETE", null)
+        .build();
+      final CompletableFuture<Void> future = new CompletableFuture<Void>();
+      this.client.newCall(req).enqueue(new Callback() {
+        public void onFailure(final Call call, final IOException e) {
+          future.completeExceptionally(e);
+        }
+
+        public void onResponse(final Call call, final Response response) {
+          try (final ResponseBody body = response.body()) {
+            if (!response.isSuccessful()) {
+              throw new IOException("bad response: " + response);
+            }
+            future.complete(null);
+          } catch (final Exception e) {
+            future.completeExceptionally(e);
+          }
+        }
+      });
+      return future;
+    }
+  }
+}
@@ -0,0 +1,76 @@
+export class Entry {
+  constructor(name) {
+    this.name = name;
+  }
+
+  static decode(data) {
+    const v_name = data["name"];
+
+    if (v_name === null || v_name === undefined) {
+      throw new Error("name" + ": required field");
+    }
+
+    return new Entry(v_name);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name === null || this.name === undefined) {
+      throw new Error("name: is a required field");
+    }
+
+    data["name"] = this.name;
+
+    return data;
+  }
+}
+
+export class Entries_Fetch {
+  constructor(url, fetchImpl) {
+    this.url = url.replace(/\/+$/, "");
+    this.fetch = fetchImpl !== undefined ? fetchImpl : (u, init) => fetch(u, init);
+  }
+
+  get_entry(request) {
+    const url = this.url + "/entries/" + encodeURIComponent(String(request.name));
+
+    return this.fetch(url, {
+      method: "GET"
+    }).then(response => {
+      if (!response.ok) {
+        throw new Error("bad response: " + response.status);
+      }
+
+      return response.json().then(data => Entry.decode(data));
+    });
+  }
+
+  create_entry(request) {
+    const url = this.url + "/entries";
+
+    return this.fetch(url, {
+      method: "POST",
+      headers: {"Content-Type": "application/json"},
+      body: JSON.stringify(request.encode())
+    }).then(response => {
+      if (response.status !== 201) {
+        throw new Error("bad response: " + response.status);
+      }
+
+      return response.json().then(data => Entry.decode(data));
+    });
+  }
+
+  delete_entry(request) {
+    const url = this.url + "/entries/" + encodeURIComponent(String(request.name));
+
+    return this.fetch(url, {
+      method: "DELETE"
+    }).then(response => {
+      if (!response.ok) {
+        throw new Error("bad response: " + response.status);
+      }
+    });
+  }
+}
@@ -0,0 +1,60 @@
+import requests
+import requests.utils
+
+class Entry:
+  def __init__(self, name):
+    self.name = name
+
+  @staticmethod
+  def decode(data):
+    f_name = data["name"]
+
+    return Entry(f_name)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is None:
+      raise Exception("name: is a required field")
+
+    data["name"] = self.name
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name: {!r}>".format(self.name)
+
+class Entries:
+  def get_entry(self, request):
+    raise NotImplementedError()
+
+  def create_entry(self, request):
+    raise NotImplementedError()
+
+  def delete_entry(self, request):
+    raise NotImplementedError()
+
+class Entries_Requests(Entries):
+  def __init__(self, url, session=None):
+    if session is None:
+      session = requests.Session()
+    self.url = url.rstrip("/")
+    self.session = session
+
+  def get_entry(self, request):
+    url = self.url + "/entries/" + requests.utils.quote(str(request.name), safe="")
+    r = self.session.request("GET", url)
+    r.raise_for_status()
+    return Entry.decode(r.json())
+
+  def create_entry(self, request):
+    url = self.url + "/entries"
+    r = self.session.request("POST", url, json=request.encode())
+    if r.status_code != 201:
+      raise requests.HTTPError("bad response: " + str(r.status_code), response=r)
+    return Entry.decode(r.json())
+
+  def delete_entry(self, request):
+    url = self.url + "/entries/" + requests.utils.quote(str(request.name), safe="")
+    r = self.session.request("DELETE", url)
+    r.raise_for_status()
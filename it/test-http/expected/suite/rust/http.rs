@@ -0,0 +1,94 @@
+use futures;
+use futures::future;
+use reqwest;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Entry {
+  name: String,
+}
+
+pub trait Entries {
+  type Error;
+
+  fn get_entry(&self, request: Entry) -> Box<futures::Future<Item = Entry, Error = Self::Error>>;
+  fn create_entry(&self, request: Entry) -> Box<futures::Future<Item = Entry, Error = Self::Error>>;
+  fn delete_entry(&self, request: Entry) -> Box<futures::Future<Item = (), Error = Self::Error>>;
+}
+
+pub struct EntriesReqwest {
+  client: reqwest::Client,
+  url: reqwest::Url,
+}
+
+impl EntriesReqwest {
+  pub fn new(client: reqwest::Client, url: reqwest::Url) -> EntriesReqwest {
+    EntriesReqwest {
+      client: client,
+      url: url,
+    }
+  }
+
+  pub fn get_entry(&self, request: &Entry) -> Result<Entry, Box<::std::error::Error>> {
+    let mut url = self.url.clone();
+
+    {
+      let mut path = url.path_segments_mut().expect("url cannot be a base");
+      path.pop_if_empty();
+      path.push("entries");
+      path.push(&request.name.to_string());
+    }
+
+    let mut response = self.client.request(reqwest::Method::Get, url).send()?.error_for_status()?;
+
+    Ok(response.json()?)
+  }
+
+  pub fn create_entry(&self, request: &Entry) -> Result<Entry, Box<::std::error::Error>> {
+    let mut url = self.url.clone();
+
+    {
+      let mut path = url.path_segments_mut().expect("url cannot be a base");
+      path.pop_if_empty();
+      path.push("entries");
+    }
+
+    let mut response = self.client.request(reqwest::Method::Post, url).json(request).send()?;
+
+    if response.status().as_u16() != 201 {
+      return Err(format!("bad response: {}", response.status()).into());
+    }
+
+    Ok(response.json()?)
+  }
+
+  pub fn delete_entry(&self, request: &Entry) -> Result<(), Box<::std::error::Error>> {
+    let mut url = self.url.clone();
+
+    {
+      let mut path = url.path_segments_mut().expect("url cannot be a base");
+      path.pop_if_empty();
+      path.push("entries");
+      path.push(&request.name.to_string());
+    }
+
+    self.client.request(reqwest::Method::Delete, url).send()?.error_for_status()?;
+
+    Ok(())
+  }
+}
+
+impl Entries for EntriesReqwest {
+  type Error = Box<::std::error::Error>;
+
+  fn get_entry(&self, request: Entry) -> Box<futures::Future<Item = Entry, Error = Self::Error>> {
+    Box::new(future::result(EntriesReqwest::get_entry(self, &request)))
+  }
+
+  fn create_entry(&self, request: Entry) -> Box<futures::Future<Item = Entry, Error = Self::Error>> {
+    Box::new(future::result(EntriesReqwest::create_entry(self, &request)))
+  }
+
+  fn delete_entry(&self, request: Entry) -> Box<futures::Future<Item = (), Error = Self::Error>> {
+    Box::new(future::result(EntriesReqwest::delete_entry(self, &request)))
+  }
+}
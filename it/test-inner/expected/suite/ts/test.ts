@@ -0,0 +1,95 @@
+export class Entry {
+  a: A | null;
+  b: A_B | null;
+
+  constructor(a: A | null, b: A_B | null) {
+    this.a = a;
+    this.b = b;
+  }
+
+  static decode(data: any): Entry {
+    let v_a: A | null = null;
+    if (data["a"] !== null && data["a"] !== undefined) {
+      v_a = A.decode(data["a"]);
+    }
+
+    let v_b: A_B | null = null;
+    if (data["b"] !== null && data["b"] !== undefined) {
+      v_b = A_B.decode(data["b"]);
+    }
+
+    return new Entry(v_a, v_b);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.a !== null && this.a !== undefined) {
+      data["a"] = this.a.encode();
+    }
+
+    if (this.b !== null && this.b !== undefined) {
+      data["b"] = this.b.encode();
+    }
+
+    return data;
+  }
+}
+
+export class A {
+  b: A_B;
+
+  constructor(b: A_B) {
+    this.b = b;
+  }
+
+  static decode(data: any): A {
+    if (data["b"] === null || data["b"] === undefined) {
+      throw new Error("b: required field");
+    }
+    const v_b: A_B = A_B.decode(data["b"]);
+
+    return new A(v_b);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    data["b"] = this.b.encode();
+
+    return data;
+  }
+}
+
+export class A_B {
+  field: string;
+
+  constructor(field: string) {
+    this.field = field;
+  }
+
+  static decode(data: any): A_B {
+    if (data["field"] === null || data["field"] === undefined) {
+      throw new Error("field: required field");
+    }
+    const v_field: string = data["field"];
+
+    return new A_B(v_field);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.field === null || this.field === undefined) {
+      throw new Error("field: is a required field");
+    }
+
+    data["field"] = this.field;
+
+    return data;
+  }
+}
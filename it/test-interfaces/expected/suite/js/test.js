@@ -1,7 +1,7 @@
 
 export class Entry {
   static decode(data) {
-    const f_type = data["type"]
+    const f_type = data["type"];
 
     if (f_type === "bar") {
       return Entry_Bar.decode(data);
@@ -24,13 +24,13 @@ export class Entry_Bar {
   encode() {
     const data = {};
 
-    data["type"] = Entry.TYPE;
+    data["type"] = Entry_Bar.TYPE;
 
     return data;
   }
 }
 
-Entry.TYPE = "Entry_Bar";
+Entry_Bar.TYPE = "bar";
 
 export class Entry_Foo {
   constructor() {
@@ -41,10 +41,10 @@ export class Entry_Foo {
   encode() {
     const data = {};
 
-    data["type"] = Entry.TYPE;
+    data["type"] = Entry_Foo.TYPE;
 
     return data;
   }
 }
 
-Entry.TYPE = "Entry_Foo";
+Entry_Foo.TYPE = "foo";
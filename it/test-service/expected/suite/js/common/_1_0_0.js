@@ -0,0 +1,68 @@
+export class Entry {
+  constructor(name) {
+    this.name = name;
+  }
+
+  static decode(data) {
+    const v_name = data["name"];
+
+    if (v_name === null || v_name === undefined) {
+      throw new Error("name" + ": required field");
+    }
+
+    return new Entry(v_name);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name === null || this.name === undefined) {
+      throw new Error("name: is a required field");
+    }
+
+    data["name"] = this.name;
+
+    return data;
+  }
+}
+
+export class ErrorMessage {
+  constructor(message, status_code) {
+    this.message = message;
+    this.status_code = status_code;
+  }
+
+  static decode(data) {
+    const v_message = data["message"];
+
+    if (v_message === null || v_message === undefined) {
+      throw new Error("message" + ": required field");
+    }
+
+    const v_status_code = data["status_code"];
+
+    if (v_status_code === null || v_status_code === undefined) {
+      throw new Error("status_code" + ": required field");
+    }
+
+    return new ErrorMessage(v_message, v_status_code);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.message === null || this.message === undefined) {
+      throw new Error("message: is a required field");
+    }
+
+    data["message"] = this.message;
+
+    if (this.status_code === null || this.status_code === undefined) {
+      throw new Error("status_code: is a required field");
+    }
+
+    data["status_code"] = this.status_code;
+
+    return data;
+  }
+}
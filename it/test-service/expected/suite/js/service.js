@@ -0,0 +1,70 @@
+import * as c from "common/_1_0_0.js";
+
+export class MyService_Client {
+  constructor(transport) {
+    this.transport = transport;
+  }
+
+  unknown() {
+    return this.transport.unary("unknown", undefined).then(data => undefined);
+  }
+
+  unknown_return() {
+    return this.transport.unary("unknown_return", undefined).then(data => c.Entry.decode(data));
+  }
+
+  unknown_argument(request) {
+    return this.transport.unary("unknown_argument", request.encode()).then(data => undefined);
+  }
+
+  unary(request) {
+    return this.transport.unary("unary", request.encode()).then(data => c.Entry.decode(data));
+  }
+
+  server_streaming(request) {
+    const responses = this.transport.stream("server_streaming", [request.encode()]);
+    return MyService_Client._map(responses, v => c.Entry.decode(v));
+  }
+
+  client_streaming(request) {
+    const responses = this.transport.stream("client_streaming", MyService_Client._map(request, v => v.encode()));
+    return MyService_Client._first(responses).then(data => c.Entry.decode(data));
+  }
+
+  bidi_streaming(request) {
+    const responses = this.transport.stream("bidi_streaming", MyService_Client._map(request, v => v.encode()));
+    return MyService_Client._map(responses, v => c.Entry.decode(v));
+  }
+
+  static _iter(iterable) {
+    if (iterable[Symbol.asyncIterator] !== undefined) {
+      return iterable[Symbol.asyncIterator]();
+    }
+
+    return iterable[Symbol.iterator]();
+  }
+
+  static _map(iterable, f) {
+    const it = MyService_Client._iter(iterable);
+
+    return {
+      [Symbol.asyncIterator]() {
+        return this;
+      },
+      next() {
+        return Promise.resolve(it.next()).then(r => {
+          return r.done ? r : {done: false, value: f(r.value)};
+        });
+      }
+    };
+  }
+
+  static _first(iterable) {
+    return Promise.resolve(MyService_Client._iter(iterable).next()).then(r => {
+      if (r.done) {
+        throw new Error("missing response");
+      }
+      return r.value;
+    });
+  }
+}
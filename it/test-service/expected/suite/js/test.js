@@ -0,0 +1,14 @@
+export class Entry {
+  constructor() {
+  }
+
+  static decode(data) {
+    return new Entry();
+  }
+
+  encode() {
+    const data = {};
+
+    return data;
+  }
+}
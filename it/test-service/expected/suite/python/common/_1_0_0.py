@@ -0,0 +1,53 @@
+class Entry:
+  def __init__(self, name):
+    self.name = name
+
+  @staticmethod
+  def decode(data):
+    f_name = data["name"]
+
+    return Entry(f_name)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is None:
+      raise Exception("name: is a required field")
+
+    data["name"] = self.name
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name: {!r}>".format(self.name)
+
+class ErrorMessage:
+  def __init__(self, message, status_code):
+    self.message = message
+    self.status_code = status_code
+
+  @staticmethod
+  def decode(data):
+    f_message = data["message"]
+
+    f_status_code = data["status_code"]
+
+    return ErrorMessage(f_message, f_status_code)
+
+  def encode(self):
+    data = dict()
+
+    if self.message is None:
+      raise Exception("message: is a required field")
+
+    data["message"] = self.message
+
+    if self.status_code is None:
+      raise Exception("status_code: is a required field")
+
+    data["status_code"] = self.status_code
+
+    return data
+
+  def __repr__(self):
+    return "<ErrorMessage message: {!r}, status_code: {!r}>".format(self.message, self.status_code)
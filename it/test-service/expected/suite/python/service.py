@@ -0,0 +1,109 @@
+import common._1_0_0 as c
+
+class MyService:
+  def unknown(self):
+    raise NotImplementedError()
+
+  def unknown_return(self):
+    raise NotImplementedError()
+
+  def unknown_argument(self, request):
+    raise NotImplementedError()
+
+  def unary(self, request):
+    raise NotImplementedError()
+
+  def server_streaming(self, request):
+    raise NotImplementedError()
+
+  def client_streaming(self, request):
+    raise NotImplementedError()
+
+  def bidi_streaming(self, request):
+    raise NotImplementedError()
+
+class MyService_Client(MyService):
+  def __init__(self, transport):
+    self.transport = transport
+
+  def unknown(self):
+    responses = self.transport.call("unknown", iter([]))
+
+    for _ in responses:
+      pass
+
+  def unknown_return(self):
+    responses = self.transport.call("unknown_return", iter([]))
+
+    for response in responses:
+      return c.Entry.decode(response)
+    raise Exception("missing response")
+
+  def unknown_argument(self, request):
+    responses = self.transport.call("unknown_argument", iter([request.encode()]))
+
+    for _ in responses:
+      pass
+
+  def unary(self, request):
+    responses = self.transport.call("unary", iter([request.encode()]))
+
+    for response in responses:
+      return c.Entry.decode(response)
+    raise Exception("missing response")
+
+  def server_streaming(self, request):
+    responses = self.transport.call("server_streaming", iter([request.encode()]))
+
+    return (c.Entry.decode(r) for r in responses)
+
+  def client_streaming(self, request):
+    responses = self.transport.call("client_streaming", (r.encode() for r in request))
+
+    for response in responses:
+      return c.Entry.decode(response)
+    raise Exception("missing response")
+
+  def bidi_streaming(self, request):
+    responses = self.transport.call("bidi_streaming", (r.encode() for r in request))
+
+    return (c.Entry.decode(r) for r in responses)
+
+class MyService_Server:
+  def __init__(self, service):
+    self.service = service
+
+  def dispatch(self, endpoint, requests):
+    if endpoint == "unknown":
+      self.service.unknown()
+      return iter([])
+
+    if endpoint == "unknown_return":
+      response = self.service.unknown_return()
+      return iter([response.encode()])
+
+    if endpoint == "unknown_argument":
+      for request in requests:
+        self.service.unknown_argument(c.Entry.decode(request))
+        return iter([])
+      raise Exception("missing request")
+
+    if endpoint == "unary":
+      for request in requests:
+        response = self.service.unary(c.Entry.decode(request))
+        return iter([response.encode()])
+      raise Exception("missing request")
+
+    if endpoint == "server_streaming":
+      for request in requests:
+        return (r.encode() for r in self.service.server_streaming(c.Entry.decode(request)))
+      raise Exception("missing request")
+
+    if endpoint == "client_streaming":
+      response = self.service.client_streaming(c.Entry.decode(r) for r in requests)
+      return iter([response.encode()])
+
+    if endpoint == "bidi_streaming":
+      return (r.encode() for r in self.service.bidi_streaming(c.Entry.decode(r) for r in requests))
+
+    raise Exception("no such endpoint: " + endpoint)
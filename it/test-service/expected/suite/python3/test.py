@@ -0,0 +1,15 @@
+class Entry:
+  def __init__(self):
+    pass
+
+  @staticmethod
+  def decode(data):
+    return Entry()
+
+  def encode(self):
+    data = dict()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry >".format()
@@ -1,9 +1,193 @@
+use common::_1_0_0 as c;
+use futures;
+use futures::stream;
+use serde_json as json;
+use std::rc;
+
 pub trait MyService {
-  fn unknown();
-  fn unknown_return();
-  fn unknown_argument();
-  fn unary();
-  fn server_streaming();
-  fn client_streaming();
-  fn bidi_streaming();
+  type Error;
+
+  fn unknown(&self) -> Box<futures::Future<Item = (), Error = Self::Error>>;
+  fn unknown_return(&self) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>>;
+  fn unknown_argument(&self, request: c::Entry) -> Box<futures::Future<Item = (), Error = Self::Error>>;
+  fn unary(&self, request: c::Entry) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>>;
+  fn server_streaming(&self, request: c::Entry) -> Box<futures::Stream<Item = c::Entry, Error = Self::Error>>;
+  fn client_streaming(&self, request: Box<futures::Stream<Item = c::Entry, Error = Self::Error>>) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>>;
+  fn bidi_streaming(&self, request: Box<futures::Stream<Item = c::Entry, Error = Self::Error>>) -> Box<futures::Stream<Item = c::Entry, Error = Self::Error>>;
+}
+
+pub trait MyServiceTransport {
+  type Error: From<json::Error> + 'static;
+
+  fn call(&self, endpoint: &'static str, requests: Box<futures::Stream<Item = json::Value, Error = Self::Error>>) -> Box<futures::Stream<Item = json::Value, Error = Self::Error>>;
+}
+
+pub struct MyServiceClient<T> {
+  transport: T,
+}
+
+impl<T: MyServiceTransport> MyServiceClient<T> {
+  pub fn new(transport: T) -> MyServiceClient<T> {
+    MyServiceClient { transport: transport }
+  }
+}
+
+impl<T: MyServiceTransport + 'static> MyService for MyServiceClient<T> {
+  type Error = T::Error;
+
+  fn unknown(&self) -> Box<futures::Future<Item = (), Error = Self::Error>> {
+    use futures::Stream;
+
+    let responses = self.transport.call("unknown", Box::new(stream::empty::<json::Value, T::Error>()));
+
+    Box::new(responses.for_each(|_| Ok(())))
+  }
+
+  fn unknown_return(&self) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>> {
+    use futures::{Future, Stream};
+
+    let responses = self.transport.call("unknown_return", Box::new(stream::empty::<json::Value, T::Error>()));
+
+    Box::new(responses.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+      value
+        .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing response"))
+        .and_then(json::from_value::<c::Entry>)
+        .map_err(T::Error::from)
+    }))
+  }
+
+  fn unknown_argument(&self, request: c::Entry) -> Box<futures::Future<Item = (), Error = Self::Error>> {
+    use futures::Stream;
+
+    let responses = self.transport.call("unknown_argument", Box::new(stream::once(json::to_value(request).map_err(T::Error::from))));
+
+    Box::new(responses.for_each(|_| Ok(())))
+  }
+
+  fn unary(&self, request: c::Entry) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>> {
+    use futures::{Future, Stream};
+
+    let responses = self.transport.call("unary", Box::new(stream::once(json::to_value(request).map_err(T::Error::from))));
+
+    Box::new(responses.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+      value
+        .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing response"))
+        .and_then(json::from_value::<c::Entry>)
+        .map_err(T::Error::from)
+    }))
+  }
+
+  fn server_streaming(&self, request: c::Entry) -> Box<futures::Stream<Item = c::Entry, Error = Self::Error>> {
+    use futures::Stream;
+
+    let responses = self.transport.call("server_streaming", Box::new(stream::once(json::to_value(request).map_err(T::Error::from))));
+
+    Box::new(responses.and_then(|r| json::from_value::<c::Entry>(r).map_err(T::Error::from)))
+  }
+
+  fn client_streaming(&self, request: Box<futures::Stream<Item = c::Entry, Error = Self::Error>>) -> Box<futures::Future<Item = c::Entry, Error = Self::Error>> {
+    use futures::{Future, Stream};
+
+    let responses = self.transport.call("client_streaming", Box::new(request.and_then(|r| json::to_value(r).map_err(T::Error::from))));
+
+    Box::new(responses.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+      value
+        .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing response"))
+        .and_then(json::from_value::<c::Entry>)
+        .map_err(T::Error::from)
+    }))
+  }
+
+  fn bidi_streaming(&self, request: Box<futures::Stream<Item = c::Entry, Error = Self::Error>>) -> Box<futures::Stream<Item = c::Entry, Error = Self::Error>> {
+    use futures::Stream;
+
+    let responses = self.transport.call("bidi_streaming", Box::new(request.and_then(|r| json::to_value(r).map_err(T::Error::from))));
+
+    Box::new(responses.and_then(|r| json::from_value::<c::Entry>(r).map_err(T::Error::from)))
+  }
+}
+
+pub struct MyServiceServer<S> {
+  service: rc::Rc<S>,
+}
+
+impl<S: MyService + 'static> MyServiceServer<S>
+where
+  S::Error: From<json::Error> + 'static,
+{
+  pub fn new(service: S) -> MyServiceServer<S> {
+    MyServiceServer { service: rc::Rc::new(service) }
+  }
+
+  pub fn dispatch(&self, endpoint: &str, requests: Box<futures::Stream<Item = json::Value, Error = S::Error>>) -> Option<Box<futures::Stream<Item = json::Value, Error = S::Error>>> {
+    use futures::{Future, Stream};
+
+    let responses: Box<futures::Stream<Item = json::Value, Error = S::Error>> = match endpoint {
+      "unknown" => {
+        let response = self.service.unknown();
+
+        Box::new(response.into_stream().filter_map(|()| None::<json::Value>))
+      }
+      "unknown_return" => {
+        let response = self.service.unknown_return();
+
+        Box::new(response.and_then(|r| json::to_value(r).map_err(S::Error::from)).into_stream())
+      }
+      "unknown_argument" => {
+        let service = self.service.clone();
+
+        let request = requests.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+          value
+            .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing request"))
+            .and_then(json::from_value::<c::Entry>)
+            .map_err(S::Error::from)
+        });
+
+        let response = request.and_then(move |request| service.unknown_argument(request));
+
+        Box::new(response.into_stream().filter_map(|()| None::<json::Value>))
+      }
+      "unary" => {
+        let service = self.service.clone();
+
+        let request = requests.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+          value
+            .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing request"))
+            .and_then(json::from_value::<c::Entry>)
+            .map_err(S::Error::from)
+        });
+
+        let response = request.and_then(move |request| service.unary(request));
+
+        Box::new(response.and_then(|r| json::to_value(r).map_err(S::Error::from)).into_stream())
+      }
+      "server_streaming" => {
+        let service = self.service.clone();
+
+        let request = requests.into_future().map_err(|(e, _)| e).and_then(|(value, _)| {
+          value
+            .ok_or_else(|| <json::Error as ::serde::de::Error>::custom("missing request"))
+            .and_then(json::from_value::<c::Entry>)
+            .map_err(S::Error::from)
+        });
+
+        let response = request.map(move |request| service.server_streaming(request)).flatten_stream();
+
+        Box::new(response.and_then(|r| json::to_value(r).map_err(S::Error::from)))
+      }
+      "client_streaming" => {
+        let response = self.service.client_streaming(Box::new(requests.and_then(|r| json::from_value::<c::Entry>(r).map_err(S::Error::from))));
+
+        Box::new(response.and_then(|r| json::to_value(r).map_err(S::Error::from)).into_stream())
+      }
+      "bidi_streaming" => {
+        let response = self.service.bidi_streaming(Box::new(requests.and_then(|r| json::from_value::<c::Entry>(r).map_err(S::Error::from))));
+
+        Box::new(response.and_then(|r| json::to_value(r).map_err(S::Error::from)))
+      }
+      _ => return None,
+    };
+
+    Some(responses)
+  }
 }
@@ -0,0 +1,138 @@
+export class Entry {
+  tuple1: Tuple1 | null;
+  tuple2: Tuple2 | null;
+
+  constructor(tuple1: Tuple1 | null, tuple2: Tuple2 | null) {
+    this.tuple1 = tuple1;
+    this.tuple2 = tuple2;
+  }
+
+  static decode(data: any): Entry {
+    let v_tuple1: Tuple1 | null = null;
+    if (data["tuple1"] !== null && data["tuple1"] !== undefined) {
+      v_tuple1 = Tuple1.decode(data["tuple1"]);
+    }
+
+    let v_tuple2: Tuple2 | null = null;
+    if (data["tuple2"] !== null && data["tuple2"] !== undefined) {
+      v_tuple2 = Tuple2.decode(data["tuple2"]);
+    }
+
+    return new Entry(v_tuple1, v_tuple2);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.tuple1 !== null && this.tuple1 !== undefined) {
+      data["tuple1"] = this.tuple1.encode();
+    }
+
+    if (this.tuple2 !== null && this.tuple2 !== undefined) {
+      data["tuple2"] = this.tuple2.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Tuple1 {
+  a: string;
+  b: number;
+
+  constructor(a: string, b: number) {
+    this.a = a;
+    this.b = b;
+  }
+
+  static decode(data: any): Tuple1 {
+    if (data[0] === null || data[0] === undefined) {
+      throw new Error("a: required field");
+    }
+    const v_a: string = data[0];
+
+    if (data[1] === null || data[1] === undefined) {
+      throw new Error("b: required field");
+    }
+    const v_b: number = data[1];
+
+    return new Tuple1(v_a, v_b);
+  }
+
+  encode(): any {
+    if (this.a === null || this.a === undefined) {
+      throw new Error("a: is a required field");
+    }
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    return [this.a, this.b];
+  }
+}
+
+export class Tuple2 {
+  a: string;
+  b: Other;
+
+  constructor(a: string, b: Other) {
+    this.a = a;
+    this.b = b;
+  }
+
+  static decode(data: any): Tuple2 {
+    if (data[0] === null || data[0] === undefined) {
+      throw new Error("a: required field");
+    }
+    const v_a: string = data[0];
+
+    if (data[1] === null || data[1] === undefined) {
+      throw new Error("b: required field");
+    }
+    const v_b: Other = Other.decode(data[1]);
+
+    return new Tuple2(v_a, v_b);
+  }
+
+  encode(): any {
+    if (this.a === null || this.a === undefined) {
+      throw new Error("a: is a required field");
+    }
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    return [this.a, this.b.encode()];
+  }
+}
+
+export class Other {
+  a: string;
+
+  constructor(a: string) {
+    this.a = a;
+  }
+
+  static decode(data: any): Other {
+    if (data["a"] === null || data["a"] === undefined) {
+      throw new Error("a: required field");
+    }
+    const v_a: string = data["a"];
+
+    return new Other(v_a);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.a === null || this.a === undefined) {
+      throw new Error("a: is a required field");
+    }
+
+    data["a"] = this.a;
+
+    return data;
+  }
+}
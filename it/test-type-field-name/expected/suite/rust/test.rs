@@ -0,0 +1,10 @@
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "kind")]
+pub enum Entry {
+  #[serde(rename = "bar")]
+  Bar {
+  },
+  #[serde(rename = "foo")]
+  Foo {
+  },
+}
@@ -0,0 +1,46 @@
+
+export class Entry {
+  static decode(data) {
+    const f_type = data[0];
+
+    if (f_type === "bar") {
+      return Entry_Bar.decode(data[1]);
+    }
+
+    if (f_type === "foo") {
+      return Entry_Foo.decode(data[1]);
+    }
+
+    throw new Error("bad type: " + f_type);
+  }
+}
+
+export class Entry_Bar {
+  constructor() {
+  }
+  static decode(data) {
+    return new Entry_Bar();
+  }
+  encode() {
+    const data = {};
+
+    return [Entry_Bar.TYPE, data];
+  }
+}
+
+Entry_Bar.TYPE = "bar";
+
+export class Entry_Foo {
+  constructor() {
+  }
+  static decode(data) {
+    return new Entry_Foo();
+  }
+  encode() {
+    const data = {};
+
+    return [Entry_Foo.TYPE, data];
+  }
+}
+
+Entry_Foo.TYPE = "foo";
@@ -0,0 +1,49 @@
+use serde_json as json;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(remote = "Entry")]
+pub enum Entry {
+  #[serde(rename = "bar")]
+  Bar {
+  },
+  #[serde(rename = "foo")]
+  Foo {
+  },
+}
+
+impl ::serde::Serialize for Entry {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ::serde::Serializer,
+  {
+    use ::serde::ser::{Error, SerializeTuple};
+
+    let value = Entry::serialize(self, json::value::Serializer).map_err(S::Error::custom)?;
+
+    let (name, value) = match value {
+      json::Value::Object(map) => map.into_iter().next().ok_or_else(|| S::Error::custom("missing sub-type"))?,
+      _ => return Err(S::Error::custom("expected object")),
+    };
+
+    let mut tuple = serializer.serialize_tuple(2)?;
+    tuple.serialize_element(&name)?;
+    tuple.serialize_element(&value)?;
+    tuple.end()
+  }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Entry {
+  fn deserialize<D>(deserializer: D) -> Result<Entry, D::Error>
+  where
+    D: ::serde::Deserializer<'de>,
+  {
+    use ::serde::de::Error;
+
+    let (name, value): (String, json::Value) = ::serde::Deserialize::deserialize(deserializer)?;
+
+    let mut map = json::Map::new();
+    map.insert(name, value);
+
+    Entry::deserialize(json::Value::Object(map)).map_err(D::Error::custom)
+  }
+}
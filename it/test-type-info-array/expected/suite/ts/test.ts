@@ -0,0 +1,53 @@
+export type Entry = Entry_Bar | Entry_Foo;
+
+export namespace Entry {
+  export function decode(data: any) {
+    const f_type = data[0];
+
+    if (f_type === "bar") {
+      return Entry_Bar.decode(data[1]);
+    }
+
+    if (f_type === "foo") {
+      return Entry_Foo.decode(data[1]);
+    }
+
+    throw new Error("bad type: " + f_type);
+  }
+}
+
+export class Entry_Bar {
+  static readonly TYPE: "bar" = "bar";
+  readonly TYPE: "bar" = "bar";
+
+  constructor() {
+  }
+
+  static decode(data: any): Entry_Bar {
+    return new Entry_Bar();
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    return [Entry_Bar.TYPE, data];
+  }
+}
+
+export class Entry_Foo {
+  static readonly TYPE: "foo" = "foo";
+  readonly TYPE: "foo" = "foo";
+
+  constructor() {
+  }
+
+  static decode(data: any): Entry_Foo {
+    return new Entry_Foo();
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    return [Entry_Foo.TYPE, data];
+  }
+}
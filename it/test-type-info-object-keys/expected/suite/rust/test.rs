@@ -0,0 +1,9 @@
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Entry {
+  #[serde(rename = "bar")]
+  Bar {
+  },
+  #[serde(rename = "foo")]
+  Foo {
+  },
+}
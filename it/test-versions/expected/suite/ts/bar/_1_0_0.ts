@@ -0,0 +1,28 @@
+export class Other {
+  name: string;
+
+  constructor(name: string) {
+    this.name = name;
+  }
+
+  static decode(data: any): Other {
+    if (data["name"] === null || data["name"] === undefined) {
+      throw new Error("name: required field");
+    }
+    const v_name: string = data["name"];
+
+    return new Other(v_name);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.name === null || this.name === undefined) {
+      throw new Error("name: is a required field");
+    }
+
+    data["name"] = this.name;
+
+    return data;
+  }
+}
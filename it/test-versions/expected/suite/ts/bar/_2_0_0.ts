@@ -0,0 +1,28 @@
+export class Other {
+  name2: string;
+
+  constructor(name2: string) {
+    this.name2 = name2;
+  }
+
+  static decode(data: any): Other {
+    if (data["name2"] === null || data["name2"] === undefined) {
+      throw new Error("name2: required field");
+    }
+    const v_name2: string = data["name2"];
+
+    return new Other(v_name2);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.name2 === null || this.name2 === undefined) {
+      throw new Error("name2: is a required field");
+    }
+
+    data["name2"] = this.name2;
+
+    return data;
+  }
+}
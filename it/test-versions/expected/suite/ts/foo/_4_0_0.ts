@@ -0,0 +1,51 @@
+import * as bar from "bar/_1_0_0.js";
+import * as bar2 from "bar/_2_0_0.js";
+
+export class Thing {
+  name: string | null;
+  other: bar.Other | null;
+  other2: bar2.Other | null;
+
+  constructor(name: string | null, other: bar.Other | null, other2: bar2.Other | null) {
+    this.name = name;
+    this.other = other;
+    this.other2 = other2;
+  }
+
+  static decode(data: any): Thing {
+    let v_name: string | null = null;
+    if (data["name"] !== null && data["name"] !== undefined) {
+      v_name = data["name"];
+    }
+
+    let v_other: bar.Other | null = null;
+    if (data["other"] !== null && data["other"] !== undefined) {
+      v_other = bar.Other.decode(data["other"]);
+    }
+
+    let v_other2: bar2.Other | null = null;
+    if (data["other2"] !== null && data["other2"] !== undefined) {
+      v_other2 = bar2.Other.decode(data["other2"]);
+    }
+
+    return new Thing(v_name, v_other, v_other2);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.name !== null && this.name !== undefined) {
+      data["name"] = this.name;
+    }
+
+    if (this.other !== null && this.other !== undefined) {
+      data["other"] = this.other.encode();
+    }
+
+    if (this.other2 !== null && this.other2 !== undefined) {
+      data["other2"] = this.other2.encode();
+    }
+
+    return data;
+  }
+}
@@ -0,0 +1,28 @@
+import * as foo from "foo/_4_0_0.js";
+
+export class Entry {
+  thing: foo.Thing | null;
+
+  constructor(thing: foo.Thing | null) {
+    this.thing = thing;
+  }
+
+  static decode(data: any): Entry {
+    let v_thing: foo.Thing | null = null;
+    if (data["thing"] !== null && data["thing"] !== undefined) {
+      v_thing = foo.Thing.decode(data["thing"]);
+    }
+
+    return new Entry(v_thing);
+  }
+
+  encode(): any {
+    const data: any = {};
+
+    if (this.thing !== null && this.thing !== undefined) {
+      data["thing"] = this.thing.encode();
+    }
+
+    return data;
+  }
+}
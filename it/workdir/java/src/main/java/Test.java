@@ -22,7 +22,22 @@ public class Test {
         break;
       }
 
-      final Entry entry = m.readValue(line, Entry.class);
+      final Entry entry;
+
+      try {
+        entry = m.readValue(line, Entry.class);
+      } catch (final Exception e) {
+        // the error which rejected the value is wrapped by jackson.
+        Throwable cause = e;
+
+        while (cause.getCause() != null) {
+          cause = cause.getCause();
+        }
+
+        System.out.println("error: " + cause.getMessage());
+        continue;
+      }
+
       System.out.println(entry);
       System.out.println(m.writeValueAsString(entry));
     }
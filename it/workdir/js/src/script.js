@@ -9,7 +9,15 @@ var rl = readline.createInterface({
 
 rl.on('line', function(line) {
   let data = JSON.parse(line);
-  let decoded = Entry.decode(data);
-  console.log(Entry.decode(data));
+  let decoded;
+
+  try {
+    decoded = Entry.decode(data);
+  } catch (e) {
+    console.log("error: " + e.message);
+    return;
+  }
+
+  console.log(decoded);
   console.log(JSON.stringify(decoded.encode()));
 })
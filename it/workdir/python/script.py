@@ -3,6 +3,11 @@ import json
 import test
 
 for line in sys.stdin:
-  e = test.Entry.decode(json.loads(line))
+  try:
+    e = test.Entry.decode(json.loads(line))
+  except Exception as error:
+    print("error: " + str(error))
+    continue
+
   print(repr(e))
   print(json.dumps(e.encode()))
@@ -1,7 +1,18 @@
 #[allow(unused_extern_crates)]
 extern crate serde_json;
+#[allow(unused_extern_crates)]
+extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate chrono;
+extern crate base64;
+#[allow(unused_extern_crates)]
+extern crate uuid;
+#[allow(unused_extern_crates)]
+extern crate bigdecimal;
+#[allow(unused_extern_crates)]
+extern crate futures;
+#[allow(unused_extern_crates)]
+extern crate regex;
 
 pub mod generated;
@@ -10,7 +10,15 @@ fn main() {
 
     for line in input.lines() {
         let line = line.unwrap();
-        let entry: rust::generated::test::Entry = json::from_str(&line).unwrap();
+
+        let entry: rust::generated::test::Entry = match json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(e) => {
+                println!("error: {}", e);
+                continue;
+            }
+        };
+
         println!("{:?}", entry);
         println!("{}", json::to_string(&entry).unwrap());
     }
@@ -0,0 +1,27 @@
+import {Entry} from "test";
+
+declare function require(name: string): any;
+declare const process: any;
+
+const readline = require("readline");
+
+const rl = readline.createInterface({
+  input: process.stdin,
+  output: process.stdout,
+  terminal: false
+});
+
+rl.on("line", (line: string) => {
+  const data = JSON.parse(line);
+  let decoded: Entry;
+
+  try {
+    decoded = Entry.decode(data);
+  } catch (e) {
+    console.log("error: " + (e as Error).message);
+    return;
+  }
+
+  console.log(decoded);
+  console.log(JSON.stringify(decoded.encode()));
+});
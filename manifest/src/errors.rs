@@ -8,6 +8,7 @@ error_chain! {
     foreign_links {
         IO(::std::io::Error);
         TomlDe(::toml::de::Error);
+        TomlSer(::toml::ser::Error);
     }
 
     errors {
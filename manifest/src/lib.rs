@@ -17,8 +17,8 @@ pub mod errors;
 
 use errors::*;
 use relative_path::{RelativePath, RelativePathBuf};
-use reproto_core::{RpPackage, RpRequiredPackage, Version, VersionReq};
-use std::collections::HashMap;
+use reproto_core::{LintLevel, RpPackage, RpRequiredPackage, Version, VersionReq};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -157,6 +157,7 @@ pub enum Language {
     Json,
     Python,
     Rust,
+    TypeScript,
 }
 
 impl Language {
@@ -169,6 +170,7 @@ impl Language {
             "json" => Json,
             "python" => Python,
             "rust" => Rust,
+            "typescript" => TypeScript,
             _ => return None,
         };
 
@@ -360,6 +362,8 @@ where
     pub repository: Repository,
     /// Documentation settings.
     pub doc: Doc,
+    /// Levels of lints, overriding their defaults.
+    pub lints: BTreeMap<String, LintLevel>,
 }
 
 impl<L> Manifest<L>
@@ -379,6 +383,7 @@ where
             id_converter: Option::default(),
             repository: Repository::default(),
             doc: Doc::default(),
+            lints: BTreeMap::default(),
         }
     }
 }
@@ -484,6 +489,8 @@ where
         manifest.doc = doc;
     }
 
+    manifest.lints.extend(take_field::<BTreeMap<String, LintLevel>>(value, "lints")?);
+
     Ok(())
 }
 
@@ -535,6 +542,153 @@ where
     Ok(manifest)
 }
 
+/// Update the versions of the given packages in the `[publish]` section of a manifest.
+///
+/// Only the versions are replaced in the content of the manifest, so that comments and
+/// formatting are preserved.
+/// Returns the content of the updated manifest.
+pub fn update_publish_versions(content: &str, versions: &[(RpPackage, Version)]) -> Result<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut updated: Vec<RpPackage> = Vec::new();
+    let mut table: Vec<String> = Vec::new();
+
+    // splitting on newlines keeps any carriage returns, and the final newline.
+    for line in content.split('\n') {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') {
+            table = match trimmed.find(']') {
+                Some(end) if !trimmed.starts_with("[[") => split_keys(&trimmed[1..end]),
+                _ => Vec::new(),
+            };
+
+            lines.push(line.to_string());
+            continue;
+        }
+
+        match update_line(&table, line, versions)? {
+            Some((package, line)) => {
+                updated.push(package);
+                lines.push(line);
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    for &(ref package, _) in versions {
+        if !updated.contains(package) {
+            return Err(format!("no [publish] entry for package: {}", package).into());
+        }
+    }
+
+    let content = lines.join("\n");
+
+    // make sure that the manifest is still valid.
+    toml::from_str::<toml::value::Table>(&content)?;
+    return Ok(content);
+
+    /// Replace the version in a single line, if it is the version of one of the packages.
+    fn update_line(
+        table: &[String],
+        line: &str,
+        versions: &[(RpPackage, Version)],
+    ) -> Result<Option<(RpPackage, String)>> {
+        let (key, value) = match key_value(line) {
+            Some(key_value) => key_value,
+            None => return Ok(None),
+        };
+
+        let key = split_keys(key);
+
+        // `[publish]` entries are either version strings, or inline tables with a version.
+        let (package, inline) = match (table.len(), key.len()) {
+            (1, 1) if table[0] == "publish" => (&key[0], true),
+            (2, 1) if table[0] == "publish" && key[0] == "version" => (&table[1], false),
+            _ => return Ok(None),
+        };
+
+        let package = RpPackage::parse(package);
+
+        let version = match versions.iter().find(|&&(ref p, _)| *p == package) {
+            Some(&(_, ref version)) => version,
+            None => return Ok(None),
+        };
+
+        let mut start = line.len() - value.len();
+
+        if inline && value.trim_left().starts_with('{') {
+            let version_key = value.find("version").ok_or_else(|| {
+                format!("no version in [publish] entry for package: {}", package)
+            })?;
+
+            let eq = value[version_key..].find('=').ok_or_else(|| {
+                format!("no version in [publish] entry for package: {}", package)
+            })?;
+
+            start += version_key + eq + 1;
+        }
+
+        let open = line[start..].find(|c: char| !c.is_whitespace()).map(|i| start + i);
+
+        let quote = match open.and_then(|open| line[open..].chars().next()) {
+            Some(c) if c == '"' || c == '\'' => c,
+            _ => {
+                return Err(
+                    format!("version of package `{}` is not a string", package).into(),
+                )
+            }
+        };
+
+        let open = open.unwrap_or(start) + 1;
+
+        let close = line[open..].find(quote).map(|i| open + i).ok_or_else(|| {
+            format!("version of package `{}` is not a string", package)
+        })?;
+
+        let line = format!("{}{}{}", &line[..open], version, &line[close..]);
+        Ok(Some((package, line)))
+    }
+
+    /// Split a line into its key, and the rest of the line after `=`.
+    fn key_value(line: &str) -> Option<(&str, &str)> {
+        let mut quote = None;
+
+        for (i, c) in line.char_indices() {
+            match (quote, c) {
+                (None, '#') => return None,
+                (None, '=') => return Some((&line[..i], &line[i + 1..])),
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (Some(q), c) if q == c => quote = None,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Split a dotted key into its parts, without any quotes.
+    fn split_keys(keys: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut current = String::new();
+        let mut quote = None;
+
+        for c in keys.chars() {
+            match (quote, c) {
+                (None, '.') => {
+                    out.push(current.trim().to_string());
+                    current.clear();
+                }
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (Some(q), c) if q == c => quote = None,
+                _ => current.push(c),
+            }
+        }
+
+        out.push(current.trim().to_string());
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -615,6 +769,68 @@ mod tests {
         assert_eq!(1, manifest.publish.len());
     }
 
+    #[test]
+    pub fn test_update_publish_versions() {
+        let versions = vec![
+            (
+                RpPackage::parse("toystore"),
+                Version::parse("1.1.0").unwrap(),
+            ),
+        ];
+
+        for content in &[
+            &include_bytes!("tests/publish_string.reproto")[..],
+            &include_bytes!("tests/publish_table.reproto")[..],
+            &include_bytes!("tests/publish_table2.reproto")[..],
+        ]
+        {
+            let content = ::std::str::from_utf8(content).unwrap();
+            let content = update_publish_versions(content, &versions).unwrap();
+
+            let path = Path::new(".").join("reproto.toml");
+            let preamble = read_manifest_preamble(&path, Cursor::new(content)).unwrap();
+            let manifest = read_manifest::<NoLang>(preamble).unwrap();
+
+            assert_eq!(1, manifest.publish.len());
+            assert_eq!(versions[0].1, manifest.publish[0].version);
+        }
+    }
+
+    #[test]
+    pub fn test_update_publish_versions_in_place() {
+        let versions = vec![
+            (
+                RpPackage::parse("io.reproto"),
+                Version::parse("1.1.0").unwrap(),
+            ),
+            (
+                RpPackage::parse("toystore"),
+                Version::parse("2.0.0").unwrap(),
+            ),
+        ];
+
+        let content = "paths = [\"src\"] # sources\n\n\
+                       [publish]\n\
+                       # released together.\n\
+                       \"io.reproto\" = {version = '1.0.1'}  # trailing\n\
+                       other = \"0.1.0\"\n\n\
+                       [publish.toystore]\n\
+                       version = \"1.0.0\"\n";
+
+        let expected = "paths = [\"src\"] # sources\n\n\
+                        [publish]\n\
+                        # released together.\n\
+                        \"io.reproto\" = {version = '1.1.0'}  # trailing\n\
+                        other = \"0.1.0\"\n\n\
+                        [publish.toystore]\n\
+                        version = \"2.0.0\"\n";
+
+        assert_eq!(expected, update_publish_versions(content, &versions).unwrap());
+
+        let missing = vec![(RpPackage::parse("missing"), Version::parse("1.0.0").unwrap())];
+        assert!(update_publish_versions(content, &missing).is_err());
+    }
+
     #[test]
     pub fn test_files_string() {
         let manifest = include_manifest!("tests/files_string.reproto");
@@ -656,4 +872,14 @@ mod tests {
             manifest.repository.objects.as_ref().map(String::as_str)
         );
     }
+
+    #[test]
+    pub fn test_lints() {
+        let manifest = include_manifest!("tests/lints.reproto");
+
+        assert_eq!(3, manifest.lints.len());
+        assert_eq!(Some(&LintLevel::Warn), manifest.lints.get("missing_docs"));
+        assert_eq!(Some(&LintLevel::Allow), manifest.lints.get("any_type"));
+        assert_eq!(Some(&LintLevel::Deny), manifest.lints.get("field_naming"));
+    }
 }
@@ -13,6 +13,13 @@ pub enum Type {
     Any,
     /// ISO-8601 for date and time.
     DateTime,
+    /// ISO-8601 for dates, without time.
+    Date,
+    /// ISO-8601 for durations.
+    Duration,
+    Uuid,
+    /// Arbitrary-precision decimal number.
+    Decimal,
     Name { name: Name },
     Array { inner: Box<Type> },
     Map { key: Box<Type>, value: Box<Type> },
@@ -64,6 +71,29 @@ pub struct Field<'input> {
     pub comment: Vec<&'input str>,
     pub ty: Type,
     pub field_as: Option<String>,
+    /// Constraints on the value of the field, like `(min = 0, max = 10)`.
+    pub constraints: Vec<Loc<Constraint<'input>>>,
+    /// Value of the field when it is absent, like `= 10`.
+    pub default: Option<Loc<Value<'input>>>,
+}
+
+/// An ordinary comment, which has no semantics but is kept when formatting.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comment<'input> {
+    /// Start of the comment.
+    pub start: usize,
+    /// Start of the line that the comment is on.
+    pub line_start: usize,
+    /// If the comment follows other source on the same line.
+    pub trailing: bool,
+    /// Content of the comment, including the comment markers.
+    pub content: &'input str,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Constraint<'input> {
+    pub name: &'input str,
+    pub value: Loc<Value<'input>>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -157,6 +187,14 @@ pub struct Endpoint<'input> {
     pub options: Vec<Loc<OptionDecl<'input>>>,
     pub request: Option<Loc<Channel>>,
     pub response: Option<Loc<Channel>>,
+    pub http: Option<EndpointHttp<'input>>,
+}
+
+/// HTTP method and path template bound to an endpoint.
+#[derive(Debug, PartialEq, Eq)]
+pub struct EndpointHttp<'input> {
+    pub method: Loc<&'input str>,
+    pub path: Loc<String>,
 }
 
 /// Describes how data is transferred over a channel.
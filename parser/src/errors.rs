@@ -8,6 +8,7 @@ error_chain! {
 
     foreign_links {
         Io(::std::io::Error);
+        Fmt(::std::fmt::Error);
         ParseInt(::std::num::ParseIntError);
         ParseFloat(::std::num::ParseFloatError);
         ParseBigIntError(::num::bigint::ParseBigIntError);
@@ -32,5 +33,10 @@ error_chain! {
             description("parse error")
             display("parse error: {}", message)
         }
+
+        Errors(errors: Vec<Error>) {
+            description("errors")
+            display("encountered {} error(s)", errors.len())
+        }
     }
 }
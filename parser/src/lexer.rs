@@ -1,3 +1,4 @@
+use super::ast::Comment;
 use super::token::*;
 use core::RpNumber;
 use num::Zero;
@@ -72,6 +73,8 @@ pub struct Lexer<'input> {
     buffer: String,
     code_block: Option<(usize, usize)>,
     code_close: Option<(usize, usize)>,
+    /// Ordinary comments encountered, if they should be collected.
+    comments: Option<Vec<Comment<'input>>>,
 }
 
 impl<'input> Lexer<'input> {
@@ -142,13 +145,21 @@ impl<'input> Lexer<'input> {
             "as" => Token::AsKeyword,
             "float" => Token::FloatKeyword,
             "double" => Token::DoubleKeyword,
+            "i8" => Token::Signed8,
+            "i16" => Token::Signed16,
             "i32" => Token::Signed32,
             "i64" => Token::Signed64,
+            "u8" => Token::Unsigned8,
+            "u16" => Token::Unsigned16,
             "u32" => Token::Unsigned32,
             "u64" => Token::Unsigned64,
             "boolean" => Token::BooleanKeyword,
             "string" => Token::StringKeyword,
             "datetime" => Token::DateTimeKeyword,
+            "date" => Token::DateKeyword,
+            "duration" => Token::DurationKeyword,
+            "uuid" => Token::UuidKeyword,
+            "decimal" => Token::DecimalKeyword,
             "bytes" => Token::BytesKeyword,
             "true" => Token::TrueKeyword,
             "false" => Token::FalseKeyword,
@@ -422,24 +433,47 @@ impl<'input> Lexer<'input> {
         }
     }
 
-    fn line_comment(&mut self) {
-        let start = self.step_n(2);
-        let _ = take_until!(self, start, '\n' | '\r');
+    fn line_comment(&mut self, start: usize) {
+        let content_start = self.step_n(2);
+        let (_, content) = take_until!(self, content_start, '\n' | '\r');
+        let end = content_start + content.len();
+        self.record_comment(start, end);
     }
 
-    // block comments have no semantics and are completely ignored.
-    fn block_comment(&mut self) {
+    // block comments have no semantics and are ignored by the parser.
+    fn block_comment(&mut self, start: usize) {
         self.step_n(2);
 
+        let mut end = self.source_len;
+
         while let Some((_, a, b)) = self.two() {
             if ('*', '/') == (a, b) {
                 self.step();
-                self.step();
+                end = self.step_n(1);
                 break;
             }
 
             self.step();
         }
+
+        self.record_comment(start, end);
+    }
+
+    /// Record the comment between `start` and `end`, if comments are being collected.
+    fn record_comment(&mut self, start: usize, end: usize) {
+        let source_str = self.source_str;
+
+        if let Some(ref mut comments) = self.comments {
+            let line_start = source_str[..start].rfind('\n').map(|n| n + 1).unwrap_or(0);
+            let trailing = !source_str[line_start..start].trim().is_empty();
+
+            comments.push(Comment {
+                start: start,
+                line_start: line_start,
+                trailing: trailing,
+                content: source_str[start..end].trim_right(),
+            });
+        }
     }
 
     fn normal_mode_next(&mut self) -> Option<Result<(usize, Token<'input>, usize)>> {
@@ -469,11 +503,11 @@ impl<'input> Lexer<'input> {
             if let Some((start, a, b)) = self.two() {
                 let token = match (a, b) {
                     ('/', '/') => {
-                        self.line_comment();
+                        self.line_comment(start);
                         continue;
                     }
                     ('/', '*') => {
-                        self.block_comment();
+                        self.block_comment(start);
                         continue;
                     }
                     ('{', '{') => {
@@ -555,9 +589,22 @@ pub fn lex(input: &str) -> Lexer {
         buffer: String::new(),
         code_block: None,
         code_close: None,
+        comments: None,
     }
 }
 
+/// Collect all ordinary comments in the input, in the order in which they appear.
+///
+/// Lexing stops at the first error, so this is intended for input which has already been parsed.
+pub fn comments(input: &str) -> Vec<Comment> {
+    let mut lexer = lex(input);
+    lexer.comments = Some(Vec::new());
+
+    while let Some(Ok(_)) = lexer.next() {}
+
+    lexer.comments.unwrap_or_else(Vec::new)
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -12,9 +12,11 @@ pub mod ast;
 pub mod errors;
 #[allow(unused)]
 mod parser;
+pub mod printer;
 mod token;
 
 use core::Object;
+use lalrpop_util::ParseError;
 use self::errors::*;
 use std::io::Read;
 use std::rc::Rc;
@@ -28,62 +30,104 @@ where
     Ok(content)
 }
 
+/// Parse the given input.
+///
+/// Syntax errors are recovered from where possible, so that every error in the input is reported.
 pub fn parse_string<'input>(
     object: Rc<Box<Object>>,
     input: &'input str,
 ) -> Result<ast::File<'input>> {
-    use self::ErrorKind::*;
-    use lalrpop_util::ParseError::*;
-    use self::token::Error::*;
-
     let lexer = lexer::lex(input);
 
-    match parser::parse_File(&object, lexer) {
-        Ok(file) => Ok(file),
-        Err(e) => {
-            match e {
-                InvalidToken { location } => {
-                    let pos = (object.clone(), location, location);
-                    Err(Syntax(Some(pos.into()), vec![]).into())
-                }
-                UnrecognizedToken { token, expected } => {
-                    let pos = token.map(|(start, _, end)| (object.clone(), start, end));
-                    Err(Syntax(pos.map(Into::into), expected).into())
-                }
-                User { error } => {
-                    match error {
-                        UnterminatedString { start } => {
-                            let pos = (object.clone(), start, start);
-                            return Err(Parse("unterminated string", pos.into()).into());
-                        }
-                        UnterminatedEscape { start } => {
-                            let pos = (object.clone(), start, start);
-                            return Err(Parse("unterminated escape sequence", pos.into()).into());
-                        }
-                        InvalidEscape { pos, message } => {
-                            let pos = (object.clone(), pos, pos);
-                            return Err(Parse(message, pos.into()).into());
-                        }
-                        UnterminatedCodeBlock { start } => {
-                            let pos = (object.clone(), start, start);
-                            return Err(Parse("unterminated code block", pos.into()).into());
-                        }
-                        InvalidNumber { pos, message } => {
-                            let pos = (object.clone(), pos, pos);
-                            return Err(Parse(message, pos.into()).into());
-                        }
-                        Unexpected { pos } => {
-                            let pos = (object.clone(), pos, pos);
-                            return Err(Parse("unexpected input", pos.into()).into());
-                        }
+    let mut recovered = Vec::new();
+    let result = parser::parse_File(&object, &mut recovered, lexer);
+
+    let mut errors: Vec<Error> = recovered
+        .into_iter()
+        .map(|r| convert_error(&object, r.error))
+        .collect();
+
+    match result {
+        Ok(file) => {
+            if errors.is_empty() {
+                return Ok(file);
+            }
+        }
+        Err(e) => errors.push(convert_error(&object, e)),
+    }
+
+    if errors.len() == 1 {
+        return Err(errors.remove(0));
+    }
+
+    return Err(ErrorKind::Errors(errors).into());
+
+    /// Convert an error from the generated parser.
+    fn convert_error<'a>(
+        object: &Rc<Box<Object>>,
+        e: ParseError<usize, token::Token<'a>, token::Error>,
+    ) -> Error {
+        use self::ErrorKind::*;
+        use lalrpop_util::ParseError::*;
+        use self::token::Error::*;
+
+        match e {
+            InvalidToken { location } => {
+                let pos = (object.clone(), location, location);
+                Syntax(Some(pos.into()), vec![]).into()
+            }
+            UnrecognizedToken { token, expected } => {
+                let pos = token.map(|(start, _, end)| (object.clone(), start, end));
+                Syntax(pos.map(Into::into), expected).into()
+            }
+            User { error } => {
+                match error {
+                    UnterminatedString { start } => {
+                        let pos = (object.clone(), start, start);
+                        Parse("unterminated string", pos.into()).into()
+                    }
+                    UnterminatedEscape { start } => {
+                        let pos = (object.clone(), start, start);
+                        Parse("unterminated escape sequence", pos.into()).into()
+                    }
+                    InvalidEscape { pos, message } => {
+                        let pos = (object.clone(), pos, pos);
+                        Parse(message, pos.into()).into()
+                    }
+                    UnterminatedCodeBlock { start } => {
+                        let pos = (object.clone(), start, start);
+                        Parse("unterminated code block", pos.into()).into()
+                    }
+                    InvalidNumber { pos, message } => {
+                        let pos = (object.clone(), pos, pos);
+                        Parse(message, pos.into()).into()
+                    }
+                    Unexpected { pos } => {
+                        let pos = (object.clone(), pos, pos);
+                        Parse("unexpected input", pos.into()).into()
                     }
                 }
-                _ => Err("parse error".into()),
             }
+            _ => "parse error".into(),
         }
     }
 }
 
+/// Collect the ordinary comments in the given input, in the order in which they appear.
+pub fn parse_comments<'input>(input: &'input str) -> Vec<ast::Comment<'input>> {
+    lexer::comments(input)
+}
+
+/// Format the given input in the canonical layout, keeping all comments.
+pub fn format_string(object: Rc<Box<Object>>, input: &str) -> Result<String> {
+    let file = parse_string(object, input)?;
+    let comments = parse_comments(input);
+
+    let mut out = String::new();
+    printer::print_with_comments(&mut out, &file, &comments)?;
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use core::*;
@@ -101,14 +145,15 @@ mod tests {
     /// Check that a parsed value equals expected.
     macro_rules! assert_value_eq {
         ($expected:expr, $input:expr) => {{
-            let v = parser::parse_Value(&new_context(), parse($input)).unwrap();
+            let v = parser::parse_Value(&new_context(), &mut Vec::new(), parse($input)).unwrap();
             assert_eq!($expected, v);
         }}
     }
 
     macro_rules! assert_type_spec_eq {
         ($expected:expr, $input:expr) => {{
-            let v = parser::parse_TypeSpec(&new_context(), parse($input)).unwrap();
+            let v = parser::parse_TypeSpec(&new_context(), &mut Vec::new(), parse($input));
+            let v = v.unwrap();
             assert_eq!($expected, v);
         }}
     }
@@ -123,15 +168,15 @@ mod tests {
     }
 
     fn parse_file(input: &'static str) -> File {
-        parser::parse_File(&new_context(), parse(input)).unwrap()
+        parser::parse_File(&new_context(), &mut Vec::new(), parse(input)).unwrap()
     }
 
     fn parse_member(input: &'static str) -> Loc<Member> {
-        parser::parse_Member(&new_context(), parse(input)).unwrap()
+        parser::parse_Member(&new_context(), &mut Vec::new(), parse(input)).unwrap()
     }
 
     fn parse_type_spec(input: &'static str) -> Type {
-        parser::parse_TypeSpec(&new_context(), parse(input)).unwrap()
+        parser::parse_TypeSpec(&new_context(), &mut Vec::new(), parse(input)).unwrap()
     }
 
     #[test]
@@ -247,6 +292,45 @@ mod tests {
         assert_type_spec_eq!(Type::Name { name: c }, "Hello::World");
     }
 
+    #[test]
+    fn test_builtin_types() {
+        assert_type_spec_eq!(Type::Signed { size: 8 }, "i8");
+        assert_type_spec_eq!(Type::Signed { size: 16 }, "i16");
+        assert_type_spec_eq!(Type::Unsigned { size: 8 }, "u8");
+        assert_type_spec_eq!(Type::Unsigned { size: 16 }, "u16");
+        assert_type_spec_eq!(Type::Date, "date");
+        assert_type_spec_eq!(Type::Duration, "duration");
+        assert_type_spec_eq!(Type::Uuid, "uuid");
+        assert_type_spec_eq!(Type::Decimal, "decimal");
+    }
+
+    #[test]
+    fn test_type_keywords_as_identifiers() {
+        let member = parse_member("date: date;").take();
+
+        if let Member::Field(field) = member {
+            assert_eq!("date", field.name);
+            assert_eq!(Type::Date, field.ty);
+        } else {
+            panic!("field did not match");
+        }
+
+        let file = parse_file(
+            "use foo.uuid as decimal;\n\ntype Foo {\n  duration: decimal::Bar;\n}\n",
+        );
+
+        let package: Vec<&str> = file.uses[0].package.parts.iter().map(String::as_str).collect();
+        assert_eq!(vec!["foo", "uuid"], package);
+        assert_eq!(Some("decimal"), file.uses[0].alias.as_ref().map(|a| *a.value()));
+
+        let c = Name::Absolute {
+            prefix: Some("decimal".to_owned()),
+            parts: vec!["Bar".to_owned()],
+        };
+
+        assert_type_spec_eq!(Type::Name { name: c }, "decimal::Bar");
+    }
+
     #[test]
     fn test_option_decl() {
         let member = parse_member("option foo_bar_baz = true;").take();
@@ -259,4 +343,234 @@ mod tests {
 
         panic!("option did not match");
     }
+
+    #[test]
+    fn test_field_constraints() {
+        let member = parse_member("name: string(min_length = 1, pattern = \"^[a-z]+$\") as n;")
+            .take();
+
+        if let Member::Field(field) = member {
+            let constraints: Vec<_> = field
+                .constraints
+                .iter()
+                .map(|c| (c.name, c.value.value()))
+                .collect();
+
+            assert_eq!(
+                vec![
+                    ("min_length", &Value::Number(1u32.into())),
+                    ("pattern", &Value::String("^[a-z]+$".to_owned())),
+                ],
+                constraints
+            );
+
+            assert_eq!(Some("n".to_owned()), field.field_as);
+            return;
+        }
+
+        panic!("field did not match");
+    }
+
+    #[test]
+    fn test_field_default() {
+        let member = parse_member("tags?: [string] = [\"a\", \"b\"];").take();
+
+        if let Member::Field(field) = member {
+            let values: Vec<&Value> = match field.default.as_ref().map(|d| d.value()) {
+                Some(&Value::Array(ref values)) => values.iter().map(|v| v.value()).collect(),
+                _ => vec![],
+            };
+
+            assert_eq!(
+                vec![
+                    &Value::String("a".to_owned()),
+                    &Value::String("b".to_owned()),
+                ],
+                values
+            );
+
+            return;
+        }
+
+        panic!("field did not match");
+    }
+
+    #[test]
+    fn test_endpoint_http() {
+        let file = parse_file(
+            "service Toys { GET \"/toys/{id}\" get_toy(GetToy) -> Toy; list() -> stream Toy; }",
+        );
+
+        let service = match *file.decls[0].value() {
+            Decl::Service(ref service) => service,
+            _ => panic!("expected service"),
+        };
+
+        let get_toy = service.endpoints[0].value();
+        let http = get_toy.http.as_ref().expect("expected http binding");
+        assert_eq!("GET", *http.method.value());
+        assert_eq!("/toys/{id}", http.path.value().as_str());
+
+        assert!(service.endpoints[1].value().http.is_none());
+    }
+
+    /// Print the given file and check that it parses back into the same file.
+    fn assert_round_trip(input: &str) {
+        let file = parser::parse_File(&new_context(), &mut Vec::new(), lexer::lex(input));
+        let file = file.unwrap();
+
+        let mut printed = String::new();
+        printer::print(&mut printed, &file).unwrap();
+
+        let reparsed = parser::parse_File(&new_context(), &mut Vec::new(), lexer::lex(&printed));
+        let reparsed = reparsed.unwrap();
+        assert_eq!(file, reparsed);
+    }
+
+    #[test]
+    fn test_print_file1() {
+        assert_round_trip(::std::str::from_utf8(FILE1).unwrap());
+    }
+
+    #[test]
+    fn test_print_interface() {
+        assert_round_trip(::std::str::from_utf8(INTERFACE1).unwrap());
+    }
+
+    #[test]
+    fn test_print_escapes() {
+        assert_round_trip(
+            r#"
+            //! Package docs.
+            use foo.bar "^1" as _type;
+
+            option naming = "a \u0022quoted\u0022 \u005c string\n";
+
+            /// Docs.
+            type Foo {
+                _type: string as "type";
+                values?: {string: [_type::Bar]}(non_empty = true);
+                size: u32(min = 1, max = 10.5);
+                limit?: u32 = 10 as "max";
+            }
+
+            enum Baz as string {
+                FIRST as "first";
+
+                option reserved = [1, 2.5, true, ident];
+            }
+
+            service Toys {
+                POST "/toys/{id}" put_toy(stream Foo) -> stream ::Baz as "put-toy" {
+                    option http_status = 201;
+                }
+            }
+            "#,
+        );
+    }
+
+    fn format(input: &str) -> String {
+        format_string(new_context(), input).unwrap()
+    }
+
+    #[test]
+    fn test_format_comments() {
+        let input = r#"
+//! Package docs.
+use foo.bar;   // trailing use
+
+// Before a type.
+/// Docs.
+type   Foo {   // trailing brace
+  // Before a field.
+    a: string; // trailing field
+  b?: u32;
+    /* block */
+    java {{
+        return 1;
+    }}
+  // Last in block.
+}  // after type
+
+enum Baz as string { // trailing enum brace
+FIRST as "first"; // first
+}
+
+// End of file.
+"#;
+
+        let expected = r#"//! Package docs.
+
+use foo.bar; // trailing use
+
+// Before a type.
+/// Docs.
+type Foo { // trailing brace
+  // Before a field.
+  a: string; // trailing field
+  b?: u32;
+
+  /* block */
+  java {{
+    return 1;
+  }}
+  // Last in block.
+} // after type
+
+enum Baz as string { // trailing enum brace
+  FIRST as "first"; // first
+}
+
+// End of file.
+"#;
+
+        assert_eq!(expected, format(input));
+    }
+
+    #[test]
+    fn test_format_idempotent() {
+        let inputs = vec![
+            ::std::str::from_utf8(FILE1).unwrap(),
+            ::std::str::from_utf8(INTERFACE1).unwrap(),
+            "type Foo { // trailing brace\n a: string; b: string; // second\n}",
+            "// Only a comment.",
+        ];
+
+        for input in inputs {
+            let formatted = format(input);
+            assert_eq!(formatted, format(&formatted));
+        }
+    }
+
+    #[test]
+    fn test_recover_errors() {
+        let input = "type Foo {\n  a: ;\n  b: string;\n  c: ;\n}\n\ntype Bar {\n  d string;\n}";
+
+        let e = parse_string(new_context(), input).unwrap_err();
+
+        let positions: Vec<usize> = match *e.kind() {
+            ErrorKind::Errors(ref errors) => {
+                errors
+                    .iter()
+                    .map(|e| match *e.kind() {
+                        ErrorKind::Syntax(Some(ref pos), _) => pos.start,
+                        ref other => panic!("expected syntax error, got: {:?}", other),
+                    })
+                    .collect()
+            }
+            ref other => panic!("expected errors, got: {:?}", other),
+        };
+
+        assert_eq!(vec![16, 36, 56], positions);
+    }
+
+    #[test]
+    fn test_recover_single_error() {
+        let input = "type Foo {\n  a: ;\n  b: string;\n}";
+
+        match *parse_string(new_context(), input).unwrap_err().kind() {
+            ErrorKind::Syntax(Some(ref pos), _) => assert_eq!(16, pos.start),
+            ref other => panic!("expected syntax error, got: {:?}", other),
+        }
+    }
 }
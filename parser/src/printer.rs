@@ -0,0 +1,608 @@
+//! Prints an AST back into the reproto language.
+//!
+//! The printed output is guaranteed to parse back into an equivalent AST, and printing it again
+//! gives the same output.
+
+use ast::*;
+use core::{Loc, RpModifier};
+use std::cmp;
+use std::fmt::{self, Write};
+use std::usize;
+
+const INDENT: &str = "  ";
+
+/// Identifiers which would be lexed as keywords, and need to be escaped.
+///
+/// `date`, `duration`, `uuid` and `decimal` are keywords, but are accepted as identifiers.
+const KEYWORDS: &[&str] = &[
+    "any",
+    "interface",
+    "type",
+    "enum",
+    "tuple",
+    "service",
+    "use",
+    "as",
+    "float",
+    "double",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "boolean",
+    "string",
+    "datetime",
+    "bytes",
+    "true",
+    "false",
+    "stream",
+    "option",
+];
+
+/// Print the given file.
+pub fn print<W: Write>(out: &mut W, file: &File) -> fmt::Result {
+    print_with_comments(out, file, &[])
+}
+
+/// Print the given file, keeping ordinary comments close to where they appeared in the source.
+///
+/// Comments must be in the order in which they appear, as returned by `comments`.
+pub fn print_with_comments<W: Write>(
+    out: &mut W,
+    file: &File,
+    comments: &[Comment],
+) -> fmt::Result {
+    Printer::new(out, comments).file(file)
+}
+
+struct Printer<'a, 'c: 'a, W: 'a> {
+    out: &'a mut W,
+    level: usize,
+    /// Comments which have not been printed yet.
+    comments: &'a [Comment<'c>],
+}
+
+impl<'a, 'c, W: Write> Printer<'a, 'c, W> {
+    fn new(out: &'a mut W, comments: &'a [Comment<'c>]) -> Printer<'a, 'c, W> {
+        Printer {
+            out: out,
+            level: 0,
+            comments: comments,
+        }
+    }
+
+    fn indent(&mut self) -> fmt::Result {
+        for _ in 0..self.level {
+            self.out.write_str(INDENT)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a single indented line.
+    fn line(&mut self, line: &str) -> fmt::Result {
+        if line.is_empty() {
+            return self.out.write_char('\n');
+        }
+
+        self.indent()?;
+        self.out.write_str(line)?;
+        self.out.write_char('\n')
+    }
+
+    fn comment(&mut self, prefix: &str, comment: &[&str]) -> fmt::Result {
+        for line in comment {
+            self.indent()?;
+            writeln!(self.out, "{}{}", prefix, line)?;
+        }
+
+        Ok(())
+    }
+
+    /// Print all remaining comments which start before `pos`, each on a line of its own.
+    fn comments_before(&mut self, pos: usize) -> fmt::Result {
+        loop {
+            let comments = self.comments;
+
+            match comments.split_first() {
+                Some((comment, rest)) if comment.start < pos => {
+                    self.indent()?;
+                    writeln!(self.out, "{}", comment.content)?;
+                    self.comments = rest;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// End the current line, which ends at `end` in the source.
+    ///
+    /// Comments which trail on the same line in the source are kept on the line.
+    fn end_line(&mut self, end: usize) -> fmt::Result {
+        loop {
+            let comments = self.comments;
+
+            match comments.split_first() {
+                Some((comment, rest))
+                    if comment.trailing && comment.line_start <= end && comment.start >= end => {
+                    write!(self.out, " {}", comment.content)?;
+                    self.comments = rest;
+                }
+                _ => break,
+            }
+        }
+
+        self.out.write_char('\n')
+    }
+
+    /// End the line opening a block, whose contents start at `next` in the source.
+    ///
+    /// Comments which trail the opening line in the source are kept on the line.
+    fn open(&mut self, next: usize) -> fmt::Result {
+        loop {
+            let comments = self.comments;
+
+            match comments.split_first() {
+                Some((comment, rest)) if comment.trailing && comment.start < next => {
+                    write!(self.out, " {}", comment.content)?;
+                    self.comments = rest;
+                }
+                _ => break,
+            }
+        }
+
+        self.out.write_char('\n')
+    }
+
+    /// Close the current block, which ends at `end` in the source.
+    fn close(&mut self, close: &str, end: usize) -> fmt::Result {
+        self.comments_before(end)?;
+        self.level -= 1;
+        self.indent()?;
+        self.out.write_str(close)?;
+        self.end_line(end)
+    }
+
+    /// Separate sections with an empty line.
+    fn section(&mut self, first: &mut bool) -> fmt::Result {
+        if !*first {
+            self.out.write_char('\n')?;
+        }
+
+        *first = false;
+        Ok(())
+    }
+
+    fn file(&mut self, file: &File) -> fmt::Result {
+        let mut first = true;
+
+        if !file.comment.is_empty() {
+            self.section(&mut first)?;
+            self.comment("//!", &file.comment)?;
+        }
+
+        if !file.uses.is_empty() {
+            self.section(&mut first)?;
+
+            for u in &file.uses {
+                self.comments_before(u.pos().start)?;
+                use_decl(self.out, u)?;
+                self.end_line(u.pos().end)?;
+            }
+        }
+
+        if !file.options.is_empty() {
+            self.section(&mut first)?;
+
+            for option in &file.options {
+                self.comments_before(option.pos().start)?;
+                self.option(option, option.pos().end)?;
+            }
+        }
+
+        for decl in &file.decls {
+            self.section(&mut first)?;
+            self.comments_before(decl.pos().start)?;
+            self.decl(decl)?;
+        }
+
+        if !self.comments.is_empty() {
+            self.section(&mut first)?;
+            self.comments_before(usize::MAX)?;
+        }
+
+        Ok(())
+    }
+
+    fn option(&mut self, option: &OptionDecl, end: usize) -> fmt::Result {
+        self.indent()?;
+        write!(self.out, "option {} = ", Ident(option.name))?;
+        value(self.out, &option.value)?;
+        self.out.write_char(';')?;
+        self.end_line(end)
+    }
+
+    fn decl(&mut self, decl: &Decl) -> fmt::Result {
+        match *decl {
+            Decl::Type(ref body) => {
+                self.comment("///", &body.comment)?;
+                self.indent()?;
+                write!(self.out, "type {} {{", body.name)?;
+                self.open(first_pos(&body.members, body.pos().end))?;
+                self.block(&body.members, body.pos().end)
+            }
+            Decl::Tuple(ref body) => {
+                self.comment("///", &body.comment)?;
+                self.indent()?;
+                write!(self.out, "tuple {} {{", body.name)?;
+                self.open(first_pos(&body.members, body.pos().end))?;
+                self.block(&body.members, body.pos().end)
+            }
+            Decl::Interface(ref body) => self.interface(body),
+            Decl::Enum(ref body) => self.enum_(body),
+            Decl::Service(ref body) => self.service(body),
+        }
+    }
+
+    fn interface(&mut self, body: &Loc<InterfaceBody>) -> fmt::Result {
+        self.comment("///", &body.comment)?;
+        self.indent()?;
+        write!(self.out, "interface {} {{", body.name)?;
+
+        let end = body.pos().end;
+        self.open(cmp::min(first_pos(&body.members, end), first_pos(&body.sub_types, end)))?;
+
+        self.level += 1;
+        let mut first = self.members_inner(&body.members)?;
+
+        for sub_type in &body.sub_types {
+            if !first {
+                self.out.write_char('\n')?;
+            }
+
+            first = false;
+
+            self.comments_before(sub_type.pos().start)?;
+            self.comment("///", &sub_type.comment)?;
+            self.indent()?;
+            self.out.write_str(*sub_type.name)?;
+
+            if let Some(ref alias) = sub_type.alias {
+                self.out.write_str(" as ")?;
+                value(self.out, alias)?;
+            }
+
+            self.out.write_str(" {")?;
+            self.open(first_pos(&sub_type.members, sub_type.pos().end))?;
+            self.block(&sub_type.members, sub_type.pos().end)?;
+        }
+
+        self.close("}", body.pos().end)
+    }
+
+    fn enum_(&mut self, body: &Loc<EnumBody>) -> fmt::Result {
+        self.comment("///", &body.comment)?;
+        self.indent()?;
+        write!(self.out, "enum {}", body.name)?;
+
+        if let Some(ref ty) = body.ty {
+            write!(self.out, " as {}", TypeSpec(ty))?;
+        }
+
+        self.out.write_str(" {")?;
+
+        let end = body.pos().end;
+        self.open(cmp::min(first_pos(&body.variants, end), first_pos(&body.members, end)))?;
+        self.level += 1;
+
+        for variant in &body.variants {
+            self.comments_before(variant.pos().start)?;
+            self.comment("///", &variant.comment)?;
+            self.indent()?;
+            self.out.write_str(*variant.name)?;
+
+            if let Some(ref argument) = variant.argument {
+                self.out.write_str(" as ")?;
+                value(self.out, argument)?;
+            }
+
+            self.out.write_char(';')?;
+            self.end_line(variant.pos().end)?;
+        }
+
+        if !body.members.is_empty() {
+            if !body.variants.is_empty() {
+                self.out.write_char('\n')?;
+            }
+
+            self.members_inner(&body.members)?;
+        }
+
+        self.close("}", body.pos().end)
+    }
+
+    fn service(&mut self, body: &Loc<ServiceBody>) -> fmt::Result {
+        self.comment("///", &body.comment)?;
+        self.indent()?;
+        write!(self.out, "service {} {{", body.name)?;
+        self.open(first_pos(&body.endpoints, body.pos().end))?;
+        self.level += 1;
+
+        for (i, endpoint) in body.endpoints.iter().enumerate() {
+            if i > 0 {
+                self.out.write_char('\n')?;
+            }
+
+            self.comments_before(endpoint.pos().start)?;
+            self.endpoint(endpoint)?;
+        }
+
+        self.close("}", body.pos().end)
+    }
+
+    fn endpoint(&mut self, endpoint: &Loc<Endpoint>) -> fmt::Result {
+        self.comment("///", &endpoint.comment)?;
+        self.indent()?;
+
+        if let Some(ref http) = endpoint.http {
+            write!(self.out, "{} ", *http.method)?;
+            string(self.out, &http.path)?;
+            self.out.write_char(' ')?;
+        }
+
+        write!(self.out, "{}(", Ident(*endpoint.id))?;
+
+        if let Some(ref request) = endpoint.request {
+            channel(self.out, request)?;
+        }
+
+        self.out.write_char(')')?;
+
+        if let Some(ref response) = endpoint.response {
+            self.out.write_str(" -> ")?;
+            channel(self.out, response)?;
+        }
+
+        if let Some(ref alias) = endpoint.alias {
+            self.out.write_str(" as ")?;
+            string(self.out, alias)?;
+        }
+
+        if endpoint.options.is_empty() {
+            self.out.write_char(';')?;
+            return self.end_line(endpoint.pos().end);
+        }
+
+        self.out.write_str(" {")?;
+        self.open(first_pos(&endpoint.options, endpoint.pos().end))?;
+        self.level += 1;
+
+        for option in &endpoint.options {
+            self.comments_before(option.pos().start)?;
+            self.option(option, option.pos().end)?;
+        }
+
+        self.close("}", endpoint.pos().end)
+    }
+
+    /// Print members in a nested block, which ends at `end` in the source.
+    fn block(&mut self, members: &[Loc<Member>], end: usize) -> fmt::Result {
+        self.level += 1;
+        self.members_inner(members)?;
+        self.close("}", end)
+    }
+
+    /// Print members at the current level.
+    ///
+    /// Consecutive fields and options are grouped together, everything else is separated by an
+    /// empty line. Returns `true` if no members were printed.
+    fn members_inner(&mut self, members: &[Loc<Member>]) -> Result<bool, fmt::Error> {
+        let mut previous: Option<&Member> = None;
+
+        for member in members {
+            let (member, pos) = member.as_ref_pair();
+
+            if let Some(previous) = previous {
+                let grouped = match (previous, member) {
+                    (&Member::Field(_), &Member::Field(ref field)) => field.comment.is_empty(),
+                    (&Member::Option(_), &Member::Option(_)) => true,
+                    _ => false,
+                };
+
+                if !grouped {
+                    self.out.write_char('\n')?;
+                }
+            }
+
+            self.comments_before(pos.start)?;
+
+            match *member {
+                Member::Field(ref field) => self.field(field, pos.end)?,
+                Member::Code(context, ref lines) => {
+                    self.indent()?;
+                    writeln!(self.out, "{} {{{{", Ident(context))?;
+                    self.level += 1;
+
+                    for line in lines {
+                        self.line(line)?;
+                    }
+
+                    self.close("}}", pos.end)?;
+                }
+                Member::Option(ref option) => self.option(option, pos.end)?,
+                Member::InnerDecl(ref decl) => self.decl(decl)?,
+            }
+
+            previous = Some(member);
+        }
+
+        Ok(previous.is_none())
+    }
+
+    fn field(&mut self, field: &Field, end: usize) -> fmt::Result {
+        self.comment("///", &field.comment)?;
+        self.indent()?;
+        write!(self.out, "{}", Ident(field.name))?;
+
+        if let RpModifier::Optional = field.modifier {
+            self.out.write_char('?')?;
+        }
+
+        write!(self.out, ": {}", TypeSpec(&field.ty))?;
+
+        if !field.constraints.is_empty() {
+            self.out.write_char('(')?;
+
+            for (i, constraint) in field.constraints.iter().enumerate() {
+                if i > 0 {
+                    self.out.write_str(", ")?;
+                }
+
+                write!(self.out, "{} = ", Ident(constraint.name))?;
+                value(self.out, constraint.value.value())?;
+            }
+
+            self.out.write_char(')')?;
+        }
+
+        if let Some(ref default) = field.default {
+            self.out.write_str(" = ")?;
+            value(self.out, default.value())?;
+        }
+
+        if let Some(ref alias) = field.field_as {
+            self.out.write_str(" as ")?;
+            string(self.out, alias)?;
+        }
+
+        self.out.write_char(';')?;
+        self.end_line(end)
+    }
+}
+
+/// Start of the first of the given items in the source, or `end` if there are none.
+fn first_pos<T>(items: &[Loc<T>], end: usize) -> usize {
+    items.first().map(|item| item.pos().start).unwrap_or(end)
+}
+
+fn use_decl<W: Write>(out: &mut W, u: &UseDecl) -> fmt::Result {
+    write!(out, "use {}", u.package.parts.join("."))?;
+
+    if let Some(ref version_req) = u.version_req {
+        out.write_char(' ')?;
+        string(out, version_req)?;
+    }
+
+    if let Some(ref alias) = u.alias {
+        write!(out, " as {}", Ident(*alias.value()))?;
+    }
+
+    out.write_char(';')
+}
+
+fn channel<W: Write>(out: &mut W, channel: &Channel) -> fmt::Result {
+    match *channel {
+        Channel::Unary { ref ty } => write!(out, "{}", TypeSpec(ty)),
+        Channel::Streaming { ref ty } => write!(out, "stream {}", TypeSpec(ty)),
+    }
+}
+
+fn value<W: Write>(out: &mut W, value: &Value) -> fmt::Result {
+    match *value {
+        Value::String(ref s) => string(out, s),
+        Value::Number(ref number) => write!(out, "{}", number),
+        Value::Boolean(b) => write!(out, "{}", b),
+        Value::Identifier(identifier) => write!(out, "{}", Ident(identifier)),
+        Value::Array(ref values) => {
+            out.write_char('[')?;
+
+            for (i, v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+
+                self::value(out, v)?;
+            }
+
+            out.write_char(']')
+        }
+    }
+}
+
+/// Write a quoted string.
+///
+/// The lexer has no escapes for quotes and backslashes, so these are written as unicode escapes.
+fn string<W: Write>(out: &mut W, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\u0022")?,
+            '\\' => out.write_str("\\u005c")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            c => out.write_char(c)?,
+        }
+    }
+
+    out.write_char('"')
+}
+
+/// An identifier, escaped with a leading underscore if it collides with a keyword.
+struct Ident<'a>(&'a str);
+
+impl<'a> fmt::Display for Ident<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if KEYWORDS.contains(&self.0) {
+            fmt.write_char('_')?;
+        }
+
+        fmt.write_str(self.0)
+    }
+}
+
+struct TypeSpec<'a>(&'a Type);
+
+impl<'a> fmt::Display for TypeSpec<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self.0 {
+            Type::Double => fmt.write_str("double"),
+            Type::Float => fmt.write_str("float"),
+            Type::Signed { size } => write!(fmt, "i{}", size),
+            Type::Unsigned { size } => write!(fmt, "u{}", size),
+            Type::Boolean => fmt.write_str("boolean"),
+            Type::String => fmt.write_str("string"),
+            Type::Bytes => fmt.write_str("bytes"),
+            Type::Any => fmt.write_str("any"),
+            Type::DateTime => fmt.write_str("datetime"),
+            Type::Date => fmt.write_str("date"),
+            Type::Duration => fmt.write_str("duration"),
+            Type::Uuid => fmt.write_str("uuid"),
+            Type::Decimal => fmt.write_str("decimal"),
+            Type::Name { ref name } => {
+                match *name {
+                    Name::Relative { ref parts } => write!(fmt, "::{}", parts.join("::")),
+                    Name::Absolute {
+                        ref prefix,
+                        ref parts,
+                    } => {
+                        if let Some(ref prefix) = *prefix {
+                            write!(fmt, "{}::", Ident(prefix))?;
+                        }
+
+                        fmt.write_str(&parts.join("::"))
+                    }
+                }
+            }
+            Type::Array { ref inner } => write!(fmt, "[{}]", TypeSpec(inner)),
+            Type::Map { ref key, ref value } => {
+                write!(fmt, "{{{}: {}}}", TypeSpec(key), TypeSpec(value))
+            }
+        }
+    }
+}
@@ -48,13 +48,21 @@ pub enum Token<'input> {
     AnyKeyword,
     FloatKeyword,
     DoubleKeyword,
+    Signed8,
+    Signed16,
     Signed32,
     Signed64,
+    Unsigned8,
+    Unsigned16,
     Unsigned32,
     Unsigned64,
     BooleanKeyword,
     StringKeyword,
     DateTimeKeyword,
+    DateKeyword,
+    DurationKeyword,
+    UuidKeyword,
+    DecimalKeyword,
     BytesKeyword,
     TrueKeyword,
     FalseKeyword,
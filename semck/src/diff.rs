@@ -0,0 +1,330 @@
+//! Structured list of the differences between two versions of a file.
+//!
+//! Builds on the semantic version checks, by attributing every difference that a patch version
+//! would not permit to the declaration, field, variant, or endpoint that it belongs to.
+
+use super::{Bump, Lookup, Violation, classify, endpoints_to_map, enum_variants, fields};
+use super::Violation::*;
+use reproto_core::{ErrorPos, RpFile, RpReg};
+use reproto_core::errors::*;
+
+/// The kind of item which differs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Item {
+    Decl,
+    Field,
+    Variant,
+    Endpoint,
+}
+
+impl Item {
+    /// Describe the item.
+    pub fn describe(&self) -> &str {
+        match *self {
+            Item::Decl => "declaration",
+            Item::Field => "field",
+            Item::Variant => "variant",
+            Item::Endpoint => "endpoint",
+        }
+    }
+}
+
+/// How an item differs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    /// Describe the kind of change.
+    pub fn describe(&self) -> &str {
+        match *self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        }
+    }
+}
+
+/// A single item which differs between two versions of a file.
+#[derive(Debug)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub item: Item,
+    /// Name of the item, like `Toy` or `Toy.name`.
+    pub name: String,
+    /// The smallest version bump which permits the change.
+    pub bump: Bump,
+    /// The differences which make up the change.
+    pub violations: Vec<Violation>,
+}
+
+/// Items of a file, with their names and positions.
+struct Items(Vec<(Item, String, ErrorPos)>);
+
+impl Items {
+    fn new(file: &RpFile) -> Items {
+        let mut items = Vec::new();
+
+        for decl in &file.decls {
+            for reg in decl.into_reg() {
+                // variants are collected through their enum.
+                if let RpReg::EnumVariant(_, _) = reg {
+                    continue;
+                }
+
+                let name = reg.name().join("::");
+
+                for field in fields(&reg) {
+                    let field_name = format!("{}.{}", name, field.ident());
+                    items.push((Item::Field, field_name, field.pos().into()));
+                }
+
+                for variant in enum_variants(&reg) {
+                    let variant_name = format!("{}.{}", name, variant.local_name.value());
+                    items.push((Item::Variant, variant_name, variant.pos().into()));
+                }
+
+                for (id, endpoint) in endpoints_to_map(&reg) {
+                    let endpoint_name = format!("{}.{}", name, id);
+                    items.push((Item::Endpoint, endpoint_name, endpoint.pos().into()));
+                }
+
+                items.push((Item::Decl, name, reg.pos().into()));
+            }
+        }
+
+        Items(items)
+    }
+
+    /// Find the innermost item that contains the given position.
+    fn find(&self, pos: &ErrorPos) -> Option<(Item, &str)> {
+        self.0
+            .iter()
+            .filter(|&&(_, _, ref p)| p.start <= pos.start && pos.end <= p.end)
+            .min_by_key(|&&(_, _, ref p)| p.end - p.start)
+            .map(|&(item, ref name, _)| (item, name.as_str()))
+    }
+}
+
+/// The kind of change that a single violation represents.
+fn change_kind(violation: &Violation) -> ChangeKind {
+    match *violation {
+        DeclRemoved(..) | RemoveField(..) | RemoveVariant(..) | RemoveEndpoint(..) |
+        RemoveSubType(..) => ChangeKind::Removed,
+        DeclAdded(..) | AddField(..) | AddRequiredField(..) | AddVariant(..) | AddEndpoint(..) |
+        TupleFieldInserted(..) => ChangeKind::Added,
+        _ => ChangeKind::Changed,
+    }
+}
+
+/// Build the list of items which differ between two versions of a file.
+///
+/// Changes are ordered by the name of the item.
+pub fn diff(from: &RpFile, to: &RpFile, lookup: &Lookup) -> Result<Vec<Change>> {
+    let classified = classify(from, to, lookup)?;
+    changes(&Items::new(from), &Items::new(to), classified)
+}
+
+/// Group classified differences by the item that they belong to.
+fn changes(
+    from_items: &Items,
+    to_items: &Items,
+    classified: Vec<(Bump, Violation)>,
+) -> Result<Vec<Change>> {
+    let mut changes: Vec<Change> = Vec::new();
+
+    for (bump, violation) in classified {
+        let kind = change_kind(&violation);
+
+        // removed items are only present in the old version.
+        let found = {
+            let (items, pos) = match violation {
+                _ if kind == ChangeKind::Removed => (from_items, violation.pos()),
+                // attributed to the reference in the checked file.
+                Transitive(ref chain, _) if !chain.is_empty() => (to_items, &chain[0].1),
+                _ => (to_items, violation.pos()),
+            };
+
+            items.find(pos).map(|(item, name)| (item, name.to_string()))
+        };
+
+        let (item, name) = found.ok_or_else(|| {
+            format!("difference not part of any declaration: {:?}", violation)
+        })?;
+
+        if let Some(change) = changes.iter_mut().find(
+            |c| c.item == item && c.name == name,
+        )
+        {
+            // additions and removals take precedence over other changes to the same item.
+            if kind < change.kind {
+                change.kind = kind;
+            }
+
+            if bump > change.bump {
+                change.bump = bump;
+            }
+
+            change.violations.push(violation);
+            continue;
+        }
+
+        changes.push(Change {
+            kind: kind,
+            item: item,
+            name: name,
+            bump: bump,
+            violations: vec![violation],
+        });
+    }
+
+    changes.sort_by(|a, b| (&a.name, a.item).cmp(&(&b.name, b.item)));
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reproto_core::{BytesObject, RpType};
+    use std::sync::Arc;
+    use tests::{NoLookup, file};
+
+    fn pos(start: usize, end: usize) -> ErrorPos {
+        let object = BytesObject::new("test".to_string(), Arc::new(vec![]));
+
+        ErrorPos {
+            object: Box::new(object),
+            start: start,
+            end: end,
+        }
+    }
+
+    /// Diff two versions of a file, and return the kind, item, name, and bump of every change.
+    fn diff_files(from: &str, to: &str) -> Vec<(ChangeKind, Item, String, Bump)> {
+        let from = file("1.0.0", from);
+        let to = file("1.1.0", to);
+
+        diff(&from, &to, &NoLookup)
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.kind, c.item, c.name, c.bump))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_innermost() {
+        let items = Items(vec![
+            (Item::Field, "Foo.a".to_string(), pos(10, 20)),
+            (Item::Decl, "Foo".to_string(), pos(0, 50)),
+            (Item::Decl, "Bar".to_string(), pos(60, 80)),
+        ]);
+
+        assert_eq!(Some((Item::Field, "Foo.a")), items.find(&pos(12, 15)));
+        assert_eq!(Some((Item::Decl, "Foo")), items.find(&pos(5, 25)));
+        assert_eq!(Some((Item::Decl, "Bar")), items.find(&pos(60, 80)));
+        assert_eq!(None, items.find(&pos(45, 65)));
+    }
+
+    #[test]
+    fn test_change_kind() {
+        use super::super::Component::*;
+
+        assert_eq!(ChangeKind::Removed, change_kind(&RemoveField(Minor, pos(0, 1))));
+        assert_eq!(ChangeKind::Removed, change_kind(&DeclRemoved(Minor, pos(0, 1))));
+        assert_eq!(ChangeKind::Added, change_kind(&AddField(Patch, pos(0, 1))));
+        assert_eq!(ChangeKind::Added, change_kind(&AddRequiredField(Minor, pos(0, 1))));
+
+        assert_eq!(
+            ChangeKind::Changed,
+            change_kind(&FieldModifierChange(Patch, pos(0, 1), pos(0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_changes_merged() {
+        use super::super::Component::*;
+
+        let items = Items(vec![
+            (Item::Field, "Foo.a".to_string(), pos(10, 20)),
+            (Item::Decl, "Foo".to_string(), pos(0, 50)),
+        ]);
+
+        let classified = vec![
+            (Bump::Minor, FieldModifierChange(Patch, pos(10, 20), pos(10, 20))),
+            (
+                Bump::Major,
+                FieldTypeChange(Minor, RpType::String, pos(10, 20), RpType::Bytes, pos(10, 20)),
+            ),
+            (Bump::Minor, FieldDefaultChange(Patch, pos(10, 20), pos(12, 18))),
+        ];
+
+        let changes = changes(&items, &items, classified).unwrap();
+
+        assert_eq!(1, changes.len());
+
+        let change = &changes[0];
+        assert_eq!(ChangeKind::Changed, change.kind);
+        assert_eq!(Item::Field, change.item);
+        assert_eq!("Foo.a", change.name);
+        assert_eq!(Bump::Major, change.bump);
+        assert_eq!(3, change.violations.len());
+    }
+
+    #[test]
+    fn test_changes_outside_items() {
+        use super::super::Component::*;
+
+        let items = Items(vec![(Item::Decl, "Foo".to_string(), pos(0, 50))]);
+        let classified = vec![(Bump::Minor, AddField(Patch, pos(60, 70)))];
+        assert!(changes(&items, &items, classified).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let changes = diff_files(
+            "type Foo {
+                a: string;
+                b: string;
+            }",
+            "type Foo {
+                a: u32;
+                c?: string;
+            }",
+        );
+
+        assert_eq!(
+            vec![
+                (ChangeKind::Changed, Item::Field, "Foo.a".to_string(), Bump::Major),
+                // only present in the old version.
+                (ChangeKind::Removed, Item::Field, "Foo.b".to_string(), Bump::Major),
+                (ChangeKind::Added, Item::Field, "Foo.c".to_string(), Bump::Minor),
+            ],
+            changes
+        );
+    }
+
+    #[test]
+    fn test_diff_decls() {
+        let changes = diff_files(
+            "type Foo {}
+
+            type Bar {}",
+            "type Bar {}
+
+            enum Baz as string {
+                ONE;
+            }",
+        );
+
+        assert_eq!(
+            vec![
+                (ChangeKind::Added, Item::Decl, "Baz".to_string(), Bump::Minor),
+                (ChangeKind::Removed, Item::Decl, "Foo".to_string(), Bump::Major),
+            ],
+            changes
+        );
+    }
+}
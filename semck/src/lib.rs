@@ -1,11 +1,21 @@
 extern crate reproto_core;
+#[cfg(test)]
+extern crate reproto_backend;
+#[cfg(test)]
+extern crate reproto_repository;
 
+mod diff;
+
+pub use self::diff::{Change, ChangeKind, Item, diff};
 use self::Component::*;
 use self::Violation::*;
-use reproto_core::{ErrorPos, Loc, RpChannel, RpDecl, RpEndpoint, RpField, RpFile, RpName, RpReg,
-                   RpType, RpVariant, Version};
+use reproto_core::{ErrorPos, Loc, RpChannel, RpConstraints, RpDecl, RpEndpoint, RpEnumType,
+                   RpField, RpFile, RpName, RpOptionDecl, RpReg, RpSubType, RpType, RpTypeInfo,
+                   RpValue, RpVariant, Version};
 use reproto_core::errors::*;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -50,6 +60,10 @@ pub enum Violation {
     AddRequiredField(Component, ErrorPos),
     /// Field modifier changed.
     FieldModifierChange(Component, ErrorPos, ErrorPos),
+    /// A constraint of a field was changed, identified by its name.
+    FieldConstraintChange(Component, String, ErrorPos, ErrorPos),
+    /// The default value of a field was added, removed, or changed.
+    FieldDefaultChange(Component, ErrorPos, ErrorPos),
     /// Endpoint added.
     AddEndpoint(Component, ErrorPos),
     /// Endpoint removed.
@@ -58,6 +72,102 @@ pub enum Violation {
     EndpointRequestChange(Component, Option<RpChannel>, ErrorPos, Option<RpChannel>, ErrorPos),
     /// Endpoint response type changed.
     EndpointResponseChange(Component, Option<RpChannel>, ErrorPos, Option<RpChannel>, ErrorPos),
+    /// Endpoint option was added, removed, or had its value changed.
+    EndpointOptionChange(Component, String, ErrorPos, ErrorPos),
+    /// Sub-type was removed from an interface.
+    RemoveSubType(Component, ErrorPos),
+    /// The names that a sub-type is identified by were changed.
+    SubTypeNamesChange(Component, Vec<String>, ErrorPos, Vec<String>, ErrorPos),
+    /// The way an interface encodes sub-type information was changed.
+    TypeInfoChange(Component, RpTypeInfo, ErrorPos, RpTypeInfo, ErrorPos),
+    /// The type of the values of an enum was changed.
+    EnumTypeChange(Component, RpEnumType, ErrorPos, RpEnumType, ErrorPos),
+    /// Tuple field was moved to another position.
+    TupleFieldMoved(Component, String, usize, ErrorPos, usize, ErrorPos),
+    /// Tuple field was inserted before existing fields.
+    TupleFieldInserted(Component, ErrorPos),
+    /// The number of fields in a tuple was changed.
+    TupleArityChange(Component, usize, ErrorPos, usize, ErrorPos),
+    /// Violation in a declaration of another package.
+    ///
+    /// Carries the chain of references which led to the declaration, starting in the checked
+    /// file, as the referenced name and the position of the reference.
+    Transitive(Vec<(RpName, ErrorPos)>, Box<Violation>),
+}
+
+impl Violation {
+    /// Get the position that the violation is primarily reported at.
+    ///
+    /// For changes, this is the position in the new version.
+    pub fn pos(&self) -> &ErrorPos {
+        match *self {
+            DeclRemoved(_, ref pos) |
+            DeclAdded(_, ref pos) |
+            RemoveField(_, ref pos) |
+            RemoveVariant(_, ref pos) |
+            AddField(_, ref pos) |
+            AddVariant(_, ref pos) |
+            AddRequiredField(_, ref pos) |
+            AddEndpoint(_, ref pos) |
+            RemoveEndpoint(_, ref pos) |
+            RemoveSubType(_, ref pos) |
+            TupleFieldInserted(_, ref pos) => pos,
+            FieldTypeChange(_, _, _, _, ref pos) |
+            FieldNameChange(_, _, _, _, ref pos) |
+            VariantOrdinalChange(_, _, _, _, ref pos) |
+            FieldRequiredChange(_, _, ref pos) |
+            FieldModifierChange(_, _, ref pos) |
+            FieldConstraintChange(_, _, _, ref pos) |
+            FieldDefaultChange(_, _, ref pos) |
+            EndpointRequestChange(_, _, _, _, ref pos) |
+            EndpointResponseChange(_, _, _, _, ref pos) |
+            EndpointOptionChange(_, _, _, ref pos) |
+            SubTypeNamesChange(_, _, _, _, ref pos) |
+            TypeInfoChange(_, _, _, _, ref pos) |
+            EnumTypeChange(_, _, _, _, ref pos) |
+            TupleFieldMoved(_, _, _, _, _, ref pos) |
+            TupleArityChange(_, _, _, _, ref pos) => pos,
+            Transitive(_, ref violation) => violation.pos(),
+        }
+    }
+}
+
+/// Looks up declarations in other packages which are referenced from the checked files.
+pub trait Lookup {
+    /// Lookup the declaration with the given name, if it is available.
+    fn lookup(&self, name: &RpName) -> Option<&RpReg>;
+}
+
+/// The smallest version bump which permits a set of changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Bump {
+    /// Describe the bump.
+    pub fn describe(&self) -> &str {
+        match *self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+
+    /// Apply the bump to the given version.
+    pub fn apply(&self, version: &Version) -> Version {
+        let mut version = version.clone();
+
+        match *self {
+            Bump::Patch => version.increment_patch(),
+            Bump::Minor => version.increment_minor(),
+            Bump::Major => version.increment_major(),
+        }
+
+        version
+    }
 }
 
 fn fields(reg: &RpReg) -> Vec<&Loc<RpField>> {
@@ -96,6 +206,17 @@ fn endpoints_to_map(reg: &RpReg) -> HashMap<&str, &Loc<RpEndpoint>> {
     }
 }
 
+/// Names that a sub-type is identified by on the wire.
+///
+/// The first name is used when encoding, all of them are accepted when decoding.
+fn sub_type_names(sub_type: &RpSubType) -> Vec<String> {
+    if sub_type.names.is_empty() {
+        return vec![sub_type.local_name.clone()];
+    }
+
+    sub_type.names.iter().map(|n| n.value().clone()).collect()
+}
+
 fn decls_to_map<'a, I: 'a>(decls: I) -> HashMap<RpName, RpReg>
 where
     I: IntoIterator<Item = &'a Rc<Loc<RpDecl>>>,
@@ -161,11 +282,11 @@ where
        -> Violation,
 {
     let from_ty = accessor(from_endpoint).as_ref().map(|r| {
-        (r.is_streaming(), r.ty().clone().localize())
+        (r.is_streaming(), r.ty().clone().without_version())
     });
 
     let to_ty = accessor(to_endpoint).as_ref().map(|r| {
-        (r.is_streaming(), r.ty().clone().localize())
+        (r.is_streaming(), r.ty().clone().without_version())
     });
 
     if from_ty != to_ty {
@@ -222,6 +343,75 @@ fn check_endpoint_type(
     Ok(())
 }
 
+fn options_to_map<'a, I: 'a>(options: I) -> HashMap<&'a str, Vec<&'a Loc<RpOptionDecl>>>
+where
+    I: IntoIterator<Item = &'a Loc<RpOptionDecl>>,
+{
+    let mut storage: HashMap<&str, Vec<&Loc<RpOptionDecl>>> = HashMap::new();
+
+    for option in options {
+        storage
+            .entry(option.name.as_str())
+            .or_insert_with(Vec::new)
+            .push(option);
+    }
+
+    storage
+}
+
+/// Perform checks on the options of an endpoint.
+///
+/// Options carry protocol metadata (like `http_status`), so any change to them is a violation.
+fn check_endpoint_options(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_endpoint: &Loc<RpEndpoint>,
+    to_endpoint: &Loc<RpEndpoint>,
+) -> Result<()> {
+    let from_options = options_to_map(&from_endpoint.options);
+    let mut to_options = options_to_map(&to_endpoint.options);
+
+    for (name, from_values) in from_options.into_iter() {
+        let to_values = to_options.remove(name).unwrap_or_else(Vec::new);
+
+        let from_pos = from_values
+            .first()
+            .map(|o| o.pos())
+            .unwrap_or(from_endpoint.pos());
+
+        let to_pos = to_values.first().map(|o| o.pos()).unwrap_or(
+            to_endpoint.pos(),
+        );
+
+        let from_values: Vec<&RpValue> = from_values.iter().map(|o| o.value.value()).collect();
+        let to_values: Vec<&RpValue> = to_values.iter().map(|o| o.value.value()).collect();
+
+        if from_values != to_values {
+            violations.push(EndpointOptionChange(
+                component.clone(),
+                name.to_string(),
+                from_pos.into(),
+                to_pos.into(),
+            ));
+        }
+    }
+
+    for (name, to_values) in to_options.into_iter() {
+        let to_pos = to_values.first().map(|o| o.pos()).unwrap_or(
+            to_endpoint.pos(),
+        );
+
+        violations.push(EndpointOptionChange(
+            component.clone(),
+            name.to_string(),
+            from_endpoint.pos().into(),
+            to_pos.into(),
+        ));
+    }
+
+    Ok(())
+}
+
 fn common_check_variant(
     component: Component,
     violations: &mut Vec<Violation>,
@@ -241,13 +431,290 @@ fn common_check_variant(
     Ok(())
 }
 
+/// Perform checks on the way an interface encodes its sub-types.
+fn check_type_info(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_reg: &RpReg,
+    to_reg: &RpReg,
+) -> Result<()> {
+    if let (&RpReg::Interface(ref from), &RpReg::Interface(ref to)) = (from_reg, to_reg) {
+        if from.type_info != to.type_info {
+            violations.push(TypeInfoChange(
+                component,
+                from.type_info.clone(),
+                from.pos().into(),
+                to.type_info.clone(),
+                to.pos().into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the values of an enum are of the same type.
+///
+/// Enums with generated values are encoded as strings, just like explicit string values.
+fn check_enum_type(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_reg: &RpReg,
+    to_reg: &RpReg,
+) -> Result<()> {
+    if let (&RpReg::Enum(ref from), &RpReg::Enum(ref to)) = (from_reg, to_reg) {
+        if from.variant_type.as_type() != to.variant_type.as_type() {
+            violations.push(EnumTypeChange(
+                component,
+                from.variant_type.clone(),
+                from.pos().into(),
+                to.variant_type.clone(),
+                to.pos().into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Perform positional checks on tuple fields.
+///
+/// Tuples are encoded as arrays, so fields must keep their position and can only be added at the
+/// end.
+fn common_check_tuple(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_reg: &RpReg,
+    to_reg: &RpReg,
+) -> Result<()> {
+    let (from, to) = match (from_reg, to_reg) {
+        (&RpReg::Tuple(ref from), &RpReg::Tuple(ref to)) => (from, to),
+        _ => return Ok(()),
+    };
+
+    let position = |fields: &[Loc<RpField>], name: &str| {
+        fields.iter().position(|f| f.ident() == name)
+    };
+
+    // fields present in both versions. Positions which are only shifted by inserted or removed
+    // fields are reported as such, so a field has only moved if the order of these changed.
+    let from_common: Vec<(usize, &Loc<RpField>)> = from.fields
+        .iter()
+        .enumerate()
+        .filter(|&(_, f)| position(&to.fields, f.ident()).is_some())
+        .collect();
+
+    let to_common: Vec<&str> = to.fields
+        .iter()
+        .filter(|f| position(&from.fields, f.ident()).is_some())
+        .map(|f| f.ident())
+        .collect();
+
+    for (&(from_index, from_field), to_name) in from_common.iter().zip(to_common) {
+        if from_field.ident() == to_name {
+            continue;
+        }
+
+        if let Some(to_index) = position(&to.fields, from_field.ident()) {
+            violations.push(TupleFieldMoved(
+                component.clone(),
+                from_field.ident().to_string(),
+                from_index,
+                from_field.pos().into(),
+                to_index,
+                to.fields[to_index].pos().into(),
+            ));
+        }
+    }
+
+    // index of the last field which is present in both versions.
+    let last_common = to.fields.iter().rposition(|f| {
+        position(&from.fields, f.ident()).is_some()
+    });
+
+    if let Some(last_common) = last_common {
+        for to_field in &to.fields[..last_common] {
+            if position(&from.fields, to_field.ident()).is_none() {
+                violations.push(TupleFieldInserted(component.clone(), to_field.pos().into()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Collect names in other packages which are referenced in the same place by both types, but
+/// through different versions of the package.
+fn type_references(out: &mut Vec<(RpName, RpName)>, from: &RpType, to: &RpType) {
+    use self::RpType::*;
+
+    match (from, to) {
+        (&Name { name: ref from }, &Name { name: ref to }) => {
+            if from.prefix.is_some() && from.package.version != to.package.version &&
+                from.clone().without_version() == to.clone().without_version()
+            {
+                out.push((from.clone(), to.clone()));
+            }
+        }
+        (&Array { inner: ref from }, &Array { inner: ref to }) => {
+            type_references(out, from, to);
+        }
+        (&Map {
+             key: ref from_key,
+             value: ref from_value,
+         },
+         &Map {
+             key: ref to_key,
+             value: ref to_value,
+         }) => {
+            type_references(out, from_key, to_key);
+            type_references(out, from_value, to_value);
+        }
+        _ => {}
+    }
+}
+
+/// Find references to other packages from the fields and endpoints present in both declarations.
+fn references(from_reg: &RpReg, to_reg: &RpReg) -> Vec<(ErrorPos, RpName, RpName)> {
+    let mut out: Vec<(ErrorPos, RpName, RpName)> = Vec::new();
+    let mut names = Vec::new();
+
+    let mut to_fields = fields_to_map(fields(to_reg));
+
+    for from_field in fields(from_reg) {
+        if let Some(to_field) = to_fields.remove(from_field.ident()) {
+            type_references(&mut names, &from_field.ty, &to_field.ty);
+            out.extend(names.drain(..).map(|(f, t)| (to_field.pos().into(), f, t)));
+        }
+    }
+
+    let mut to_endpoints = endpoints_to_map(to_reg);
+
+    for (name, from_endpoint) in endpoints_to_map(from_reg) {
+        if let Some(to_endpoint) = to_endpoints.remove(name) {
+            let channels = [
+                (&from_endpoint.request, &to_endpoint.request),
+                (&from_endpoint.response, &to_endpoint.response),
+            ];
+
+            for &(from, to) in &channels {
+                if let (&Some(ref from), &Some(ref to)) = (from, to) {
+                    type_references(&mut names, from.ty(), to.ty());
+                    out.extend(names.drain(..).map(|(f, t)| (to.pos().into(), f, t)));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn clone_chain(chain: &[(RpName, ErrorPos)]) -> Vec<(RpName, ErrorPos)> {
+    chain
+        .iter()
+        .map(|&(ref name, ref pos)| (name.clone(), pos.clone_error_pos()))
+        .collect()
+}
+
+/// Perform checks on declarations in other packages, which are referenced from declarations
+/// present in both versions, through different versions of the other package.
+///
+/// The referenced declarations are checked with `check_reg`, and their own references are
+/// followed.
+fn check_references<F>(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    pairs: &[(RpReg, RpReg)],
+    lookup: &Lookup,
+    check_reg: F,
+) -> Result<()>
+where
+    F: Fn(&mut Vec<Violation>, &RpReg, &RpReg) -> Result<()>,
+{
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    for &(ref from_reg, ref to_reg) in pairs {
+        for (pos, from, to) in references(from_reg, to_reg) {
+            queue.push_back((vec![(to.clone(), pos)], from, to));
+        }
+    }
+
+    while let Some((chain, from_name, to_name)) = queue.pop_front() {
+        if !visited.insert((from_name.clone(), to_name.clone())) {
+            continue;
+        }
+
+        let (from_reg, to_reg) = match (lookup.lookup(&from_name), lookup.lookup(&to_name)) {
+            (Some(from_reg), Some(to_reg)) => (from_reg, to_reg),
+            (Some(from_reg), None) => {
+                let violation = decl_removed(component.clone(), from_reg);
+                violations.push(Transitive(chain, Box::new(violation)));
+                continue;
+            }
+            _ => continue,
+        };
+
+        let mut found = Vec::new();
+        let mut regs = vec![(from_reg.clone(), to_reg.clone())];
+
+        // sub-types are not referenced by name, so they are checked with their interface.
+        if let (&RpReg::Interface(ref from), &RpReg::Interface(ref to)) = (from_reg, to_reg) {
+            for (key, from_sub_type) in &from.sub_types {
+                if let Some(to_sub_type) = to.sub_types.get(key) {
+                    regs.push((
+                        RpReg::SubType(from.clone(), from_sub_type.clone()),
+                        RpReg::SubType(to.clone(), to_sub_type.clone()),
+                    ));
+                } else {
+                    found.push(RemoveSubType(component.clone(), from_sub_type.pos().into()));
+                }
+            }
+        }
+
+        for &(ref from_reg, ref to_reg) in &regs {
+            check_reg(&mut found, from_reg, to_reg)?;
+
+            for (pos, from, to) in references(from_reg, to_reg) {
+                let mut chain = clone_chain(&chain);
+                chain.push((to.clone(), pos));
+                queue.push_back((chain, from, to));
+            }
+        }
+
+        violations.extend(found.into_iter().map(
+            |v| Transitive(clone_chain(&chain), Box::new(v)),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build the violation for a declaration which has been removed.
+fn decl_removed(component: Component, reg: &RpReg) -> Violation {
+    match *reg {
+        RpReg::SubType(_, ref sub_type) => RemoveSubType(component, sub_type.pos().into()),
+        _ => DeclRemoved(component, reg.pos().into()),
+    }
+}
+
 fn common_check_field(
     component: Component,
     violations: &mut Vec<Violation>,
     from_field: &Loc<RpField>,
     to_field: &Loc<RpField>,
 ) -> Result<()> {
-    if to_field.ty.clone().localize() != from_field.ty.clone().localize() {
+    // changes to the versions of referenced packages are checked through their declarations.
+    let from_ty = from_field.ty.clone().without_version();
+    let to_ty = to_field.ty.clone().without_version();
+
+    // a minor version may widen the type of a field, like from `i32` to `i64`.
+    let widened = match component {
+        Minor => to_ty.is_widening_of(&from_ty),
+        Patch => false,
+    };
+
+    if to_ty != from_ty && !widened {
         violations.push(FieldTypeChange(
             component.clone(),
             from_field.ty.clone(),
@@ -268,63 +735,89 @@ fn common_check_field(
         ));
     }
 
+    // defaults change how existing messages are decoded.
+    if to_field.default.as_ref().map(Loc::value) != from_field.default.as_ref().map(Loc::value) {
+        violations.push(FieldDefaultChange(
+            component.clone(),
+            from_field.pos().into(),
+            to_field.pos().into(),
+        ));
+    }
+
     Ok(())
 }
 
 /// Performs checks for minor version violations.
-fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
+fn check_minor(from: &RpFile, to: &RpFile, lookup: &Lookup) -> Result<Vec<Violation>> {
     let mut violations = Vec::new();
+    let mut pairs = Vec::new();
 
     let from_storage = decls_to_map(&from.decls);
     let mut to_storage = decls_to_map(&to.decls);
 
     for (name, from_reg) in from_storage {
         if let Some(to_reg) = to_storage.remove(&name) {
-            let from_fields = fields_to_map(fields(&from_reg));
-            let mut to_fields = fields_to_map(fields(&to_reg));
+            check_reg(&mut violations, &from_reg, &to_reg)?;
+            pairs.push((from_reg, to_reg));
+        } else {
+            violations.push(decl_removed(Minor, &from_reg));
+        }
+    }
 
-            for (name, from_field) in from_fields.into_iter() {
-                if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
-                } else {
-                    violations.push(RemoveField(Minor, from_field.pos().into()));
-                }
+    check_references(Minor, &mut violations, &pairs, lookup, check_reg)?;
+    return Ok(violations);
+
+    fn check_reg(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        let from_fields = fields_to_map(fields(from_reg));
+        let mut to_fields = fields_to_map(fields(to_reg));
+
+        for (name, from_field) in from_fields.into_iter() {
+            if let Some(to_field) = to_fields.remove(&name) {
+                check_field(violations, from_field, to_field)?;
+            } else {
+                violations.push(RemoveField(Minor, from_field.pos().into()));
             }
+        }
 
-            // check that added fields are not required.
-            for (_, to_field) in to_fields.into_iter() {
-                if to_field.is_required() {
-                    violations.push(AddRequiredField(Minor, to_field.pos().into()));
-                }
+        // check that added fields are not required.
+        for (_, to_field) in to_fields.into_iter() {
+            if to_field.is_required() {
+                violations.push(AddRequiredField(Minor, to_field.pos().into()));
             }
+        }
 
-            let from_variants = variants_to_map(enum_variants(&from_reg));
-            let mut to_variants = variants_to_map(enum_variants(&to_reg));
+        let from_variants = variants_to_map(enum_variants(from_reg));
+        let mut to_variants = variants_to_map(enum_variants(to_reg));
 
-            for (name, from_variant) in from_variants.into_iter() {
-                if let Some(to_variant) = to_variants.remove(&name) {
-                    check_variant(&mut violations, from_variant, to_variant)?;
-                } else {
-                    violations.push(RemoveVariant(Minor, from_variant.pos().into()));
-                }
+        for (name, from_variant) in from_variants.into_iter() {
+            if let Some(to_variant) = to_variants.remove(&name) {
+                check_variant(violations, from_variant, to_variant)?;
+            } else {
+                violations.push(RemoveVariant(Minor, from_variant.pos().into()));
             }
+        }
 
-            let from_endpoints = endpoints_to_map(&from_reg);
-            let mut to_endpoints = endpoints_to_map(&to_reg);
+        let from_endpoints = endpoints_to_map(from_reg);
+        let mut to_endpoints = endpoints_to_map(to_reg);
 
-            for (name, from_endpoint) in from_endpoints.into_iter() {
-                if let Some(to_endpoint) = to_endpoints.remove(&name) {
-                    check_endpoint(&mut violations, from_endpoint, to_endpoint)?;
-                } else {
-                    violations.push(RemoveEndpoint(Minor, from_endpoint.pos().into()));
-                }
+        for (name, from_endpoint) in from_endpoints.into_iter() {
+            if let Some(to_endpoint) = to_endpoints.remove(&name) {
+                check_endpoint(violations, from_endpoint, to_endpoint)?;
+            } else {
+                violations.push(RemoveEndpoint(Minor, from_endpoint.pos().into()));
             }
-        } else {
-            violations.push(DeclRemoved(Minor, from_reg.pos().into()));
         }
-    }
 
-    return Ok(violations);
+        check_type_info(Minor, violations, from_reg, to_reg)?;
+        check_enum_type(Minor, violations, from_reg, to_reg)?;
+        check_sub_type(violations, from_reg, to_reg)?;
+        check_tuple(violations, from_reg, to_reg)?;
+        Ok(())
+    }
 
     fn check_field(
         violations: &mut Vec<Violation>,
@@ -342,9 +835,72 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
             ));
         }
 
+        // Minor patch may relax constraints, but not tighten them.
+        for name in tightened_constraints(&from_field.constraints, &to_field.constraints) {
+            violations.push(FieldConstraintChange(
+                Minor,
+                name.to_string(),
+                from_field.pos().into(),
+                to_field.pos().into(),
+            ));
+        }
+
         Ok(())
     }
 
+    /// Names of the constraints which reject values that were previously accepted.
+    fn tightened_constraints(from: &RpConstraints, to: &RpConstraints) -> Vec<&'static str> {
+        let mut out = Vec::new();
+
+        if let Some(ref to_min) = to.min {
+            if from.min.as_ref().map(|f| to_min.cmp_value(f) == Ordering::Greater).unwrap_or(true) {
+                out.push("min");
+            }
+        }
+
+        if let Some(ref to_max) = to.max {
+            if from.max.as_ref().map(|f| to_max.cmp_value(f) == Ordering::Less).unwrap_or(true) {
+                out.push("max");
+            }
+        }
+
+        let lower = vec![
+            ("min_length", from.min_length, to.min_length),
+            ("min_items", from.min_items, to.min_items),
+        ];
+
+        for (name, from, to) in lower {
+            if let Some(to) = to {
+                if from.map(|f| to > f).unwrap_or(true) {
+                    out.push(name);
+                }
+            }
+        }
+
+        let upper = vec![
+            ("max_length", from.max_length, to.max_length),
+            ("max_items", from.max_items, to.max_items),
+        ];
+
+        for (name, from, to) in upper {
+            if let Some(to) = to {
+                if from.map(|f| to < f).unwrap_or(true) {
+                    out.push(name);
+                }
+            }
+        }
+
+        if to.pattern.is_some() && to.pattern != from.pattern {
+            out.push("pattern");
+        }
+
+        if to.non_empty && !from.non_empty {
+            out.push("non_empty");
+        }
+
+        out
+    }
+
     fn check_variant(
         violations: &mut Vec<Violation>,
         from_variant: &Loc<RpVariant>,
@@ -360,75 +916,144 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
         to_endpoint: &Loc<RpEndpoint>,
     ) -> Result<()> {
         check_endpoint_type(Minor, violations, from_endpoint, to_endpoint)?;
+        check_endpoint_options(Minor, violations, from_endpoint, to_endpoint)?;
+        Ok(())
+    }
+
+    fn check_sub_type(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        if let (&RpReg::SubType(_, ref from), &RpReg::SubType(_, ref to)) = (from_reg, to_reg) {
+            let from_names = sub_type_names(from);
+            let to_names = sub_type_names(to);
+
+            // Minor patch may add names, but the name used when encoding must stay the same and
+            // existing names must still be accepted.
+            let first_changed = from_names.first() != to_names.first();
+            let removed = from_names.iter().any(|n| !to_names.contains(n));
+
+            if first_changed || removed {
+                violations.push(SubTypeNamesChange(
+                    Minor,
+                    from_names,
+                    from.pos().into(),
+                    to_names,
+                    to.pos().into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_tuple(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        common_check_tuple(Minor, violations, from_reg, to_reg)?;
+
+        // Minor patch may add fields at the end, but not remove them.
+        if let (&RpReg::Tuple(ref from), &RpReg::Tuple(ref to)) = (from_reg, to_reg) {
+            if to.fields.len() < from.fields.len() {
+                violations.push(TupleArityChange(
+                    Minor,
+                    from.fields.len(),
+                    from.pos().into(),
+                    to.fields.len(),
+                    to.pos().into(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
 
-fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
+fn check_patch(from: &RpFile, to: &RpFile, lookup: &Lookup) -> Result<Vec<Violation>> {
     let mut violations = Vec::new();
+    let mut pairs = Vec::new();
 
     let from_storage = decls_to_map(&from.decls);
     let mut to_storage = decls_to_map(&to.decls);
 
     for (name, from_reg) in from_storage {
         if let Some(to_reg) = to_storage.remove(&name) {
-            let from_fields = fields_to_map(fields(&from_reg));
-            let mut to_fields = fields_to_map(fields(&to_reg));
+            check_reg(&mut violations, &from_reg, &to_reg)?;
+            pairs.push((from_reg, to_reg));
+        } else {
+            violations.push(decl_removed(Patch, &from_reg));
+        }
+    }
 
-            for (name, from_field) in from_fields.into_iter() {
-                if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
-                } else {
-                    violations.push(RemoveField(Patch, from_field.pos().into()));
-                }
-            }
+    for (_, to_reg) in to_storage.into_iter() {
+        violations.push(DeclAdded(Patch, to_reg.pos().into()));
+    }
 
-            // added fields are not permitted
-            for (_, to_field) in to_fields.into_iter() {
-                violations.push(AddField(Patch, to_field.pos().into()));
+    check_references(Patch, &mut violations, &pairs, lookup, check_reg)?;
+    return Ok(violations);
+
+    fn check_reg(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        let from_fields = fields_to_map(fields(from_reg));
+        let mut to_fields = fields_to_map(fields(to_reg));
+
+        for (name, from_field) in from_fields.into_iter() {
+            if let Some(to_field) = to_fields.remove(&name) {
+                check_field(violations, from_field, to_field)?;
+            } else {
+                violations.push(RemoveField(Patch, from_field.pos().into()));
             }
+        }
 
-            let from_variants = variants_to_map(enum_variants(&from_reg));
-            let mut to_variants = variants_to_map(enum_variants(&to_reg));
+        // added fields are not permitted
+        for (_, to_field) in to_fields.into_iter() {
+            violations.push(AddField(Patch, to_field.pos().into()));
+        }
 
-            for (name, from_variant) in from_variants.into_iter() {
-                if let Some(to_variant) = to_variants.remove(&name) {
-                    check_variant(&mut violations, from_variant, to_variant)?;
-                } else {
-                    violations.push(RemoveVariant(Patch, from_variant.pos().into()));
-                }
-            }
+        let from_variants = variants_to_map(enum_variants(from_reg));
+        let mut to_variants = variants_to_map(enum_variants(to_reg));
 
-            // added variants are not permitted
-            for (_, to_variant) in to_variants.into_iter() {
-                violations.push(AddVariant(Patch, to_variant.pos().into()));
+        for (name, from_variant) in from_variants.into_iter() {
+            if let Some(to_variant) = to_variants.remove(&name) {
+                check_variant(violations, from_variant, to_variant)?;
+            } else {
+                violations.push(RemoveVariant(Patch, from_variant.pos().into()));
             }
+        }
 
-            let from_endpoints = endpoints_to_map(&from_reg);
-            let mut to_endpoints = endpoints_to_map(&to_reg);
+        // added variants are not permitted
+        for (_, to_variant) in to_variants.into_iter() {
+            violations.push(AddVariant(Patch, to_variant.pos().into()));
+        }
 
-            for (name, from_endpoint) in from_endpoints.into_iter() {
-                if let Some(to_endpoint) = to_endpoints.remove(&name) {
-                    check_endpoint(&mut violations, from_endpoint, to_endpoint)?;
-                } else {
-                    violations.push(RemoveEndpoint(Patch, from_endpoint.pos().into()));
-                }
-            }
+        let from_endpoints = endpoints_to_map(from_reg);
+        let mut to_endpoints = endpoints_to_map(to_reg);
 
-            // added endpoints are not permitted
-            for (_, to_endpoint) in to_endpoints.into_iter() {
-                violations.push(AddEndpoint(Patch, to_endpoint.pos().into()));
+        for (name, from_endpoint) in from_endpoints.into_iter() {
+            if let Some(to_endpoint) = to_endpoints.remove(&name) {
+                check_endpoint(violations, from_endpoint, to_endpoint)?;
+            } else {
+                violations.push(RemoveEndpoint(Patch, from_endpoint.pos().into()));
             }
-        } else {
-            violations.push(DeclRemoved(Patch, from_reg.pos().into()));
         }
-    }
 
-    for (_, to_reg) in to_storage.into_iter() {
-        violations.push(DeclAdded(Patch, to_reg.pos().into()));
-    }
+        // added endpoints are not permitted
+        for (_, to_endpoint) in to_endpoints.into_iter() {
+            violations.push(AddEndpoint(Patch, to_endpoint.pos().into()));
+        }
 
-    return Ok(violations);
+        check_type_info(Patch, violations, from_reg, to_reg)?;
+        check_enum_type(Patch, violations, from_reg, to_reg)?;
+        check_sub_type(violations, from_reg, to_reg)?;
+        check_tuple(violations, from_reg, to_reg)?;
+        Ok(())
+    }
 
     fn check_field(
         violations: &mut Vec<Violation>,
@@ -445,9 +1070,57 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
             ));
         }
 
+        for name in changed_constraints(&from_field.constraints, &to_field.constraints) {
+            violations.push(FieldConstraintChange(
+                Patch,
+                name.to_string(),
+                from_field.pos().into(),
+                to_field.pos().into(),
+            ));
+        }
+
         Ok(())
     }
 
+    /// Names of the constraints which differ in any way.
+    fn changed_constraints(from: &RpConstraints, to: &RpConstraints) -> Vec<&'static str> {
+        let mut out = Vec::new();
+
+        if from.min != to.min {
+            out.push("min");
+        }
+
+        if from.max != to.max {
+            out.push("max");
+        }
+
+        if from.min_length != to.min_length {
+            out.push("min_length");
+        }
+
+        if from.max_length != to.max_length {
+            out.push("max_length");
+        }
+
+        if from.pattern != to.pattern {
+            out.push("pattern");
+        }
+
+        if from.min_items != to.min_items {
+            out.push("min_items");
+        }
+
+        if from.max_items != to.max_items {
+            out.push("max_items");
+        }
+
+        if from.non_empty != to.non_empty {
+            out.push("non_empty");
+        }
+
+        out
+    }
+
     fn check_variant(
         violations: &mut Vec<Violation>,
         from_variant: &Loc<RpVariant>,
@@ -463,23 +1136,507 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
         to_endpoint: &Loc<RpEndpoint>,
     ) -> Result<()> {
         check_endpoint_type(Patch, violations, from_endpoint, to_endpoint)?;
+        check_endpoint_options(Patch, violations, from_endpoint, to_endpoint)?;
         Ok(())
     }
+
+    fn check_sub_type(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        if let (&RpReg::SubType(_, ref from), &RpReg::SubType(_, ref to)) = (from_reg, to_reg) {
+            let from_names = sub_type_names(from);
+            let to_names = sub_type_names(to);
+
+            // no changes to names are permitted
+            if from_names != to_names {
+                violations.push(SubTypeNamesChange(
+                    Patch,
+                    from_names,
+                    from.pos().into(),
+                    to_names,
+                    to.pos().into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_tuple(
+        violations: &mut Vec<Violation>,
+        from_reg: &RpReg,
+        to_reg: &RpReg,
+    ) -> Result<()> {
+        common_check_tuple(Patch, violations, from_reg, to_reg)?;
+
+        if let (&RpReg::Tuple(ref from), &RpReg::Tuple(ref to)) = (from_reg, to_reg) {
+            if to.fields.len() != from.fields.len() {
+                violations.push(TupleArityChange(
+                    Patch,
+                    from.fields.len(),
+                    from.pos().into(),
+                    to.fields.len(),
+                    to.pos().into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Classify every difference between two versions of a file by the bump it requires.
+///
+/// Differences which violate a minor version require a major bump, and the remaining differences
+/// which violate a patch version (like additions) require a minor bump.
+pub fn classify(from: &RpFile, to: &RpFile, lookup: &Lookup) -> Result<Vec<(Bump, Violation)>> {
+    let mut out: Vec<(Bump, Violation)> = check_minor(from, to, lookup)?
+        .into_iter()
+        .map(|v| (Bump::Major, v))
+        .collect();
+
+    for violation in check_patch(from, to, lookup)? {
+        // already classified as a major change.
+        if out.iter().any(|&(_, ref v)| {
+            same_kind(v, &violation) && same_pos(v.pos(), violation.pos())
+        })
+        {
+            continue;
+        }
+
+        out.push((Bump::Minor, violation));
+    }
+
+    Ok(out)
+}
+
+/// Suggest the smallest version which follows the given version, and permits all changes.
+pub fn suggest(from: (&Version, &RpFile), to: &RpFile, lookup: &Lookup) -> Result<Version> {
+    let (from_version, from_file) = from;
+
+    let bump = classify(from_file, to, lookup)?
+        .into_iter()
+        .map(|(bump, _)| bump)
+        .max()
+        .unwrap_or(Bump::Patch);
+
+    Ok(bump.apply(from_version))
+}
+
+/// Check if two violations are the same kind of change, regardless of the component that they
+/// were found for.
+fn same_kind(a: &Violation, b: &Violation) -> bool {
+    match (a, b) {
+        (&FieldConstraintChange(_, ref a, _, _), &FieldConstraintChange(_, ref b, _, _)) => a == b,
+        (&EndpointOptionChange(_, ref a, _, _), &EndpointOptionChange(_, ref b, _, _)) => a == b,
+        (&Transitive(_, ref a), &Transitive(_, ref b)) => same_kind(a, b),
+        // the same change, as reported by the minor and the patch checks.
+        (&AddRequiredField(..), &AddField(..)) |
+        (&AddField(..), &AddRequiredField(..)) |
+        (&FieldRequiredChange(..), &FieldModifierChange(..)) |
+        (&FieldModifierChange(..), &FieldRequiredChange(..)) => true,
+        _ => mem::discriminant(a) == mem::discriminant(b),
+    }
+}
+
+fn same_pos(a: &ErrorPos, b: &ErrorPos) -> bool {
+    a.start == b.start && a.end == b.end && a.object.to_string() == b.object.to_string()
 }
 
-pub fn check(from: (&Version, &RpFile), to: (&Version, &RpFile)) -> Result<Vec<Violation>> {
+/// Check that the changes between two versions of a file are permitted by semantic versioning.
+///
+/// Declarations in other packages are resolved through `lookup`.
+pub fn check(
+    from: (&Version, &RpFile),
+    to: (&Version, &RpFile),
+    lookup: &Lookup,
+) -> Result<Vec<Violation>> {
     let (from_version, from_file) = from;
     let (to_version, to_file) = to;
 
     if from_version.major == to_version.major {
         if from_version.minor < to_version.minor {
-            return check_minor(from_file, to_file);
+            return check_minor(from_file, to_file, lookup);
         }
 
         if from_version.patch < to_version.patch {
-            return check_patch(from_file, to_file);
+            return check_patch(from_file, to_file, lookup);
         }
     }
 
     Ok(vec![])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reproto_backend::Environment;
+    use reproto_core::{BytesObject, Object, RpPackage, RpRequiredPackage, RpVersionedPackage};
+    use reproto_repository::{self as repository, Resolved, ResolvedByPrefix, Resolver, Resolvers};
+    use std::sync::Arc;
+
+    pub struct NoLookup;
+
+    impl Lookup for NoLookup {
+        fn lookup(&self, _: &RpName) -> Option<&RpReg> {
+            None
+        }
+    }
+
+    /// Load a single file as the given version of the `test` package.
+    pub fn file(version: &str, input: &str) -> RpFile {
+        let mut env = Environment::new(None, Box::new(Resolvers::new(vec![])));
+
+        let version = Version::parse(version).unwrap();
+        let package = RpVersionedPackage::new(RpPackage::parse("test"), Some(version));
+        let bytes = Arc::new(input.as_bytes().to_vec());
+        let object: Box<Object> = Box::new(BytesObject::new("test".to_string(), bytes));
+
+        env.load_object(object, &package).unwrap()
+    }
+
+    /// Check a bump between two versions of a file, and return all violations.
+    fn check_files(from: (&str, &str), to: (&str, &str)) -> Vec<Violation> {
+        let (from_version, from_input) = from;
+        let (to_version, to_input) = to;
+
+        let from_file = file(from_version, from_input);
+        let to_file = file(to_version, to_input);
+
+        check(
+            (&Version::parse(from_version).unwrap(), &from_file),
+            (&Version::parse(to_version).unwrap(), &to_file),
+            &NoLookup,
+        ).unwrap()
+    }
+
+    /// Check a minor version bump, and return the names of all options which changed.
+    fn option_changes(from: &str, to: &str) -> Vec<String> {
+        let from_version = Version::parse("1.0.0").unwrap();
+        let to_version = Version::parse("1.1.0").unwrap();
+
+        let from_file = file("1.0.0", from);
+        let to_file = file("1.1.0", to);
+
+        let violations = check(
+            (&from_version, &from_file),
+            (&to_version, &to_file),
+            &NoLookup,
+        ).unwrap();
+
+        violations
+            .into_iter()
+            .map(|v| match v {
+                EndpointOptionChange(Minor, name, _, _) => name,
+                v => panic!("unexpected violation: {:?}", v),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_endpoint_option_change() {
+        let with_status = |status: u32| {
+            format!(
+                "service Foo {{ get_foo() -> string {{ option http_status = {}; }} }}",
+                status
+            )
+        };
+
+        let without_status = "service Foo { get_foo() -> string; }";
+
+        assert!(option_changes(&with_status(200), &with_status(200)).is_empty());
+
+        assert_eq!(
+            vec!["http_status".to_string()],
+            option_changes(&with_status(200), &with_status(201))
+        );
+
+        assert_eq!(
+            vec!["http_status".to_string()],
+            option_changes(without_status, &with_status(201))
+        );
+
+        assert_eq!(
+            vec!["http_status".to_string()],
+            option_changes(&with_status(200), without_status)
+        );
+    }
+    #[test]
+    fn test_remove_sub_type() {
+        let violations = check_files(
+            ("1.0.0", "interface Foo { Bar {} Baz {} }"),
+            ("1.1.0", "interface Foo { Bar {} }"),
+        );
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            RemoveSubType(Minor, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_sub_type_names_change() {
+        let from = "interface Foo { Bar as \"bar\" {} }";
+
+        let violations = check_files(
+            ("1.0.0", from),
+            ("1.1.0", "interface Foo { Bar as \"baz\" {} }"),
+        );
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            SubTypeNamesChange(Minor, ref from_names, _, ref to_names, _) => {
+                assert_eq!(vec!["bar".to_string()], *from_names);
+                assert_eq!(vec!["baz".to_string()], *to_names);
+            }
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+
+        // a minor version may add names, as long as the first one is kept.
+        let violations = check_files(
+            ("1.0.0", from),
+            ("1.1.0", "interface Foo { Bar as [\"bar\", \"b\"] {} }"),
+        );
+
+        assert!(violations.is_empty());
+
+        let violations = check_files(
+            ("1.0.0", from),
+            ("1.0.1", "interface Foo { Bar as [\"bar\", \"b\"] {} }"),
+        );
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            SubTypeNamesChange(Patch, _, _, _, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_type_info_change() {
+        let violations = check_files(
+            ("1.0.0", "interface Foo { Bar {} }"),
+            ("1.1.0", "interface Foo { option type_info = array; Bar {} }"),
+        );
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            TypeInfoChange(Minor, ref from, _, ref to, _) => {
+                assert_eq!(RpTypeInfo::TypeField { name: "type".to_string() }, *from);
+                assert_eq!(RpTypeInfo::Array, *to);
+            }
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+    #[test]
+    fn test_tuple_field_inserted() {
+        let violations = check_files(
+            ("1.0.0", "tuple Foo { a: string; b: string; }"),
+            ("1.1.0", "tuple Foo { a: string; c: string; b: string; }"),
+        );
+
+        // `b` is only shifted by the inserted field, and is not reported as moved.
+        assert_eq!(2, violations.len());
+
+        match violations[0] {
+            AddRequiredField(Minor, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+
+        match violations[1] {
+            TupleFieldInserted(Minor, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_tuple_field_moved() {
+        let violations = check_files(
+            ("1.0.0", "tuple Foo { a: string; b: string; c: string; }"),
+            ("1.1.0", "tuple Foo { a: string; c: string; b: string; }"),
+        );
+
+        let moved: Vec<(String, usize, usize)> = violations
+            .into_iter()
+            .map(|v| match v {
+                TupleFieldMoved(Minor, name, from_index, _, to_index, _) => {
+                    (name, from_index, to_index)
+                }
+                v => panic!("unexpected violation: {:?}", v),
+            })
+            .collect();
+
+        assert_eq!(vec![("b".to_string(), 1, 2), ("c".to_string(), 2, 1)], moved);
+    }
+
+    #[test]
+    fn test_tuple_arity_change() {
+        let violations = check_files(
+            ("1.0.0", "tuple Foo { a: string; b: string; }"),
+            ("1.1.0", "tuple Foo { a: string; }"),
+        );
+
+        assert_eq!(2, violations.len());
+
+        match violations[0] {
+            RemoveField(Minor, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+
+        match violations[1] {
+            TupleArityChange(Minor, 2, _, 1, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+
+        // a patch version may not add fields at the end either.
+        let violations = check_files(
+            ("1.0.0", "tuple Foo { a: string; }"),
+            ("1.0.1", "tuple Foo { a: string; b: string; }"),
+        );
+
+        assert_eq!(2, violations.len());
+
+        match violations[0] {
+            AddField(Patch, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+
+        match violations[1] {
+            TupleArityChange(Patch, 1, _, 2, _) => {}
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+    const OTHER_1: &str = "type Entry { name: string; }";
+    const OTHER_2: &str = "type Entry {}";
+
+    /// Resolves the `other` package, in the versions `1.0.0` and `2.0.0`.
+    struct OtherResolver;
+
+    impl Resolver for OtherResolver {
+        fn resolve(
+            &mut self,
+            required: &RpRequiredPackage,
+        ) -> repository::errors::Result<Vec<Resolved>> {
+            let mut out = Vec::new();
+
+            for &(version, input) in &[("1.0.0", OTHER_1), ("2.0.0", OTHER_2)] {
+                let version = Version::parse(version).unwrap();
+
+                if !required.version_req.matches(&version) {
+                    continue;
+                }
+
+                let name = format!("other-{}", version);
+                let bytes = Arc::new(input.as_bytes().to_vec());
+
+                out.push(Resolved {
+                    version: Some(version),
+                    object: Box::new(BytesObject::new(name, bytes)),
+                });
+            }
+
+            Ok(out)
+        }
+
+        fn resolve_by_prefix(
+            &mut self,
+            _: &RpPackage,
+        ) -> repository::errors::Result<Vec<ResolvedByPrefix>> {
+            Ok(vec![])
+        }
+    }
+
+    /// Looks up declarations in the environment that the checked files were loaded into.
+    struct EnvironmentLookup(Environment);
+
+    impl Lookup for EnvironmentLookup {
+        fn lookup(&self, name: &RpName) -> Option<&RpReg> {
+            self.0.lookup(name).ok()
+        }
+    }
+
+    #[test]
+    fn test_transitive() {
+        let from = "use other \"^1\" as o;\n\ntype Foo {\n  other: o::Entry;\n}\n";
+        let to = "use other \"^2\" as o;\n\ntype Foo {\n  other: o::Entry;\n}\n";
+
+        let mut env = Environment::new(None, Box::new(OtherResolver));
+
+        // both versions are loaded into the same environment, which resolves both versions of
+        // `other`.
+        let (from_file, to_file) = {
+            let mut load = |version: &str, input: &str| {
+                let version = Version::parse(version).unwrap();
+                let package = RpVersionedPackage::new(RpPackage::parse("test"), Some(version));
+                let bytes = Arc::new(input.as_bytes().to_vec());
+                let object: Box<Object> = Box::new(BytesObject::new("test".to_string(), bytes));
+                env.load_object(object, &package).unwrap()
+            };
+
+            (load("1.0.0", from), load("1.1.0", to))
+        };
+
+        let lookup = EnvironmentLookup(env);
+
+        let violations = check(
+            (&Version::parse("1.0.0").unwrap(), &from_file),
+            (&Version::parse("1.1.0").unwrap(), &to_file),
+            &lookup,
+        ).unwrap();
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            Transitive(ref chain, ref violation) => {
+                // reported at the field of the referencing type.
+                assert_eq!(1, chain.len());
+                let (ref name, ref pos) = chain[0];
+                assert_eq!("Entry", name.parts.join("::"));
+                assert_eq!("<test>", pos.object.to_string());
+                assert_eq!(to.find("other: ").unwrap(), pos.start);
+
+                // caused by the removed field of the referenced type.
+                match **violation {
+                    RemoveField(Minor, ref pos) => {
+                        assert_eq!("<other-1.0.0>", pos.object.to_string());
+                        assert_eq!(OTHER_1.find("name").unwrap(), pos.start);
+                    }
+                    ref v => panic!("unexpected violation: {:?}", v),
+                }
+            }
+            ref v => panic!("unexpected violation: {:?}", v),
+        }
+    }
+    #[test]
+    fn test_classify() {
+        let from_file = file("1.0.0", "type Foo { a: u32(min = 2, max = 10); }");
+        let to_file = file("1.1.0", "type Foo { a: u64(min = 3, max = 20); }");
+
+        let classified: Vec<(Bump, String)> = classify(&from_file, &to_file, &NoLookup)
+            .unwrap()
+            .into_iter()
+            .map(|(bump, v)| match v {
+                FieldConstraintChange(_, name, _, _) => (bump, name),
+                FieldTypeChange(..) => (bump, "type".to_string()),
+                v => panic!("unexpected violation: {:?}", v),
+            })
+            .collect();
+
+        // changes at the same position are only merged if they are of the same kind.
+        assert_eq!(
+            vec![
+                (Bump::Major, "min".to_string()),
+                (Bump::Minor, "type".to_string()),
+                (Bump::Minor, "max".to_string()),
+            ],
+            classified
+        );
+    }
+}